@@ -1,7 +1,10 @@
-use std::mem;
+use std::{mem, sync::atomic::{AtomicU32, Ordering}};
 
 use crossterm::terminal::WindowSize;
 
+// Cell size in pixels reported by `CSI 16 t`, as `width << 16 | height`
+static CELL: AtomicU32 = AtomicU32::new(0);
+
 pub struct Dimension;
 
 impl Dimension {
@@ -18,12 +21,31 @@ impl Dimension {
 			}
 		}
 
-		// TODO: Use `CSI 14 t` to get the actual size of the terminal
-		// if size.width == 0 || size.height == 0 {}
+		// Not every terminal fills in the pixel fields of `TIOCGWINSZ`
+		if size.width == 0 || size.height == 0 {
+			if let Some((w, h)) = Self::cell_size() {
+				size.width = size.columns.saturating_mul(w);
+				size.height = size.rows.saturating_mul(h);
+			}
+		}
 
 		size
 	}
 
+	pub fn cell_size() -> Option<(u16, u16)> {
+		let n = CELL.load(Ordering::Relaxed);
+		if n == 0 { None } else { Some(((n >> 16) as u16, n as u16)) }
+	}
+
+	// Parse the `CSI 6 ; height ; width t` reply to `CSI 16 t`
+	pub fn set_cell_size(resp: &str) {
+		let Some((_, s)) = resp.split_once("\x1b[6;") else { return };
+		let Some((h, w)) = s.split_once('t').and_then(|(s, _)| s.split_once(';')) else { return };
+		if let (Ok(w @ 1..), Ok(h @ 1..)) = (w.parse::<u16>(), h.parse::<u16>()) {
+			CELL.store(((w as u32) << 16) | h as u32, Ordering::Relaxed);
+		}
+	}
+
 	#[inline]
 	pub fn ratio() -> Option<(f64, f64)> {
 		let s = Self::available();
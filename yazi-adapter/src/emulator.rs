@@ -160,6 +160,35 @@ impl Emulator {
 		Ok(Self::Unknown(adapters))
 	}
 
+	// Ask for the background color with OSC 11, followed by DA1 that every terminal
+	// answers, so the ones without OSC 11 support don't keep us waiting
+	pub fn query_background() -> Option<bool> {
+		use std::io::IsTerminal;
+		if !std::io::stdin().is_terminal() || !stderr().is_terminal() {
+			return None;
+		}
+
+		defer! { disable_raw_mode().ok(); }
+		enable_raw_mode().ok()?;
+		execute!(LineWriter::new(stderr()), Print("\x1b]11;?\x1b\\\x1b[c")).ok()?;
+
+		let resp = futures::executor::block_on(Self::read_until_da1());
+		Self::parse_background(&resp)
+	}
+
+	// e.g. "\x1b]11;rgb:ffff/ffff/ffff\x1b\\", whose components have 1 to 4 hex digits
+	fn parse_background(resp: &str) -> Option<bool> {
+		let rgb = resp.split_once("]11;rgb:")?.1;
+		let rgb = &rgb[..rgb.find(['\x1b', '\x07'])?];
+
+		let mut it = rgb.split('/').map(|s| {
+			let n = u32::from_str_radix(s, 16).ok()?;
+			(1..=4).contains(&s.len()).then(|| n as f64 / ((1u32 << (4 * s.len())) - 1) as f64)
+		});
+		let (r, g, b) = (it.next()??, it.next()??, it.next()??);
+		Some(0.299 * r + 0.587 * g + 0.114 * b > 0.5)
+	}
+
 	pub fn move_lock<F, T>((x, y): (u16, u16), cb: F) -> Result<T>
 	where
 		F: FnOnce(&mut std::io::BufWriter<std::io::StderrLock>) -> Result<T>,
@@ -1,6 +1,6 @@
 use std::{fs::File, io::BufReader, path::{Path, PathBuf}};
 
-use anyhow::Result;
+use anyhow::{Result, ensure};
 use exif::{In, Tag};
 use image::{DynamicImage, ExtendedColorType, ImageEncoder, ImageError, Limits, codecs::{jpeg::JpegEncoder, png::PngEncoder}, imageops::{self, FilterType}};
 use ratatui::layout::Rect;
@@ -12,6 +12,7 @@ pub struct Image;
 
 impl Image {
 	pub async fn precache(path: &Path, cache: PathBuf) -> Result<()> {
+		Self::check_size(path).await?;
 		let orientation = Self::orientation(path).await?;
 
 		let path = path.to_owned();
@@ -54,6 +55,7 @@ impl Image {
 	}
 
 	pub(super) async fn downscale(path: &Path, rect: Rect) -> Result<DynamicImage> {
+		Self::check_size(path).await?;
 		let orientation = Self::orientation(path).await?;
 
 		let path = path.to_owned();
@@ -113,6 +115,16 @@ impl Image {
 		}
 	}
 
+	async fn check_size(path: &Path) -> Result<()> {
+		if PREVIEW.image_max_size == 0 || path.parent() == Some(&PREVIEW.cache_dir) {
+			return Ok(());
+		}
+
+		let len = tokio::fs::metadata(path).await?.len();
+		ensure!(len <= PREVIEW.image_max_size, "Image too large to preview: {len} bytes");
+		Ok(())
+	}
+
 	async fn orientation(path: &Path) -> Result<u8> {
 		// We don't want to read the orientation of the cached image that has been
 		// rotated in the `Self::precache()` step.
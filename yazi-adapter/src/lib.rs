@@ -39,6 +39,21 @@ pub static WSL: RoCell<bool> = RoCell::new();
 static SHOWN: RoCell<arc_swap::ArcSwapOption<ratatui::layout::Rect>> = RoCell::new();
 
 pub fn init() {
+	init_env();
+
+	ADAPTOR.init(Adapter::matches());
+	ADAPTOR.start();
+}
+
+// Without a terminal to draw on, as in headless mode, where stdin carries the commands
+// and must not be read for the emulator's responses
+pub fn init_headless() {
+	init_env();
+
+	ADAPTOR.init(Adapter::Chafa);
+}
+
+fn init_env() {
 	// Tmux support
 	TMUX.init(env_exists("TMUX_PANE") && env_exists("TMUX"));
 	ESCAPE.init(if *TMUX { "\x1b\x1b" } else { "\x1b" });
@@ -59,9 +74,6 @@ pub fn init() {
 
 	// Image state
 	SHOWN.with(<_>::default);
-
-	ADAPTOR.init(Adapter::matches());
-	ADAPTOR.start();
 }
 
 pub fn tcsi(s: &str) -> std::borrow::Cow<str> {
@@ -3,6 +3,18 @@ use std::process;
 pub(crate) struct Actions;
 
 impl Actions {
+	// Run before the config is loaded, as it may not be valid
+	pub(crate) fn act_config(args: &crate::Args) {
+		if args.init {
+			Self::init_config();
+			process::exit(0);
+		}
+
+		if args.check_config {
+			process::exit(if Self::check_config() { 0 } else { 1 });
+		}
+	}
+
 	pub(crate) fn act(args: &crate::Args) {
 		if args.debug {
 			println!("{}", Self::debug().unwrap());
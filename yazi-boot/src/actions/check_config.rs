@@ -0,0 +1,18 @@
+use yazi_shared::Xdg;
+
+use super::Actions;
+
+impl Actions {
+	pub(super) fn check_config() -> bool {
+		let problems = yazi_config::check();
+		if problems.is_empty() {
+			println!("No problems found in {:?}", Xdg::config_dir());
+			return true;
+		}
+
+		for p in &problems {
+			eprintln!("{p}");
+		}
+		false
+	}
+}
@@ -71,6 +71,7 @@ impl Actions {
 		writeln!(s, "    ueberzugpp       : {}", Self::process_output("ueberzugpp", "--version"))?;
 		writeln!(s, "    ffmpegthumbnailer: {}", Self::process_output("ffmpegthumbnailer", "-v"))?;
 		writeln!(s, "    magick           : {}", Self::process_output("magick", "--version"))?;
+		writeln!(s, "    exiftool         : {}", Self::process_output("exiftool", "-ver"))?;
 		writeln!(s, "    fzf              : {}", Self::process_output("fzf", "--version"))?;
 		writeln!(s, "    fd               : {}", Self::process_output("fd", "--version"))?;
 		writeln!(s, "    rg               : {}", Self::process_output("rg", "--version"))?;
@@ -0,0 +1,37 @@
+use yazi_shared::Xdg;
+
+use super::Actions;
+
+impl Actions {
+	// Existing files are left alone, and the defaults are written commented out,
+	// so they're still picked up from the preset after an update
+	pub(super) fn init_config() {
+		let dir = Xdg::config_dir();
+		if let Err(e) = std::fs::create_dir_all(&dir) {
+			return eprintln!("Failed to create the config directory {dir:?}: {e}");
+		}
+
+		for (name, content) in yazi_config::presets() {
+			let path = dir.join(name);
+			if path.exists() {
+				println!("Skipped {path:?}, it already exists");
+				continue;
+			}
+
+			let mut s = format!(
+				"# The defaults of Yazi {}, uncomment the ones to change along with their [section]\n\n",
+				env!("CARGO_PKG_VERSION")
+			);
+			for line in content.lines() {
+				s.push_str(if line.is_empty() { "" } else { "# " });
+				s.push_str(line);
+				s.push('\n');
+			}
+
+			match std::fs::write(&path, s) {
+				Ok(()) => println!("Created {path:?}"),
+				Err(e) => eprintln!("Failed to write {path:?}: {e}"),
+			}
+		}
+	}
+}
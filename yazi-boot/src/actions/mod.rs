@@ -1,8 +1,10 @@
 #![allow(clippy::module_inception)]
 
 mod actions;
+mod check_config;
 mod clear_cache;
 mod debug;
+mod init_config;
 mod version;
 
 pub(super) use actions::*;
@@ -19,6 +19,19 @@ pub struct Args {
 	/// Clear the cache directory
 	#[arg(long)]
 	pub clear_cache: bool,
+	/// Restore the tabs of the last session
+	#[arg(long = "continue")]
+	pub continue_:   bool,
+
+	/// Use the specified directory for the configuration, overriding `YAZI_CONFIG_HOME`
+	#[arg(long)]
+	pub config_dir:   Option<PathBuf>,
+	/// Write the default config files, commented out, to the configuration directory
+	#[arg(long)]
+	pub init:         bool,
+	/// Check the config files for errors, and print them
+	#[arg(long)]
+	pub check_config: bool,
 
 	/// Use the specified client ID, must be a globally unique number
 	#[arg(long)]
@@ -30,6 +43,13 @@ pub struct Args {
 	#[arg(long)]
 	pub remote_events: Option<String>,
 
+	/// Run commands without the TUI, and report the state after each as JSON
+	#[arg(long)]
+	pub headless: bool,
+	/// The command to run in headless mode, read from stdin if none is given
+	#[arg(long, requires = "headless")]
+	pub run:      Vec<String>,
+
 	/// Print debug information
 	#[arg(long)]
 	pub debug: bool,
@@ -1,6 +1,7 @@
 use std::{collections::HashSet, ffi::OsString, path::PathBuf};
 
 use serde::Serialize;
+use yazi_config::MANAGER;
 use yazi_shared::{Xdg, fs::{current_cwd, expand_path}};
 
 #[derive(Debug, Default, Serialize)]
@@ -20,7 +21,8 @@ pub struct Boot {
 impl Boot {
 	fn parse_entries(entries: &[PathBuf]) -> (Vec<PathBuf>, Vec<OsString>) {
 		if entries.is_empty() {
-			return (vec![current_cwd().unwrap()], vec![OsString::new()]);
+			let cwd = Some(&MANAGER.startup_dir).filter(|p| p.is_dir()).cloned().or_else(current_cwd);
+			return (vec![cwd.unwrap()], vec![OsString::new()]);
 		}
 
 		let mut cwds = Vec::with_capacity(entries.len());
@@ -1,5 +1,5 @@
 use clap::Parser;
-use yazi_shared::RoCell;
+use yazi_shared::{RoCell, fs::expand_path};
 
 mod actions;
 mod args;
@@ -11,8 +11,20 @@ pub use boot::*;
 pub static ARGS: RoCell<Args> = RoCell::new();
 pub static BOOT: RoCell<Boot> = RoCell::new();
 
-pub fn init() {
+// Parsed ahead of the config, so `--config-dir` can decide where it's loaded from,
+// and `--init` and `--check-config` can run even if it's broken. Must be called
+// before any other thread is started, since it may modify the environment
+pub fn init_args() {
 	ARGS.with(<_>::parse);
+
+	if let Some(p) = &ARGS.config_dir {
+		std::env::set_var("YAZI_CONFIG_HOME", expand_path(p));
+	}
+
+	actions::Actions::act_config(&ARGS);
+}
+
+pub fn init() {
 	BOOT.init(From::from(&*ARGS));
 
 	actions::Actions::act(&ARGS);
@@ -1,6 +1,6 @@
 use std::borrow::Cow;
 
-use anyhow::{Result, bail};
+use anyhow::Result;
 use clap::{Parser, Subcommand, command};
 
 #[derive(Parser)]
@@ -20,6 +20,14 @@ pub(super) enum Command {
 	Pub(CommandPub),
 	/// Publish a message to the specified instance.
 	PubTo(CommandPubTo),
+	/// Run a command in the current instance.
+	Emit(CommandEmit),
+	/// Run a command in the specified instance.
+	EmitTo(CommandEmitTo),
+	/// Query the state of the current instance.
+	Query(CommandQuery),
+	/// Query the state of the specified instance.
+	QueryTo(CommandQueryTo),
 	/// Subscribe to messages from all remote instances.
 	Sub(CommandSub),
 	/// Manage packages.
@@ -42,17 +50,6 @@ pub(super) struct CommandPub {
 	pub(super) list: Vec<String>,
 }
 
-impl CommandPub {
-	#[allow(dead_code)]
-	pub(super) fn receiver(&self) -> Result<u64> {
-		if let Some(s) = std::env::var("YAZI_PID").ok().filter(|s| !s.is_empty()) {
-			Ok(s.parse()?)
-		} else {
-			bail!("No `YAZI_ID` environment variable found.")
-		}
-	}
-}
-
 #[derive(clap::Args)]
 pub(super) struct CommandPubTo {
 	/// The receiver ID.
@@ -72,6 +69,46 @@ pub(super) struct CommandPubTo {
 	pub(super) list:     Vec<String>,
 }
 
+#[derive(clap::Args)]
+pub(super) struct CommandEmit {
+	/// The name of the command.
+	#[arg(index = 1)]
+	pub(super) name: String,
+	/// The arguments of the command.
+	#[arg(index = 2, num_args = 0.., allow_hyphen_values = true)]
+	pub(super) args: Vec<String>,
+}
+
+#[derive(clap::Args)]
+pub(super) struct CommandEmitTo {
+	/// The receiver ID.
+	#[arg(index = 1)]
+	pub(super) receiver: u64,
+	/// The name of the command.
+	#[arg(index = 2)]
+	pub(super) name:     String,
+	/// The arguments of the command.
+	#[arg(index = 3, num_args = 0.., allow_hyphen_values = true)]
+	pub(super) args:     Vec<String>,
+}
+
+#[derive(clap::Args)]
+pub(super) struct CommandQuery {
+	/// What to query, printed as JSON.
+	#[arg(index = 1, value_parser = ["current-dir", "hovered", "selection"])]
+	pub(super) what: String,
+}
+
+#[derive(clap::Args)]
+pub(super) struct CommandQueryTo {
+	/// The receiver ID.
+	#[arg(index = 1)]
+	pub(super) receiver: u64,
+	/// What to query, printed as JSON.
+	#[arg(index = 2, value_parser = ["current-dir", "hovered", "selection"])]
+	pub(super) what:     String,
+}
+
 #[derive(clap::Args)]
 pub(super) struct CommandSub {
 	/// The kind of messages to subscribe to, separated by commas if multiple.
@@ -118,3 +155,20 @@ macro_rules! impl_body {
 
 impl_body!(CommandPub);
 impl_body!(CommandPubTo);
+
+macro_rules! impl_emit_body {
+	($name:ident) => {
+		impl $name {
+			#[allow(dead_code)]
+			pub(super) fn body(&self) -> Result<String> {
+				let mut list = Vec::with_capacity(self.args.len() + 1);
+				list.push(&self.name);
+				list.extend(&self.args);
+				Ok(serde_json::to_string(&list)?)
+			}
+		}
+	};
+}
+
+impl_emit_body!(CommandEmit);
+impl_emit_body!(CommandEmitTo);
@@ -1,6 +1,7 @@
 mod args;
 mod package;
 
+use anyhow::{Result, bail};
 use args::*;
 use clap::Parser;
 
@@ -22,7 +23,7 @@ async fn main() -> anyhow::Result<()> {
 		Command::Pub(cmd) => {
 			yazi_boot::init_default();
 			yazi_dds::init();
-			if let Err(e) = yazi_dds::Client::shot(&cmd.kind, cmd.receiver()?, &cmd.body()?).await {
+			if let Err(e) = yazi_dds::Client::shot(&cmd.kind, receiver()?, &cmd.body()?).await {
 				eprintln!("Cannot send message: {e}");
 				std::process::exit(1);
 			}
@@ -37,6 +38,48 @@ async fn main() -> anyhow::Result<()> {
 			}
 		}
 
+		Command::Emit(cmd) => {
+			yazi_boot::init_default();
+			yazi_dds::init();
+			if let Err(e) = yazi_dds::Client::shot("dds-emit", receiver()?, &cmd.body()?).await {
+				eprintln!("Cannot emit command: {e}");
+				std::process::exit(1);
+			}
+		}
+
+		Command::EmitTo(cmd) => {
+			yazi_boot::init_default();
+			yazi_dds::init();
+			if let Err(e) = yazi_dds::Client::shot("dds-emit", cmd.receiver, &cmd.body()?).await {
+				eprintln!("Cannot emit command: {e}");
+				std::process::exit(1);
+			}
+		}
+
+		Command::Query(cmd) => {
+			yazi_boot::init_default();
+			yazi_dds::init();
+			match yazi_dds::Client::query(receiver()?, &cmd.what).await {
+				Ok(reply) => println!("{reply}"),
+				Err(e) => {
+					eprintln!("Cannot query: {e}");
+					std::process::exit(1);
+				}
+			}
+		}
+
+		Command::QueryTo(cmd) => {
+			yazi_boot::init_default();
+			yazi_dds::init();
+			match yazi_dds::Client::query(cmd.receiver, &cmd.what).await {
+				Ok(reply) => println!("{reply}"),
+				Err(e) => {
+					eprintln!("Cannot query: {e}");
+					std::process::exit(1);
+				}
+			}
+		}
+
 		Command::Sub(cmd) => {
 			yazi_boot::init_default();
 			yazi_dds::init();
@@ -73,3 +116,12 @@ async fn main() -> anyhow::Result<()> {
 
 	Ok(())
 }
+
+// The instance that `ya` runs in, for the subcommands that don't take a receiver
+fn receiver() -> Result<u64> {
+	if let Some(s) = std::env::var("YAZI_PID").ok().filter(|s| !s.is_empty()) {
+		Ok(s.parse()?)
+	} else {
+		bail!("No `YAZI_PID` environment variable found.")
+	}
+}
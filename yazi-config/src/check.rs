@@ -0,0 +1,131 @@
+use std::{fmt::Display, str::FromStr};
+
+use yazi_shared::Xdg;
+
+use crate::{Preset, clipboard, keymap, locale, log, manager, open, plugin, popup, preview, remote, tasks, theme, which};
+
+// Something wrong with a config file, found by `check()`
+pub struct Problem {
+	pub file:   &'static str,
+	pub key:    String,
+	pub reason: String,
+}
+
+impl Display for Problem {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		if self.key.is_empty() {
+			write!(f, "{}: {}", self.file, self.reason)
+		} else {
+			write!(f, "{}: [{}] {}", self.file, self.key, self.reason)
+		}
+	}
+}
+
+// Parse the config files without applying them, and collect all the problems instead of
+// stopping at the first one
+pub fn check() -> Vec<Problem> {
+	let config_dir = Xdg::config_dir();
+	let mut problems = vec![];
+
+	macro_rules! check {
+		($file:literal, $key:literal, $result:expr) => {
+			check!($file, $key, "", $result)
+		};
+		($file:literal, $key:literal, $src:expr, $result:expr) => {
+			match $result {
+				Ok(v) => Some(v),
+				Err(e) => {
+					let e = e.into();
+					let key = key(&e, $src).unwrap_or_else(|| $key.to_owned());
+					problems.push(Problem { file: $file, key, reason: reason(e) });
+					None
+				}
+			}
+		};
+	}
+
+	if let Some(s) = check!("yazi.toml", "", Preset::yazi(&config_dir)) {
+		check!("yazi.toml", "manager", &s, manager::Manager::from_str(&s));
+		check!("yazi.toml", "preview", &s, preview::Preview::from_str(&s));
+		check!("yazi.toml", "opener", &s, open::Open::from_str(&s));
+		check!("yazi.toml", "plugin", &s, plugin::Plugin::from_str(&s));
+		check!("yazi.toml", "tasks", &s, tasks::Tasks::from_str(&s));
+		check!("yazi.toml", "input", &s, popup::Input::from_str(&s));
+		check!("yazi.toml", "confirm", &s, popup::Confirm::from_str(&s));
+		check!("yazi.toml", "select", &s, popup::Select::from_str(&s));
+		check!("yazi.toml", "which", &s, which::Which::from_str(&s));
+		check!("yazi.toml", "locale", &s, locale::Locale::from_str(&s));
+		check!("yazi.toml", "remote", &s, remote::Remote::from_str(&s));
+		check!("yazi.toml", "clipboard", &s, clipboard::Clipboard::from_str(&s));
+		check!("yazi.toml", "log", &s, log::Log::from_str(&s));
+	}
+
+	if let Some(s) = check!("keymap.toml", "", Preset::keymap(&config_dir)) {
+		check!("keymap.toml", "", &s, keymap::Keymap::from_str(&s));
+	}
+
+	if let Some(s) = check!("theme.toml", "", Preset::theme(&config_dir)) {
+		check!("theme.toml", "", &s, theme::Theme::from_str(&s));
+	}
+
+	problems
+}
+
+// A bare TOML error comes from the merged config, so its position is dropped as it doesn't
+// point into the user's file, unlike the ones with a context from parsing that file
+fn reason(e: anyhow::Error) -> String {
+	match e.downcast_ref::<toml::de::Error>() {
+		Some(t) if e.chain().count() == 1 => t.message().to_owned(),
+		_ => format!("{e:#}"),
+	}
+}
+
+// The dotted path of the key a TOML error is about, from its span into the parsed `src`,
+// or the one named in the message when it has no span
+fn key(e: &anyhow::Error, src: &str) -> Option<String> {
+	let t = e.downcast_ref::<toml::de::Error>()?;
+	if let Some(span) = t.span() {
+		return key_at(src, span.start);
+	}
+
+	let s = t.to_string();
+	s.lines().rev().find_map(|l| l.strip_prefix("in `")?.strip_suffix('`')).map(ToOwned::to_owned)
+}
+
+// The innermost key that is assigned at `pos`, under the table header before it,
+// for a value nested in an array or inline table, it's the key that holds them
+fn key_at(src: &str, pos: usize) -> Option<String> {
+	let (mut table, mut key) = ("", "");
+	for line in src.get(..pos).unwrap_or(src).lines().map(str::trim_start) {
+		if line.starts_with('#') {
+			continue;
+		} else if line.starts_with('[') {
+			table = line.trim_start_matches('[').split(']').next().unwrap_or_default().trim();
+			key = "";
+		} else if let Some((k, _)) = line.split_once('=') {
+			let k = k.trim();
+			if !k.is_empty() && k.chars().all(|c| c.is_alphanumeric() || "_-.\"".contains(c)) {
+				key = k;
+			}
+		}
+	}
+
+	let path: Vec<_> = [table, key].into_iter().filter(|s| !s.is_empty()).collect();
+	if path.is_empty() { None } else { Some(path.join(".")) }
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_key_at() {
+		let src = "[manager]\n# sort = 1\nsort_by = \"bogus\"\n\n\
+		           [[opener.edit]]\nrun = [\n\t{ x = 1 },\n]\n";
+		let at = |needle: &str| key_at(src, src.find(needle).unwrap());
+		assert_eq!(at("\"bogus"), Some("manager.sort_by".to_owned()));
+		assert_eq!(at("{ x"), Some("opener.edit.run".to_owned()));
+		assert_eq!(at("# sort"), Some("manager".to_owned()));
+		assert_eq!(key_at(src, 0), None);
+	}
+}
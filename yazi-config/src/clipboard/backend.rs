@@ -0,0 +1,42 @@
+use std::str::FromStr;
+
+use anyhow::bail;
+use serde::Deserialize;
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(try_from = "String")]
+pub enum ClipboardBackend {
+	#[default]
+	Auto,
+	Pasteboard,
+	Wayland,
+	Xclip,
+	Xsel,
+	Wsl,
+	Windows,
+	Osc52,
+}
+
+impl FromStr for ClipboardBackend {
+	type Err = anyhow::Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Ok(match s {
+			"auto" => Self::Auto,
+			"pasteboard" => Self::Pasteboard,
+			"wayland" => Self::Wayland,
+			"xclip" => Self::Xclip,
+			"xsel" => Self::Xsel,
+			"wsl" => Self::Wsl,
+			"windows" => Self::Windows,
+			"osc52" => Self::Osc52,
+			_ => bail!("Invalid clipboard `backend` value: {s}"),
+		})
+	}
+}
+
+impl TryFrom<String> for ClipboardBackend {
+	type Error = anyhow::Error;
+
+	fn try_from(value: String) -> Result<Self, Self::Error> { Self::from_str(&value) }
+}
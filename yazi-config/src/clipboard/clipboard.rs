@@ -0,0 +1,24 @@
+use std::str::FromStr;
+
+use serde::Deserialize;
+
+use super::ClipboardBackend;
+
+#[derive(Debug, Deserialize)]
+pub struct Clipboard {
+	// Detected at startup if `auto`
+	pub backend: ClipboardBackend,
+}
+
+impl FromStr for Clipboard {
+	type Err = anyhow::Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		#[derive(Deserialize)]
+		struct Outer {
+			clipboard: Clipboard,
+		}
+
+		Ok(toml::from_str::<Outer>(s)?.clipboard)
+	}
+}
@@ -0,0 +1,5 @@
+mod backend;
+mod clipboard;
+
+pub use backend::*;
+pub use clipboard::*;
@@ -17,6 +17,17 @@ pub struct Chord {
 	pub desc: Option<String>,
 }
 
+// Chords only carry arguments parsed from the keymap, so a shallow clone is a complete one
+impl Clone for Chord {
+	fn clone(&self) -> Self {
+		Self {
+			on:   self.on.clone(),
+			run:  self.run.iter().map(Cmd::shallow_clone).collect(),
+			desc: self.desc.clone(),
+		}
+	}
+}
+
 impl PartialEq for Chord {
 	fn eq(&self, other: &Self) -> bool { self.on == other.on }
 }
@@ -1,12 +1,15 @@
-use std::{collections::HashSet, str::FromStr};
+use std::{collections::{HashMap, HashSet}, str::FromStr};
 
 use indexmap::IndexSet;
 use serde::{Deserialize, Deserializer};
-use yazi_shared::Layer;
+use yazi_shared::{Layer, event::Cmd};
 
 use super::Chord;
 use crate::Preset;
 
+// How deep aliases can refer to other aliases
+const ALIAS_DEPTH: usize = 16;
+
 #[derive(Debug)]
 pub struct Keymap {
 	pub manager:    Vec<Chord>,
@@ -16,6 +19,11 @@ pub struct Keymap {
 	pub confirm:    Vec<Chord>,
 	pub help:       Vec<Chord>,
 	pub completion: Vec<Chord>,
+
+	// User-defined commands, made up of existing ones
+	pub aliases: HashMap<String, Vec<Cmd>>,
+	// Commands to run when an event occurs, e.g. `cd`
+	pub hooks:   HashMap<String, Vec<Cmd>>,
 }
 
 impl Keymap {
@@ -33,6 +41,39 @@ impl Keymap {
 			Layer::Which => unreachable!(),
 		}
 	}
+
+	// The commands an alias runs, with the aliases among them expanded in turn
+	pub fn alias(&self, name: &str) -> Option<Result<Vec<Cmd>, String>> {
+		self.aliases.contains_key(name).then(|| {
+			let mut out = vec![];
+			Self::expand(&self.aliases, name, &mut vec![], &mut out).map(|()| out)
+		})
+	}
+
+	fn expand(
+		aliases: &HashMap<String, Vec<Cmd>>,
+		name: &str,
+		stack: &mut Vec<String>,
+		out: &mut Vec<Cmd>,
+	) -> Result<(), String> {
+		if stack.iter().any(|s| s == name) {
+			stack.push(name.to_owned());
+			return Err(format!("The alias `{}` refers to itself: {}", stack[0], stack.join(" -> ")));
+		} else if stack.len() >= ALIAS_DEPTH {
+			return Err(format!("The alias `{}` nests more than {ALIAS_DEPTH} levels deep", stack[0]));
+		}
+
+		stack.push(name.to_owned());
+		for cmd in &aliases[name] {
+			if aliases.contains_key(&cmd.name) {
+				Self::expand(aliases, &cmd.name, stack, out)?;
+			} else {
+				out.push(cmd.shallow_clone());
+			}
+		}
+		stack.pop();
+		Ok(())
+	}
 }
 
 impl FromStr for Keymap {
@@ -55,6 +96,10 @@ impl<'de> Deserialize<'de> for Keymap {
 			confirm:    Inner,
 			help:       Inner,
 			completion: Inner,
+			#[serde(default)]
+			alias:      HashMap<String, Run>,
+			#[serde(default)]
+			hook:       HashMap<String, Run>,
 		}
 		#[derive(Deserialize)]
 		struct Inner {
@@ -65,6 +110,9 @@ impl<'de> Deserialize<'de> for Keymap {
 			append_keymap:  IndexSet<Chord>,
 		}
 
+		#[derive(Deserialize)]
+		struct Run(#[serde(deserialize_with = "super::deserialize_run")] Vec<Cmd>);
+
 		fn mix(mut a: IndexSet<Chord>, b: IndexSet<Chord>, c: IndexSet<Chord>) -> Vec<Chord> {
 			let mut seen = HashSet::new();
 			b.iter().filter(|&v| v.on.len() > 1).for_each(|v| _ = seen.insert(&v.on[..2]));
@@ -75,6 +123,11 @@ impl<'de> Deserialize<'de> for Keymap {
 		}
 
 		let shadow = Shadow::deserialize(deserializer)?;
+		let aliases: HashMap<_, _> = shadow.alias.into_iter().map(|(k, v)| (k, v.0)).collect();
+		for name in aliases.keys() {
+			Self::expand(&aliases, name, &mut vec![], &mut vec![]).map_err(serde::de::Error::custom)?;
+		}
+
 		Ok(Self {
 			#[rustfmt::skip]
 			manager:    mix(shadow.manager.keymap, shadow.manager.prepend_keymap, shadow.manager.append_keymap),
@@ -90,6 +143,38 @@ impl<'de> Deserialize<'de> for Keymap {
 			help:       mix(shadow.help.keymap, shadow.help.prepend_keymap, shadow.help.append_keymap),
 			#[rustfmt::skip]
 			completion: mix(shadow.completion.keymap, shadow.completion.prepend_keymap, shadow.completion.append_keymap),
+
+			aliases,
+			hooks:   shadow.hook.into_iter().map(|(k, v)| (k, v.0)).collect(),
 		})
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn expand(pairs: &[(&str, &[&str])], name: &str) -> Result<Vec<String>, String> {
+		let aliases = pairs
+			.iter()
+			.map(|&(k, v)| (k.to_owned(), v.iter().map(|&s| Cmd::new(s)).collect()))
+			.collect();
+
+		let mut out = vec![];
+		Keymap::expand(&aliases, name, &mut vec![], &mut out)?;
+		Ok(out.into_iter().map(|c| c.name).collect())
+	}
+
+	#[test]
+	fn test_alias() {
+		let pairs: &[(&str, &[&str])] = &[("a", &["b", "quit"]), ("b", &["escape", "c"]), ("c", &["help"])];
+		assert_eq!(expand(pairs, "a").unwrap(), ["escape", "help", "quit"]);
+
+		// The same alias twice in a row is fine, only a path back to itself isn't
+		let pairs: &[(&str, &[&str])] = &[("a", &["b", "b"]), ("b", &["quit"])];
+		assert_eq!(expand(pairs, "a").unwrap(), ["quit", "quit"]);
+
+		let pairs: &[(&str, &[&str])] = &[("a", &["b"]), ("b", &["c"]), ("c", &["a"])];
+		assert!(expand(pairs, "a").unwrap_err().contains("a -> b -> c -> a"));
+	}
+}
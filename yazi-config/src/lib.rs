@@ -1,11 +1,16 @@
 #![allow(clippy::module_inception)]
 
-use std::str::FromStr;
+use std::{borrow::Cow, str::FromStr, sync::Arc};
+
+use arc_swap::ArcSwap;
 
 use yazi_shared::{RoCell, Xdg};
 
+mod check;
+pub mod clipboard;
 pub mod keymap;
 mod layout;
+pub mod locale;
 mod log;
 mod macros;
 pub mod manager;
@@ -16,25 +21,30 @@ pub mod popup;
 mod preset;
 pub mod preview;
 mod priority;
+pub mod remote;
 mod tasks;
 pub mod theme;
 pub mod which;
 
+pub use check::*;
 pub use layout::*;
 pub(crate) use pattern::*;
 pub(crate) use preset::*;
 pub use priority::*;
 
-pub static LAYOUT: RoCell<arc_swap::ArcSwap<Layout>> = RoCell::new();
+pub static LAYOUT: RoCell<ArcSwap<Layout>> = RoCell::new();
 
-pub static KEYMAP: RoCell<keymap::Keymap> = RoCell::new();
+pub static CLIPBOARD: RoCell<clipboard::Clipboard> = RoCell::new();
+pub static KEYMAP: RoCell<ArcSwap<keymap::Keymap>> = RoCell::new();
+pub static LOCALE: RoCell<locale::Locale> = RoCell::new();
 pub static LOG: RoCell<log::Log> = RoCell::new();
 pub static MANAGER: RoCell<manager::Manager> = RoCell::new();
 pub static OPEN: RoCell<open::Open> = RoCell::new();
 pub static PLUGIN: RoCell<plugin::Plugin> = RoCell::new();
 pub static PREVIEW: RoCell<preview::Preview> = RoCell::new();
+pub static REMOTE: RoCell<remote::Remote> = RoCell::new();
 pub static TASKS: RoCell<tasks::Tasks> = RoCell::new();
-pub static THEME: RoCell<theme::Theme> = RoCell::new();
+pub static THEME: RoCell<ArcSwap<theme::Theme>> = RoCell::new();
 pub static INPUT: RoCell<popup::Input> = RoCell::new();
 pub static CONFIRM: RoCell<popup::Confirm> = RoCell::new();
 pub static SELECT: RoCell<popup::Select> = RoCell::new();
@@ -48,21 +58,25 @@ pub fn init() -> anyhow::Result<()> {
 
 	LAYOUT.with(<_>::default);
 
-	KEYMAP.init(<_>::from_str(keymap_toml)?);
+	CLIPBOARD.init(<_>::from_str(yazi_toml)?);
+	KEYMAP.init(ArcSwap::from_pointee(<_>::from_str(keymap_toml)?));
+	LOCALE.init(<_>::from_str(yazi_toml)?);
 	LOG.init(<_>::from_str(yazi_toml)?);
 	MANAGER.init(<_>::from_str(yazi_toml)?);
+	yazi_shared::set_ambiguous_wide(MANAGER.ambiguous == manager::Ambiguous::Wide);
 	OPEN.init(<_>::from_str(yazi_toml)?);
 	PLUGIN.init(<_>::from_str(yazi_toml)?);
 	PREVIEW.init(<_>::from_str(yazi_toml)?);
+	REMOTE.init(<_>::from_str(yazi_toml)?);
 	TASKS.init(<_>::from_str(yazi_toml)?);
-	THEME.init(<_>::from_str(theme_toml)?);
+	THEME.init(ArcSwap::from_pointee(<_>::from_str(theme_toml)?));
 	INPUT.init(<_>::from_str(yazi_toml)?);
 	CONFIRM.init(<_>::from_str(yazi_toml)?);
 	SELECT.init(<_>::from_str(yazi_toml)?);
 	WHICH.init(<_>::from_str(yazi_toml)?);
 
 	// TODO: Remove in v0.3.2
-	for c in &KEYMAP.manager {
+	for c in &KEYMAP.load().manager {
 		for r in &c.run {
 			if r.name != "shell" {
 				continue;
@@ -95,3 +109,26 @@ Please change `create_title = "Create:"` to `create_title = ["Create:", "Create
 
 	Ok(())
 }
+
+// The bundled defaults, as the file name and its content
+pub fn presets() -> [(&'static str, Cow<'static, str>); 3] {
+	[
+		("yazi.toml", preset!("yazi")),
+		("keymap.toml", preset!("keymap")),
+		("theme.toml", preset!("theme")),
+	]
+}
+
+// Re-read the keymap and theme, which are swapped as a whole. The settings of yazi.toml
+// are read once at startup, as most of them are captured by the running tasks
+pub fn reload() -> anyhow::Result<()> {
+	let config_dir = Xdg::config_dir();
+	let keymap: keymap::Keymap = Preset::keymap(&config_dir)?.parse()?;
+	let theme: theme::Theme = Preset::theme(&config_dir)?.parse()?;
+
+	// Chords may carry non-`Sync` data, but the keymap is only read on the main thread
+	#[allow(clippy::arc_with_non_send_sync)]
+	KEYMAP.store(Arc::new(keymap));
+	THEME.store(Arc::new(theme));
+	Ok(())
+}
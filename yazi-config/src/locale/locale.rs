@@ -0,0 +1,107 @@
+use std::{borrow::Cow, env, path::Path, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use crate::preset;
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Locale {
+	pub lang: String,
+
+	// tasks
+	pub task_cut:      String,
+	pub task_copy:     String,
+	pub task_link:     String,
+	pub task_hardlink: String,
+	pub task_delete:   String,
+	pub task_shred:    String,
+	pub task_trash:    String,
+	pub task_restore:  String,
+
+	pub task_title:            String,
+	pub task_finished:         String,
+	pub task_failures:         String,
+	pub task_failures_content: String,
+
+	pub fail_cut_into_itself:      String,
+	pub fail_copy_into_itself:     String,
+	pub fail_hardlink_into_itself: String,
+
+	// status
+	pub status_top:    String,
+	pub status_bottom: String,
+
+	// help
+	pub help_filter: String,
+
+	// folder
+	pub folder_loading:    String,
+	pub folder_empty:      String,
+	pub folder_no_results: String,
+}
+
+impl FromStr for Locale {
+	type Err = toml::de::Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		#[derive(Deserialize)]
+		struct Outer {
+			locale: Locale,
+		}
+
+		Ok(toml::from_str::<Outer>(s)?.locale)
+	}
+}
+
+impl Locale {
+	// The translation laid over the preset `yazi.toml`, looked up in the `locales/`
+	// of the config directory first, then in the bundled ones
+	pub(crate) fn overlay(dir: &Path, user: &str) -> Option<Cow<'static, str>> {
+		Self::candidates(user).into_iter().find_map(|lang| {
+			std::fs::read_to_string(dir.join(format!("locales/{lang}.toml")))
+				.map(Cow::Owned)
+				.ok()
+				.or_else(|| Self::bundled(&lang))
+		})
+	}
+
+	// An explicit `lang` wins, otherwise follow the environment, e.g. "zh_CN.UTF-8"
+	// gives "zh_CN" and then "zh"
+	fn candidates(user: &str) -> Vec<String> {
+		#[derive(Deserialize)]
+		struct Outer {
+			locale: Shadow,
+		}
+		#[derive(Deserialize)]
+		struct Shadow {
+			lang: String,
+		}
+
+		let lang = toml::from_str::<Outer>(user)
+			.ok()
+			.map(|o| o.locale.lang)
+			.filter(|s| !s.is_empty())
+			.or_else(|| {
+				["LC_ALL", "LC_MESSAGES", "LANG"]
+					.into_iter()
+					.filter_map(|k| env::var(k).ok())
+					.find(|s| !s.is_empty())
+			})
+			.unwrap_or_default();
+
+		let full = lang.split(['.', '@']).next().unwrap_or_default().replace('-', "_");
+		if matches!(full.as_str(), "" | "C" | "POSIX") {
+			return vec![];
+		}
+
+		let short = full.split('_').next().unwrap_or_default().to_owned();
+		if short == full { vec![full] } else { vec![full, short] }
+	}
+
+	fn bundled(lang: &str) -> Option<Cow<'static, str>> {
+		match lang {
+			"zh" | "zh_CN" | "zh_SG" => Some(preset!("locales/zh_CN")),
+			_ => None,
+		}
+	}
+}
@@ -0,0 +1,3 @@
+mod locale;
+
+pub use locale::*;
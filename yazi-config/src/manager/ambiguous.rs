@@ -0,0 +1,44 @@
+use std::{fmt::Display, str::FromStr};
+
+use anyhow::bail;
+use serde::{Deserialize, Serialize};
+
+// How many cells the East Asian Ambiguous characters take up in the terminal.
+//
+// Only Yazi's own width math follows it: ratatui's `Buffer` still lays out every
+// ambiguous character in one cell, so with `Wide`, text drawn after one on the same
+// line (e.g. a right-aligned size) can be pushed a cell to the right by the terminal.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(try_from = "String")]
+pub enum Ambiguous {
+	#[default]
+	Narrow,
+	Wide,
+}
+
+impl FromStr for Ambiguous {
+	type Err = anyhow::Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Ok(match s {
+			"narrow" => Self::Narrow,
+			"wide" => Self::Wide,
+			_ => bail!("invalid ambiguous value: {s}"),
+		})
+	}
+}
+
+impl TryFrom<String> for Ambiguous {
+	type Error = anyhow::Error;
+
+	fn try_from(s: String) -> Result<Self, Self::Error> { Self::from_str(&s) }
+}
+
+impl Display for Ambiguous {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.write_str(match self {
+			Self::Narrow => "narrow",
+			Self::Wide => "wide",
+		})
+	}
+}
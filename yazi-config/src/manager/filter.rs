@@ -0,0 +1,43 @@
+use std::{fmt::Display, str::FromStr};
+
+use anyhow::bail;
+use serde::{Deserialize, Serialize};
+
+// What happens to a filter when leaving the directory it was applied to
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(try_from = "String")]
+pub enum FilterPolicy {
+	#[default]
+	Keep,
+	Clear,
+	Inherit,
+}
+
+impl FromStr for FilterPolicy {
+	type Err = anyhow::Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Ok(match s {
+			"keep" => Self::Keep,
+			"clear" => Self::Clear,
+			"inherit" => Self::Inherit,
+			_ => bail!("invalid filter_policy value: {s}"),
+		})
+	}
+}
+
+impl TryFrom<String> for FilterPolicy {
+	type Error = anyhow::Error;
+
+	fn try_from(s: String) -> Result<Self, Self::Error> { Self::from_str(&s) }
+}
+
+impl Display for FilterPolicy {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.write_str(match self {
+			Self::Keep => "keep",
+			Self::Clear => "clear",
+			Self::Inherit => "inherit",
+		})
+	}
+}
@@ -0,0 +1,37 @@
+use std::{ffi::OsStr, path::Path};
+
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use serde::{Deserialize, Serialize, Serializer};
+
+// Names that are always hidden, no matter whether `show_hidden` is on
+#[derive(Debug, Default, Deserialize)]
+#[serde(try_from = "Vec<String>")]
+pub struct ManagerHide {
+	raw: Vec<String>,
+	set: GlobSet,
+}
+
+impl ManagerHide {
+	#[inline]
+	pub fn matches(&self, name: &OsStr) -> bool {
+		!self.raw.is_empty() && self.set.is_match(Path::new(name))
+	}
+}
+
+impl TryFrom<Vec<String>> for ManagerHide {
+	type Error = anyhow::Error;
+
+	fn try_from(raw: Vec<String>) -> Result<Self, Self::Error> {
+		let mut builder = GlobSetBuilder::new();
+		for s in &raw {
+			builder.add(GlobBuilder::new(s).literal_separator(true).backslash_escape(false).build()?);
+		}
+		Ok(Self { set: builder.build()?, raw })
+	}
+}
+
+impl Serialize for ManagerHide {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.collect_seq(&self.raw)
+	}
+}
@@ -1,9 +1,11 @@
-use std::str::FromStr;
+use std::{path::PathBuf, str::FromStr};
 
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
-use super::{ManagerRatio, MouseEvents, SortBy};
+use yazi_shared::fs::expand_path;
+
+use super::{Ambiguous, FilterPolicy, ManagerHide, ManagerOverride, ManagerRatio, MouseEvents, SortBy};
 
 #[derive(Debug, Deserialize, Serialize, Validate)]
 pub struct Manager {
@@ -20,10 +22,24 @@ pub struct Manager {
 	#[validate(length(min = 1, max = 20, message = "must be between 1 and 20 characters"))]
 	pub linemode:     String,
 	pub show_hidden:  bool,
+	pub hide:         ManagerHide,
 	pub show_symlink: bool,
 	pub scrolloff:    u8,
+	pub cursor_wrap:  bool,
 	pub mouse_events: MouseEvents,
 	pub title_format: String,
+	pub dual_pane:    bool,
+	pub ambiguous:    Ambiguous,
+
+	pub filter_policy: FilterPolicy,
+
+	pub overrides: Vec<ManagerOverride>,
+
+	pub restore_session: bool,
+	pub startup_dir:     PathBuf,
+
+	#[validate(range(min = 1, message = "Cannot be less than 1"))]
+	pub max_fps: u16,
 }
 
 impl FromStr for Manager {
@@ -35,9 +51,16 @@ impl FromStr for Manager {
 			manager: Manager,
 		}
 
-		let manager = toml::from_str::<Outer>(s)?.manager;
+		let mut manager = toml::from_str::<Outer>(s)?.manager;
 		manager.validate()?;
 
+		for o in &mut manager.overrides {
+			o.path = expand_path(&o.path);
+		}
+		if !manager.startup_dir.as_os_str().is_empty() {
+			manager.startup_dir = expand_path(&manager.startup_dir);
+		}
+
 		Ok(manager)
 	}
 }
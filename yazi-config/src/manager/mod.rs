@@ -1,9 +1,17 @@
+mod ambiguous;
+mod filter;
+mod hide;
 mod manager;
 mod mouse;
+mod overrides;
 mod ratio;
 mod sorting;
 
+pub use ambiguous::*;
+pub use filter::*;
+pub use hide::*;
 pub use manager::*;
 pub use mouse::*;
+pub use overrides::*;
 pub use ratio::*;
 pub use sorting::*;
@@ -0,0 +1,22 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use super::SortBy;
+
+// Settings that apply to a specific directory only, e.g. sorting `~/Downloads` by mtime
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ManagerOverride {
+	pub path: PathBuf,
+
+	// Sorting
+	pub sort_by:        Option<SortBy>,
+	pub sort_sensitive: Option<bool>,
+	pub sort_reverse:   Option<bool>,
+	pub sort_dir_first: Option<bool>,
+	pub sort_translit:  Option<bool>,
+
+	// Display
+	pub linemode:    Option<String>,
+	pub show_hidden: Option<bool>,
+}
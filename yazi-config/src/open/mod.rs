@@ -1,7 +1,11 @@
 mod open;
 mod opener;
 mod rule;
+#[cfg(all(unix, not(target_os = "macos")))]
+mod system;
 
 pub use open::*;
 pub use opener::*;
 use rule::*;
+#[cfg(all(unix, not(target_os = "macos")))]
+use system::*;
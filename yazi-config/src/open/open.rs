@@ -4,6 +4,8 @@ use indexmap::IndexSet;
 use serde::{Deserialize, Deserializer};
 use yazi_shared::MIME_DIR;
 
+#[cfg(all(unix, not(target_os = "macos")))]
+use super::system_opener;
 use super::Opener;
 use crate::{Preset, open::OpenRule};
 
@@ -20,10 +22,9 @@ impl Open {
 		M: AsRef<str>,
 	{
 		let is_dir = mime.as_ref() == MIME_DIR;
-		self.rules.iter().find_map(|rule| {
-			if rule.mime.as_ref().is_some_and(|p| p.match_mime(&mime))
-				|| rule.name.as_ref().is_some_and(|p| p.match_path(&path, is_dir))
-			{
+		let matched = self.rules.iter().find_map(|rule| {
+			let by_mime = rule.mime.as_ref().is_some_and(|p| p.match_mime(&mime));
+			if by_mime || rule.name.as_ref().is_some_and(|p| p.match_path(&path, is_dir)) {
 				let openers = rule
 					.use_
 					.iter()
@@ -31,11 +32,23 @@ impl Open {
 					.flatten()
 					.collect::<IndexSet<_>>();
 
-				if openers.is_empty() { None } else { Some(openers) }
+				if openers.is_empty() { None } else { Some((openers, by_mime)) }
 			} else {
 				None
 			}
-		})
+		});
+
+		// Below the configured ones, the default application of the system, which
+		// `open` on macOS and `start` on Windows already go through. A rule matched
+		// by name is more specific than the MIME type, so it's left as is
+		#[cfg(all(unix, not(target_os = "macos")))]
+		if !matches!(matched, Some((_, false))) {
+			if let Some(o) = system_opener(mime.as_ref()) {
+				let openers = matched.map(|(o, _)| o).unwrap_or_default();
+				return Some(openers.into_iter().chain([o]).collect());
+			}
+		}
+		matched.map(|(o, _)| o)
 	}
 
 	#[inline]
@@ -52,6 +65,12 @@ impl Open {
 		let flat: IndexSet<_> = grouped.iter().flatten().copied().collect();
 		flat.into_iter().filter(|&o| grouped.iter().all(|g| g.contains(o))).collect()
 	}
+
+	pub fn any_openers(&self, targets: &[(impl AsRef<Path>, impl AsRef<str>)]) -> Vec<&Opener> {
+		let flat: IndexSet<_> =
+			targets.iter().filter_map(|(p, m)| self.openers(p, m)).flatten().collect();
+		flat.into_iter().collect()
+	}
 }
 
 impl FromStr for Open {
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Deserializer};
+use yazi_shared::env_exists;
 
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Opener {
@@ -18,6 +19,8 @@ impl Opener {
 				b"windows" if cfg!(windows) => {}
 				b"linux" if cfg!(target_os = "linux") => {}
 				b"macos" if cfg!(target_os = "macos") => {}
+				// Running inside a Neovim terminal, which exports its server address as `$NVIM`
+				b"nvim" if cfg!(unix) && env_exists("NVIM") => {}
 				_ => return None,
 			}
 		}
@@ -40,6 +43,7 @@ impl<'de> Deserialize<'de> for Opener {
 			desc:   Option<String>,
 			#[serde(rename = "for")]
 			for_:   Option<String>,
+			spread: Option<bool>,
 		}
 
 		let shadow = Shadow::deserialize(deserializer)?;
@@ -49,9 +53,18 @@ impl<'de> Deserialize<'de> for Opener {
 			return Err(serde::de::Error::custom("`run` cannot be empty"));
 		}
 
+		// A blocking opener always waits for its process, so `block` wins over `orphan`
+		let orphan = shadow.orphan && !shadow.block;
+		if shadow.block && shadow.orphan {
+			eprintln!("WARNING: The opener `{run}` sets both `block` and `orphan`, `orphan` is ignored.");
+		}
+
 		let desc = shadow.desc.unwrap_or_else(|| run.split_whitespace().next().unwrap().to_string());
 
-		let spread = run.contains("$@") || run.contains("%*") || run.contains("$*");
-		Ok(Self { run, block: shadow.block, orphan: shadow.orphan, desc, for_: shadow.for_, spread })
+		// All files in one invocation, or one process per file
+		let spread = shadow
+			.spread
+			.unwrap_or_else(|| run.contains("$@") || run.contains("%*") || run.contains("$*"));
+		Ok(Self { run, block: shadow.block, orphan, desc, for_: shadow.for_, spread })
 	}
 }
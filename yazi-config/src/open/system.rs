@@ -0,0 +1,193 @@
+use std::{collections::HashMap, env, fs, path::{Path, PathBuf}, sync::{Mutex, OnceLock}};
+
+use yazi_shared::shell::escape_unix;
+
+use super::Opener;
+
+// `[Default Applications]` of all `mimeapps.list`, the same ones `xdg-open` consults
+static DEFAULTS: OnceLock<HashMap<String, Vec<String>>> = OnceLock::new();
+static OPENERS: OnceLock<Mutex<HashMap<String, Option<&'static Opener>>>> = OnceLock::new();
+
+pub(super) fn system_opener(mime: &str) -> Option<&'static Opener> {
+	let mut cache = OPENERS.get_or_init(Default::default).lock().unwrap();
+	*cache.entry(mime.to_owned()).or_insert_with(|| resolve(mime).map(|o| &*Box::leak(Box::new(o))))
+}
+
+fn resolve(mime: &str) -> Option<Opener> {
+	let ids = DEFAULTS.get_or_init(defaults).get(mime)?;
+	let dirs: Vec<_> = data_dirs().into_iter().map(|d| d.join("applications")).collect();
+	ids.iter().find_map(|id| dirs.iter().find_map(|d| desktop_entry(&d.join(id))))
+}
+
+fn defaults() -> HashMap<String, Vec<String>> {
+	let mut dirs = config_dirs();
+	dirs.extend(data_dirs().into_iter().map(|d| d.join("applications")));
+
+	// The earlier ones take precedence
+	let mut map = HashMap::new();
+	for dir in dirs {
+		let Ok(s) = fs::read_to_string(dir.join("mimeapps.list")) else { continue };
+		for (mime, ids) in section(&s, "Default Applications") {
+			map.entry(mime.to_owned()).or_insert_with(|| {
+				ids.split(';').filter(|s| !s.is_empty()).map(ToOwned::to_owned).collect()
+			});
+		}
+	}
+	map
+}
+
+fn desktop_entry(path: &Path) -> Option<Opener> {
+	let s = fs::read_to_string(path).ok()?;
+
+	let (mut name, mut exec, mut terminal) = (None, None, false);
+	for (k, v) in section(&s, "Desktop Entry") {
+		match k {
+			"Name" => name = Some(v),
+			"Exec" => exec = Some(v),
+			"Terminal" => terminal = v == "true",
+			_ => {}
+		}
+	}
+
+	let run = exec_to_run(exec?)?;
+	Some(Opener {
+		spread: run.contains("$@"),
+		run,
+		block: terminal,
+		orphan: !terminal,
+		desc: name.or_else(|| path.file_stem()?.to_str())?.to_owned(),
+		for_: None,
+	})
+}
+
+fn section<'a>(s: &'a str, name: &'a str) -> impl Iterator<Item = (&'a str, &'a str)> {
+	s.lines()
+		.map(str::trim)
+		.skip_while(move |l| l.strip_prefix('[').and_then(|l| l.strip_suffix(']')) != Some(name))
+		.skip(1)
+		.take_while(|l| !l.starts_with('['))
+		.filter_map(|l| l.split_once('='))
+		.map(|(k, v)| (k.trim(), v.trim()))
+}
+
+// Turn `Exec` into a shell command, quoting its arguments and replacing the field codes
+// with shell arguments, dropping the deprecated and unsupported ones
+fn exec_to_run(exec: &str) -> Option<String> {
+	let exec = unescape(exec);
+	let (mut args, mut arg, mut lit) = (vec![], String::new(), String::new());
+	let (mut quoted, mut started, mut files) = (false, false, false);
+
+	let mut it = exec.chars();
+	while let Some(c) = it.next() {
+		match c {
+			'"' => (quoted, started) = (!quoted, true),
+			'\\' if quoted => lit.push(it.next()?),
+			' ' | '\t' if !quoted => {
+				flush(&mut args, &mut arg, &mut lit, started);
+				started = false;
+			}
+			'%' if !quoted => {
+				let code = match it.next() {
+					Some('f' | 'u') => r#""$1""#,
+					Some('F' | 'U') => r#""$@""#,
+					Some('%') => {
+						lit.push('%');
+						started = true;
+						continue;
+					}
+					_ => continue,
+				};
+				if !lit.is_empty() {
+					arg.push_str(&escape_unix(&std::mem::take(&mut lit)));
+				}
+				arg.push_str(code);
+				(started, files) = (true, true);
+			}
+			c => {
+				lit.push(c);
+				started = true;
+			}
+		}
+	}
+	if quoted {
+		return None;
+	}
+
+	flush(&mut args, &mut arg, &mut lit, started);
+	if !files {
+		args.push(r#""$1""#.to_owned());
+	}
+	Some(args.join(" "))
+}
+
+fn flush(args: &mut Vec<String>, arg: &mut String, lit: &mut String, started: bool) {
+	if !lit.is_empty() || (started && arg.is_empty()) {
+		arg.push_str(&escape_unix(lit));
+	}
+	if started {
+		args.push(std::mem::take(arg));
+	}
+	lit.clear();
+}
+
+// The escape sequences of string values, before `Exec` gets split into arguments
+fn unescape(s: &str) -> String {
+	let (mut out, mut it) = (String::with_capacity(s.len()), s.chars());
+	while let Some(c) = it.next() {
+		match (c, it.clone().next()) {
+			('\\', Some('s')) => out.push(' '),
+			('\\', Some('n')) => out.push('\n'),
+			('\\', Some('t')) => out.push('\t'),
+			('\\', Some('r')) => out.push('\r'),
+			('\\', Some('\\')) => out.push('\\'),
+			_ => {
+				out.push(c);
+				continue;
+			}
+		}
+		it.next();
+	}
+	out
+}
+
+fn config_dirs() -> Vec<PathBuf> {
+	let mut dirs = vec![home_or("XDG_CONFIG_HOME", ".config")];
+	dirs.extend(env::split_paths(&env::var_os("XDG_CONFIG_DIRS").unwrap_or("/etc/xdg".into())));
+	dirs.into_iter().filter(|p| p.is_absolute()).collect()
+}
+
+fn data_dirs() -> Vec<PathBuf> {
+	let mut dirs = vec![home_or("XDG_DATA_HOME", ".local/share")];
+	dirs.extend(env::split_paths(
+		&env::var_os("XDG_DATA_DIRS").unwrap_or("/usr/local/share:/usr/share".into()),
+	));
+	dirs.into_iter().filter(|p| p.is_absolute()).collect()
+}
+
+fn home_or(name: &str, fallback: &str) -> PathBuf {
+	env::var_os(name)
+		.map(PathBuf::from)
+		.filter(|p| p.is_absolute())
+		.or_else(|| env::var_os("HOME").map(|h| Path::new(&h).join(fallback)))
+		.unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_exec_to_run() {
+		let run = |s| exec_to_run(s).unwrap();
+		assert_eq!(run("firefox %u"), r#"firefox "$1""#);
+		assert_eq!(run("vlc --started-from-file %U"), r#"vlc --started-from-file "$@""#);
+		assert_eq!(run("gimp-2.10 %F %i"), r#"gimp-2.10 "$@""#);
+		assert_eq!(run("xterm -e 100%%"), r#"xterm -e '100%' "$1""#);
+		assert_eq!(run("feh"), r#"feh "$1""#);
+		assert_eq!(run("mpv --file=%f"), r#"mpv --file="$1""#);
+		assert_eq!(run(r#""/opt/My App/app" %U"#), r#"'/opt/My App/app' "$@""#);
+		assert_eq!(run(r#"sh -c "echo \\"\\$1\\"" %f"#), r#"sh -c 'echo "$1"' "$1""#);
+		assert_eq!(run(r#"app\s-x "" %f"#), r#"app -x '' "$1""#);
+		assert_eq!(exec_to_run(r#"app "%f"#), None);
+	}
+}
@@ -16,6 +16,24 @@ pub struct Confirm {
 	pub delete_origin: Origin,
 	pub delete_offset: Offset,
 
+	// shred
+	pub shred_title:   String,
+	pub shred_content: String,
+	pub shred_origin:  Origin,
+	pub shred_offset:  Offset,
+
+	// dry_run
+	pub dry_run_title:   String,
+	pub dry_run_content: String,
+	pub dry_run_origin:  Origin,
+	pub dry_run_offset:  Offset,
+
+	// resume
+	pub resume_title:   String,
+	pub resume_content: String,
+	pub resume_origin:  Origin,
+	pub resume_offset:  Offset,
+
 	// overwrite
 	pub overwrite_title:   String,
 	pub overwrite_content: String,
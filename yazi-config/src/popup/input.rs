@@ -23,6 +23,11 @@ pub struct Input {
 	pub rename_origin: Origin,
 	pub rename_offset: Offset,
 
+	// tab_rename
+	pub tab_rename_title:  String,
+	pub tab_rename_origin: Origin,
+	pub tab_rename_offset: Offset,
+
 	// filter
 	pub filter_title:  String,
 	pub filter_origin: Origin,
@@ -42,6 +47,21 @@ pub struct Input {
 	pub shell_title:  [String; 2],
 	pub shell_origin: Origin,
 	pub shell_offset: Offset,
+
+	// palette
+	pub palette_title:  String,
+	pub palette_origin: Origin,
+	pub palette_offset: Offset,
+
+	// goto
+	pub goto_title:  String,
+	pub goto_origin: Origin,
+	pub goto_offset: Offset,
+
+	// xattr
+	pub xattr_title:  String,
+	pub xattr_origin: Origin,
+	pub xattr_offset: Offset,
 }
 
 impl Input {
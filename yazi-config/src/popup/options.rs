@@ -1,5 +1,5 @@
 use ratatui::{text::Text, widgets::{Paragraph, Wrap}};
-use yazi_shared::fs::Url;
+use yazi_shared::{fs::Url, readable_size};
 
 use super::{Offset, Origin, Position};
 use crate::{CONFIRM, INPUT, SELECT};
@@ -20,6 +20,7 @@ pub struct SelectCfg {
 	pub title:    String,
 	pub items:    Vec<String>,
 	pub position: Position,
+	pub current:  Option<usize>,
 }
 
 #[derive(Default)]
@@ -56,18 +57,26 @@ impl InputCfg {
 		}
 	}
 
-	pub fn filter() -> Self {
+	pub fn tab_rename() -> Self {
 		Self {
-			title: INPUT.filter_title.to_owned(),
+			title: INPUT.tab_rename_title.to_owned(),
+			position: Position::new(INPUT.tab_rename_origin, INPUT.tab_rename_offset),
+			..Default::default()
+		}
+	}
+
+	pub fn filter(mode: &str) -> Self {
+		Self {
+			title: INPUT.filter_title.replace("{mode}", mode),
 			position: Position::new(INPUT.filter_origin, INPUT.filter_offset),
 			realtime: true,
 			..Default::default()
 		}
 	}
 
-	pub fn find(prev: bool) -> Self {
+	pub fn find(prev: bool, mode: &str) -> Self {
 		Self {
-			title: INPUT.find_title[prev as usize].to_owned(),
+			title: INPUT.find_title[prev as usize].replace("{mode}", mode),
 			position: Position::new(INPUT.find_origin, INPUT.find_offset),
 			realtime: true,
 			..Default::default()
@@ -91,6 +100,31 @@ impl InputCfg {
 		}
 	}
 
+	pub fn palette() -> Self {
+		Self {
+			title: INPUT.palette_title.to_owned(),
+			position: Position::new(INPUT.palette_origin, INPUT.palette_offset),
+			completion: true,
+			..Default::default()
+		}
+	}
+
+	pub fn goto() -> Self {
+		Self {
+			title: INPUT.goto_title.to_owned(),
+			position: Position::new(INPUT.goto_origin, INPUT.goto_offset),
+			..Default::default()
+		}
+	}
+
+	pub fn xattr() -> Self {
+		Self {
+			title: INPUT.xattr_title.to_owned(),
+			position: Position::new(INPUT.xattr_origin, INPUT.xattr_offset),
+			..Default::default()
+		}
+	}
+
 	#[inline]
 	pub fn with_value(mut self, value: impl Into<String>) -> Self {
 		self.value = value.into();
@@ -119,21 +153,55 @@ impl ConfirmCfg {
 		}
 	}
 
-	pub fn trash(urls: &[yazi_shared::fs::Url]) -> Self {
+	// Every victim is listed, since the list scrolls and a misfire is costly.
+	// `partial` is for a size that only counts the top-level entries
+	pub fn trash(urls: &[yazi_shared::fs::Url], size: u64, partial: bool) -> Self {
 		Self::new(
 			Self::replace_number(&CONFIRM.trash_title, urls.len()),
 			(CONFIRM.trash_origin, CONFIRM.trash_offset),
-			None,
-			Self::truncate_list(urls.iter(), urls.len(), 100),
+			Some(Text::raw(Self::total_size(size, partial))),
+			Self::truncate_list(urls.iter(), urls.len(), urls.len()),
 		)
 	}
 
-	pub fn delete(urls: &[yazi_shared::fs::Url]) -> Self {
+	pub fn delete(urls: &[yazi_shared::fs::Url], size: u64, partial: bool) -> Self {
 		Self::new(
 			Self::replace_number(&CONFIRM.delete_title, urls.len()),
 			(CONFIRM.delete_origin, CONFIRM.delete_offset),
-			None,
-			Self::truncate_list(urls.iter(), urls.len(), 100),
+			Some(Text::raw(Self::total_size(size, partial))),
+			Self::truncate_list(urls.iter(), urls.len(), urls.len()),
+		)
+	}
+
+	pub fn shred(urls: &[yazi_shared::fs::Url], size: u64, partial: bool) -> Self {
+		let mut content = Text::raw(Self::total_size(size, partial));
+		content.push_line(CONFIRM.shred_content.as_str());
+
+		Self::new(
+			Self::replace_number(&CONFIRM.shred_title, urls.len()),
+			(CONFIRM.shred_origin, CONFIRM.shred_offset),
+			Some(content),
+			Self::truncate_list(urls.iter(), urls.len(), urls.len()),
+		)
+	}
+
+	pub fn dry_run(plan: Vec<String>) -> Self {
+		let len = plan.len();
+		Self::new(
+			Self::replace_number(&CONFIRM.dry_run_title, len),
+			(CONFIRM.dry_run_origin, CONFIRM.dry_run_offset),
+			Some(Text::raw(&CONFIRM.dry_run_content)),
+			Self::truncate_list(plan.into_iter(), len, 100),
+		)
+	}
+
+	pub fn resume(names: Vec<String>) -> Self {
+		let len = names.len();
+		Self::new(
+			Self::replace_number(&CONFIRM.resume_title, len),
+			(CONFIRM.resume_origin, CONFIRM.resume_offset),
+			Some(Text::raw(&CONFIRM.resume_content)),
+			Self::truncate_list(names.into_iter(), len, 100),
 		)
 	}
 
@@ -159,6 +227,14 @@ impl ConfirmCfg {
 		tpl.replace("{n}", &n.to_string()).replace("{s}", if n > 1 { "s" } else { "" })
 	}
 
+	fn total_size(n: u64, partial: bool) -> String {
+		if partial {
+			format!("{} in total, counting the top-level entries only", readable_size(n))
+		} else {
+			format!("{} in total", readable_size(n))
+		}
+	}
+
 	fn truncate_list(
 		it: impl Iterator<Item = impl Into<String>>,
 		len: usize,
@@ -178,12 +254,12 @@ impl ConfirmCfg {
 
 impl SelectCfg {
 	#[inline]
-	fn max_height(len: usize) -> u16 {
-		SELECT.open_offset.height.min(SELECT.border().saturating_add(len as u16))
+	fn max_height(max: u16, len: usize) -> u16 {
+		max.min(SELECT.border().saturating_add(len as u16))
 	}
 
 	pub fn open(items: Vec<String>) -> Self {
-		let max_height = Self::max_height(items.len());
+		let max_height = Self::max_height(SELECT.open_offset.height, items.len());
 		Self {
 			title: SELECT.open_title.to_owned(),
 			items,
@@ -191,6 +267,118 @@ impl SelectCfg {
 				height: max_height,
 				..SELECT.open_offset
 			}),
+			..Default::default()
+		}
+	}
+
+	pub fn history(items: Vec<String>) -> Self {
+		let max_height = Self::max_height(SELECT.history_offset.height, items.len());
+		Self {
+			title: SELECT.history_title.to_owned(),
+			items,
+			position: Position::new(SELECT.history_origin, Offset {
+				height: max_height,
+				..SELECT.history_offset
+			}),
+			..Default::default()
+		}
+	}
+
+	pub fn info(items: Vec<String>) -> Self {
+		let max_height = Self::max_height(SELECT.info_offset.height, items.len());
+		Self {
+			title: SELECT.info_title.to_owned(),
+			items,
+			position: Position::new(SELECT.info_origin, Offset {
+				height: max_height,
+				..SELECT.info_offset
+			}),
+			..Default::default()
+		}
+	}
+
+	pub fn ancestor(items: Vec<String>) -> Self {
+		let max_height = Self::max_height(SELECT.ancestor_offset.height, items.len());
+		Self {
+			title: SELECT.ancestor_title.to_owned(),
+			items,
+			position: Position::new(SELECT.ancestor_origin, Offset {
+				height: max_height,
+				..SELECT.ancestor_offset
+			}),
+			..Default::default()
 		}
 	}
+
+	pub fn device(items: Vec<String>) -> Self {
+		let max_height = Self::max_height(SELECT.device_offset.height, items.len());
+		Self {
+			title: SELECT.device_title.to_owned(),
+			items,
+			position: Position::new(SELECT.device_origin, Offset {
+				height: max_height,
+				..SELECT.device_offset
+			}),
+			..Default::default()
+		}
+	}
+
+	pub fn drive(items: Vec<String>) -> Self {
+		let max_height = Self::max_height(SELECT.drive_offset.height, items.len());
+		Self {
+			title: SELECT.drive_title.to_owned(),
+			items,
+			position: Position::new(SELECT.drive_origin, Offset {
+				height: max_height,
+				..SELECT.drive_offset
+			}),
+			..Default::default()
+		}
+	}
+
+	pub fn restore(items: Vec<String>) -> Self {
+		let max_height = Self::max_height(SELECT.restore_offset.height, items.len());
+		Self {
+			title: SELECT.restore_title.to_owned(),
+			items,
+			position: Position::new(SELECT.restore_origin, Offset {
+				height: max_height,
+				..SELECT.restore_offset
+			}),
+			..Default::default()
+		}
+	}
+
+	pub fn sort(items: Vec<String>) -> Self {
+		let max_height = Self::max_height(SELECT.sort_offset.height, items.len());
+		Self {
+			title: SELECT.sort_title.to_owned(),
+			items,
+			position: Position::new(SELECT.sort_origin, Offset {
+				height: max_height,
+				..SELECT.sort_offset
+			}),
+			..Default::default()
+		}
+	}
+
+	pub fn linemode(items: Vec<String>) -> Self {
+		let max_height = Self::max_height(SELECT.linemode_offset.height, items.len());
+		Self {
+			title: SELECT.linemode_title.to_owned(),
+			items,
+			position: Position::new(SELECT.linemode_origin, Offset {
+				height: max_height,
+				..SELECT.linemode_offset
+			}),
+			..Default::default()
+		}
+	}
+
+	// The item of the current value, marked and hovered initially
+	#[inline]
+	pub fn with_current(mut self, current: Option<usize>) -> Self {
+		self.current = current;
+		self
+	}
 }
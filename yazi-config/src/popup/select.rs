@@ -10,6 +10,46 @@ pub struct Select {
 	pub open_title:  String,
 	pub open_origin: Origin,
 	pub open_offset: Offset,
+
+	// history
+	pub history_title:  String,
+	pub history_origin: Origin,
+	pub history_offset: Offset,
+
+	// info
+	pub info_title:  String,
+	pub info_origin: Origin,
+	pub info_offset: Offset,
+
+	// ancestor
+	pub ancestor_title:  String,
+	pub ancestor_origin: Origin,
+	pub ancestor_offset: Offset,
+
+	// device
+	pub device_title:  String,
+	pub device_origin: Origin,
+	pub device_offset: Offset,
+
+	// drive
+	pub drive_title:  String,
+	pub drive_origin: Origin,
+	pub drive_offset: Offset,
+
+	// restore
+	pub restore_title:  String,
+	pub restore_origin: Origin,
+	pub restore_offset: Offset,
+
+	// sort
+	pub sort_title:  String,
+	pub sort_origin: Origin,
+	pub sort_offset: Offset,
+
+	// linemode
+	pub linemode_title:  String,
+	pub linemode_origin: Origin,
+	pub linemode_offset: Offset,
 }
 
 impl Select {
@@ -3,13 +3,19 @@ use std::{borrow::Cow, path::{Path, PathBuf}};
 use anyhow::{Context, Result};
 use toml::{Table, Value};
 
-use crate::{preset, theme::Flavor};
+use crate::{locale::Locale, preset, theme::Flavor};
 
 pub(crate) struct Preset;
 
 impl Preset {
 	pub(crate) fn yazi(p: &Path) -> Result<Cow<str>> {
-		Self::merge_path(p.join("yazi.toml"), preset!("yazi"))
+		let user = std::fs::read_to_string(p.join("yazi.toml")).unwrap_or_default();
+		let Some(locale) = Locale::overlay(p, &user) else {
+			return Self::merge_path(p.join("yazi.toml"), preset!("yazi"));
+		};
+
+		let base = Self::merge_str(&locale, &preset!("yazi")).context("failed to parse the locale")?;
+		Self::merge_path(p.join("yazi.toml"), base)
 	}
 
 	pub(crate) fn keymap(p: &Path) -> Result<Cow<str>> {
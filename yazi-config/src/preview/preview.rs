@@ -1,6 +1,6 @@
 use std::{borrow::Cow, path::PathBuf, str::FromStr, time::{SystemTime, UNIX_EPOCH}};
 
-use anyhow::Context;
+use anyhow::{Context, bail};
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 use yazi_shared::fs::expand_path;
@@ -11,6 +11,8 @@ use crate::Xdg;
 #[rustfmt::skip]
 const TABS: &[&str] = &["", " ", "  ", "   ", "    ", "     ", "      ", "       ", "        ", "         ", "          ", "           ", "            ", "             ", "              ", "               ", "                "];
 
+const IMAGE_FILTERS: &[&str] = &["nearest", "triangle", "catmull-rom", "gaussian", "lanczos3"];
+
 #[derive(Debug, Serialize)]
 pub struct Preview {
 	pub wrap:       PreviewWrap,
@@ -23,6 +25,7 @@ pub struct Preview {
 	pub image_delay:    u8,
 	pub image_filter:   String,
 	pub image_quality:  u8,
+	pub image_max_size: u64,
 	pub sixel_fraction: u8,
 
 	pub ueberzug_scale:  f32,
@@ -67,6 +70,7 @@ impl FromStr for Preview {
 			image_filter:   String,
 			#[validate(range(min = 50, max = 90))]
 			image_quality:  u8,
+			image_max_size: u64,
 			#[validate(range(min = 10, max = 20))]
 			sixel_fraction: u8,
 
@@ -76,6 +80,9 @@ impl FromStr for Preview {
 
 		let preview = toml::from_str::<Outer>(s)?.preview;
 		preview.validate()?;
+		if !IMAGE_FILTERS.contains(&preview.image_filter.as_str()) {
+			bail!("`image_filter` must be one of {}", IMAGE_FILTERS.join(", "));
+		}
 
 		let cache_dir =
 			preview.cache_dir.filter(|p| !p.is_empty()).map_or_else(Xdg::cache_dir, expand_path);
@@ -92,6 +99,7 @@ impl FromStr for Preview {
 			image_delay: preview.image_delay,
 			image_filter: preview.image_filter,
 			image_quality: preview.image_quality,
+			image_max_size: preview.image_max_size,
 			sixel_fraction: preview.sixel_fraction,
 
 			ueberzug_scale: preview.ueberzug_scale,
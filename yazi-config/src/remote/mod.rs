@@ -0,0 +1,3 @@
+mod remote;
+
+pub use remote::*;
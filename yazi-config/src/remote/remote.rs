@@ -0,0 +1,25 @@
+use std::str::FromStr;
+
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct Remote {
+	// S3, the credentials are read from the environment if left empty
+	pub s3_endpoint:   String,
+	pub s3_region:     String,
+	pub s3_access_key: String,
+	pub s3_secret_key: String,
+}
+
+impl FromStr for Remote {
+	type Err = anyhow::Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		#[derive(Deserialize)]
+		struct Outer {
+			remote: Remote,
+		}
+
+		Ok(toml::from_str::<Outer>(s)?.remote)
+	}
+}
@@ -0,0 +1,12 @@
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct TaskHook {
+	pub on:  Vec<String>,
+	pub run: String,
+}
+
+impl TaskHook {
+	#[inline]
+	pub fn matches(&self, action: &str) -> bool { self.on.iter().any(|s| s == "*" || s == action) }
+}
@@ -1,3 +1,5 @@
+mod hook;
 mod tasks;
 
+pub use hook::*;
 pub use tasks::*;
@@ -3,6 +3,8 @@ use std::str::FromStr;
 use serde::Deserialize;
 use validator::Validate;
 
+use super::TaskHook;
+
 #[derive(Debug, Deserialize, Validate)]
 pub struct Tasks {
 	#[validate(range(min = 1, message = "Cannot be less than 1"))]
@@ -10,12 +12,27 @@ pub struct Tasks {
 	#[validate(range(min = 1, message = "Cannot be less than 1"))]
 	pub macro_workers: u8,
 	#[validate(range(min = 1, message = "Cannot be less than 1"))]
-	pub bizarre_retry: u8,
+	pub bizarre_retry:   u8,
+	pub transient_retry: u8,
+
+	pub copy_limit: u64,
 
 	pub image_alloc: u32,
 	pub image_bound: [u16; 2],
 
 	pub suppress_preload: bool,
+	pub precache_pages:   u8,
+	pub precache_sizes:   bool,
+
+	pub notify_kinds:   Vec<String>,
+	pub notify_after:   u64,
+	pub notify_desktop: bool,
+	pub notify_bell:    bool,
+
+	pub progress_title: bool,
+	pub progress_osc:   bool,
+
+	pub hooks: Vec<TaskHook>,
 }
 
 impl FromStr for Tasks {
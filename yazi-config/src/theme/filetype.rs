@@ -1,7 +1,7 @@
 use serde::{Deserialize, Deserializer};
 use yazi_shared::{fs::File, theme::{Color, Style, StyleShadow}};
 
-use super::Is;
+use super::{Is, LsColors};
 use crate::Pattern;
 
 pub struct Filetype {
@@ -29,7 +29,9 @@ impl Filetype {
 	{
 		#[derive(Deserialize)]
 		struct FiletypeOuter {
-			rules: Vec<FiletypeRule>,
+			rules:     Vec<FiletypeRule>,
+			#[serde(default)]
+			ls_colors: bool,
 		}
 		#[derive(Deserialize)]
 		struct FiletypeRule {
@@ -60,30 +62,35 @@ impl Filetype {
 			crossed:     bool,
 		}
 
-		Ok(
-			FiletypeOuter::deserialize(deserializer)?
-				.rules
-				.into_iter()
-				.map(|r| Filetype {
-					is:    r.is,
-					name:  r.name,
-					mime:  r.mime,
-					style: StyleShadow {
-						fg:          r.fg,
-						bg:          r.bg,
-						bold:        r.bold,
-						dim:         r.dim,
-						italic:      r.italic,
-						underline:   r.underline,
-						blink:       r.blink,
-						blink_rapid: r.blink_rapid,
-						reversed:    r.reversed,
-						hidden:      r.hidden,
-						crossed:     r.crossed,
-					}
-					.into(),
-				})
-				.collect(),
-		)
+		let outer = FiletypeOuter::deserialize(deserializer)?;
+		let mut filetypes: Vec<_> = outer
+			.rules
+			.into_iter()
+			.map(|r| Filetype {
+				is:    r.is,
+				name:  r.name,
+				mime:  r.mime,
+				style: StyleShadow {
+					fg:          r.fg,
+					bg:          r.bg,
+					bold:        r.bold,
+					dim:         r.dim,
+					italic:      r.italic,
+					underline:   r.underline,
+					blink:       r.blink,
+					blink_rapid: r.blink_rapid,
+					reversed:    r.reversed,
+					hidden:      r.hidden,
+					crossed:     r.crossed,
+				}
+				.into(),
+			})
+			.collect();
+
+		// Rules from the theme take precedence over the ones from `$LS_COLORS`
+		if outer.ls_colors {
+			filetypes.extend(LsColors::load());
+		}
+		Ok(filetypes)
 	}
 }
@@ -2,22 +2,46 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Deserialize, Serialize)]
 pub struct Flavor {
-	#[serde(rename = "use")]
-	pub use_: String,
+	#[serde(rename = "use", default)]
+	pub use_:  String,
+	#[serde(default)]
+	pub dark:  String,
+	#[serde(default)]
+	pub light: String,
 }
 
 impl Flavor {
 	pub fn parse_use(s: &str) -> Option<String> {
 		#[derive(Deserialize)]
 		struct Outer {
-			flavor: Inner,
+			flavor: Flavor,
 		}
-		#[derive(Deserialize)]
-		struct Inner {
-			#[serde(rename = "use")]
-			pub use_: String,
+
+		toml::from_str::<Outer>(s).ok().map(|o| o.flavor.active().to_owned()).filter(|s| !s.is_empty())
+	}
+
+	// An explicit `use` always wins, otherwise pick by the terminal background
+	pub fn active(&self) -> &str {
+		if !self.use_.is_empty() {
+			&self.use_
+		} else if Self::light_background() {
+			&self.light
+		} else {
+			&self.dark
+		}
+	}
+
+	// The background the terminal reported for OSC 11, otherwise terminals like Konsole
+	// and rxvt report their colors as "fg;bg" in `$COLORFGBG`, where background 7 and
+	// 9-15 are the light ones
+	pub(super) fn light_background() -> bool {
+		if let Some(light) = yazi_shared::light_background() {
+			return light;
 		}
 
-		toml::from_str::<Outer>(s).ok().map(|o| o.flavor.use_).filter(|s| !s.is_empty())
+		std::env::var("COLORFGBG")
+			.ok()
+			.and_then(|s| s.rsplit(';').next()?.parse::<u8>().ok())
+			.is_some_and(|bg| matches!(bg, 7 | 9..=15))
 	}
 }
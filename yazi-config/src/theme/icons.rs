@@ -1,21 +1,22 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, sync::Arc};
 
 use anyhow::Result;
 use serde::{Deserialize, Deserializer};
 use yazi_shared::{Condition, fs::File, theme::{Color, Icon, Style}};
 
+use super::Flavor;
 use crate::{Pattern, Preset};
 
 pub struct Icons {
-	globs: Vec<(Pattern, Icon)>,
-	dirs:  HashMap<String, Icon>,
-	files: HashMap<String, Icon>,
-	exts:  HashMap<String, Icon>,
-	conds: Vec<(Condition, Icon)>,
+	globs: Vec<(Pattern, Arc<Icon>)>,
+	dirs:  HashMap<String, Arc<Icon>>,
+	files: HashMap<String, Arc<Icon>>,
+	exts:  HashMap<String, Arc<Icon>>,
+	conds: Vec<(Condition, Arc<Icon>)>,
 }
 
 impl Icons {
-	pub fn matches(&self, file: &File) -> Option<&Icon> {
+	pub fn matches(&self, file: &File) -> Option<&Arc<Icon>> {
 		if let Some(i) = self.match_by_glob(file) {
 			return Some(i);
 		}
@@ -42,12 +43,12 @@ impl Icons {
 	}
 
 	#[inline]
-	fn match_by_glob(&self, file: &File) -> Option<&Icon> {
+	fn match_by_glob(&self, file: &File) -> Option<&Arc<Icon>> {
 		self.globs.iter().find(|(p, _)| p.match_path(&file.url, file.is_dir())).map(|(_, i)| i)
 	}
 
 	#[inline]
-	fn match_by_name(&self, file: &File) -> Option<&Icon> {
+	fn match_by_name(&self, file: &File) -> Option<&Arc<Icon>> {
 		let name = file.name().to_str()?;
 		if file.is_dir() {
 			self.dirs.get(name).or_else(|| self.dirs.get(&name.to_ascii_lowercase()))
@@ -61,12 +62,40 @@ impl Icons {
 	}
 
 	#[inline]
-	fn match_by_ext(&self, file: &File) -> Option<&Icon> {
+	fn match_by_ext(&self, file: &File) -> Option<&Arc<Icon>> {
 		let ext = file.url.extension()?.to_str()?;
 		self.exts.get(ext).or_else(|| self.exts.get(&ext.to_ascii_lowercase()))
 	}
 }
 
+impl Icons {
+	// Classify files like `ls -F`, for fonts without the Nerd Font glyphs
+	fn ascii() -> Self {
+		let conds = [
+			("orphan", "!"),
+			("link", "@"),
+			("dir", "/"),
+			("fifo", "|"),
+			("sock", "="),
+			("exec", "*"),
+			("!dir", "-"),
+		];
+
+		Self {
+			globs: vec![],
+			dirs:  Default::default(),
+			files: Default::default(),
+			exts:  Default::default(),
+			conds: conds
+				.into_iter()
+				.map(|(c, t)| {
+					(c.parse().unwrap(), Arc::new(Icon { text: t.to_owned(), style: Default::default() }))
+				})
+				.collect(),
+		}
+	}
+}
+
 impl<'de> Deserialize<'de> for Icons {
 	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
 	where
@@ -74,6 +103,9 @@ impl<'de> Deserialize<'de> for Icons {
 	{
 		#[derive(Deserialize)]
 		pub struct Shadow {
+			#[serde(default)]
+			ascii: bool,
+
 			globs:         Vec<ShadowPat>,
 			#[serde(default)]
 			prepend_globs: Vec<ShadowPat>,
@@ -109,7 +141,6 @@ impl<'de> Deserialize<'de> for Icons {
 			name:     Pattern,
 			text:     String,
 			fg_dark:  Option<Color>,
-			#[allow(dead_code)]
 			fg_light: Option<Color>,
 		}
 		#[derive(Deserialize)]
@@ -117,7 +148,6 @@ impl<'de> Deserialize<'de> for Icons {
 			name:     String,
 			text:     String,
 			fg_dark:  Option<Color>,
-			#[allow(dead_code)]
 			fg_light: Option<Color>,
 		}
 		#[derive(Deserialize)]
@@ -126,30 +156,36 @@ impl<'de> Deserialize<'de> for Icons {
 			if_:      Condition,
 			text:     String,
 			fg_dark:  Option<Color>,
-			#[allow(dead_code)]
 			fg_light: Option<Color>,
 		}
 
+		fn icon(text: String, dark: Option<Color>, light: Option<Color>, is_light: bool) -> Arc<Icon> {
+			let fg = if is_light { light.or(dark) } else { dark };
+			Arc::new(Icon { text, style: Style { fg, ..Default::default() } })
+		}
+
 		let shadow = Shadow::deserialize(deserializer)?;
+		if shadow.ascii {
+			return Ok(Self::ascii());
+		}
+
+		let is_light = Flavor::light_background();
 
 		let globs = Preset::mix(shadow.globs, shadow.prepend_globs, shadow.append_globs)
-			.map(|v| {
-				(v.name, Icon { text: v.text, style: Style { fg: v.fg_dark, ..Default::default() } })
-			})
+			.map(|v| (v.name, icon(v.text, v.fg_dark, v.fg_light, is_light)))
 			.collect();
 
 		let conds = Preset::mix(shadow.conds, shadow.prepend_conds, shadow.append_conds)
-			.map(|v| {
-				(v.if_, Icon { text: v.text, style: Style { fg: v.fg_dark, ..Default::default() } })
-			})
+			.map(|v| (v.if_, icon(v.text, v.fg_dark, v.fg_light, is_light)))
 			.collect();
 
-		fn as_map(it: impl Iterator<Item = ShadowStr>) -> HashMap<String, Icon> {
+		fn as_map(
+			it: impl Iterator<Item = ShadowStr>,
+			is_light: bool,
+		) -> HashMap<String, Arc<Icon>> {
 			let mut map = HashMap::with_capacity(it.size_hint().0);
 			for v in it {
-				map
-					.entry(v.name)
-					.or_insert(Icon { text: v.text, style: Style { fg: v.fg_dark, ..Default::default() } });
+				map.entry(v.name).or_insert_with(|| icon(v.text, v.fg_dark, v.fg_light, is_light));
 			}
 			map.shrink_to_fit();
 			map
@@ -157,9 +193,9 @@ impl<'de> Deserialize<'de> for Icons {
 
 		Ok(Self {
 			globs,
-			dirs: as_map(Preset::mix(shadow.dirs, shadow.prepend_dirs, shadow.append_dirs)),
-			files: as_map(Preset::mix(shadow.files, shadow.prepend_files, shadow.append_files)),
-			exts: as_map(Preset::mix(shadow.exts, shadow.prepend_exts, shadow.append_exts)),
+			dirs: as_map(Preset::mix(shadow.dirs, shadow.prepend_dirs, shadow.append_dirs), is_light),
+			files: as_map(Preset::mix(shadow.files, shadow.prepend_files, shadow.append_files), is_light),
+			exts: as_map(Preset::mix(shadow.exts, shadow.prepend_exts, shadow.append_exts), is_light),
 			conds,
 		})
 	}
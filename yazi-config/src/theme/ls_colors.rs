@@ -0,0 +1,111 @@
+use yazi_shared::theme::{Color, StyleShadow};
+
+use super::{Filetype, Is};
+
+// Used when `$LS_COLORS` isn't set, a subset of the defaults from `dircolors`
+const DEFAULT: &str = "di=01;34:ln=01;36:pi=40;33:so=01;35:bd=40;33;01:cd=40;33;01:or=40;31;01:\
+st=37;44:ex=01;32:*.tar=01;31:*.tgz=01;31:*.zip=01;31:*.gz=01;31:*.bz2=01;31:*.xz=01;31:\
+*.zst=01;31:*.7z=01;31:*.rar=01;31:*.deb=01;31:*.rpm=01;31:*.jpg=01;35:*.jpeg=01;35:\
+*.gif=01;35:*.png=01;35:*.svg=01;35:*.webp=01;35:*.mp4=01;35:*.mkv=01;35:*.webm=01;35:\
+*.avi=01;35:*.mov=01;35:*.flac=00;36:*.mp3=00;36:*.ogg=00;36:*.wav=00;36:*.m4a=00;36";
+
+const NAMES: [&str; 8] = ["black", "red", "green", "yellow", "blue", "magenta", "cyan", "gray"];
+const BRIGHT_NAMES: [&str; 8] = [
+	"darkgray",
+	"lightred",
+	"lightgreen",
+	"lightyellow",
+	"lightblue",
+	"lightmagenta",
+	"lightcyan",
+	"white",
+];
+
+pub(super) struct LsColors;
+
+impl LsColors {
+	pub(super) fn load() -> Vec<Filetype> {
+		match std::env::var("LS_COLORS") {
+			Ok(s) if !s.is_empty() => Self::parse(&s),
+			_ => Self::parse(DEFAULT),
+		}
+	}
+
+	// File types go first and the fallbacks last, following the precedence of `ls`
+	fn parse(s: &str) -> Vec<Filetype> {
+		let (mut types, mut names, mut fallbacks) = (vec![], vec![], vec![]);
+		for (key, codes) in s.split(':').filter_map(|s| s.split_once('=')) {
+			let style = Self::style(codes);
+			let (is, name) = match key {
+				"ln" => (Is::Link, "*"),
+				"or" => (Is::Orphan, "*"),
+				"pi" => (Is::Fifo, "*"),
+				"so" => (Is::Sock, "*"),
+				"bd" => (Is::Block, "*"),
+				"cd" => (Is::Char, "*"),
+				"ex" => (Is::Exec, "*"),
+				"st" => (Is::Sticky, "*/"),
+				"di" => (Is::None, "*/"),
+				"fi" => (Is::None, "*"),
+				_ if key.starts_with('*') => (Is::None, key),
+				_ => continue,
+			};
+
+			let Ok(name) = name.try_into() else { continue };
+			let rule = Filetype { is, name: Some(name), mime: None, style: style.into() };
+			match key {
+				"di" | "fi" => fallbacks.push(rule),
+				_ if key.starts_with('*') => names.push(rule),
+				_ => types.push(rule),
+			}
+		}
+
+		types.extend(names);
+		types.extend(fallbacks);
+		types
+	}
+
+	fn style(codes: &str) -> StyleShadow {
+		let mut style = StyleShadow::default();
+		let mut it = codes.split(';').filter_map(|s| s.parse::<u8>().ok());
+
+		while let Some(code) = it.next() {
+			match code {
+				0 => style = StyleShadow::default(),
+				1 => style.bold = true,
+				2 => style.dim = true,
+				3 => style.italic = true,
+				4 => style.underline = true,
+				5 => style.blink = true,
+				6 => style.blink_rapid = true,
+				7 => style.reversed = true,
+				8 => style.hidden = true,
+				9 => style.crossed = true,
+				30..=37 => style.fg = Self::color(NAMES[code as usize - 30]),
+				40..=47 => style.bg = Self::color(NAMES[code as usize - 40]),
+				90..=97 => style.fg = Self::color(BRIGHT_NAMES[code as usize - 90]),
+				100..=107 => style.bg = Self::color(BRIGHT_NAMES[code as usize - 100]),
+				38 | 48 => {
+					let color = match it.next() {
+						Some(5) => it.next().and_then(|n| Self::color(&n.to_string())),
+						Some(2) => match (it.next(), it.next(), it.next()) {
+							(Some(r), Some(g), Some(b)) => Self::color(&format!("#{r:02x}{g:02x}{b:02x}")),
+							_ => None,
+						},
+						_ => None,
+					};
+					if code == 38 {
+						style.fg = color;
+					} else {
+						style.bg = color;
+					}
+				}
+				_ => {}
+			}
+		}
+		style
+	}
+
+	#[inline]
+	fn color(s: &str) -> Option<Color> { s.parse().ok() }
+}
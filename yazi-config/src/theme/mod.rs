@@ -2,10 +2,12 @@ mod filetype;
 mod flavor;
 mod icons;
 mod is;
+mod ls_colors;
 mod theme;
 
 pub use filetype::*;
 pub use flavor::*;
 pub use icons::*;
 pub use is::*;
+use ls_colors::*;
 pub use theme::*;
@@ -34,11 +34,12 @@ impl FromStr for Theme {
 		theme.manager.validate()?;
 		theme.which.validate()?;
 
-		if theme.flavor.use_.is_empty() {
+		let flavor = theme.flavor.active();
+		if flavor.is_empty() {
 			theme.manager.syntect_theme = expand_path(&theme.manager.syntect_theme);
 		} else {
 			theme.manager.syntect_theme =
-				Xdg::config_dir().join(format!("flavors/{}.yazi/tmtheme.xml", theme.flavor.use_));
+				Xdg::config_dir().join(format!("flavors/{flavor}.yazi/tmtheme.xml"));
 		}
 
 		Ok(theme)
@@ -50,8 +51,9 @@ pub struct Manager {
 	cwd: Style,
 
 	// Hovered
-	hovered:         Style,
-	preview_hovered: Style,
+	hovered:          Style,
+	preview_hovered:  Style,
+	pub preview_line: Style,
 
 	// Find
 	find_keyword:  Style,
@@ -62,6 +64,8 @@ pub struct Manager {
 	marker_cut:      Style,
 	marker_marked:   Style,
 	marker_selected: Style,
+	entry_copied:    Style,
+	entry_cut:       Style,
 
 	// Tab
 	tab_active:   Style,
@@ -78,6 +82,10 @@ pub struct Manager {
 	pub border_symbol: String,
 	pub border_style:  Style,
 
+	// Scrollbar
+	pub scrollbar_symbol: String,
+	pub scrollbar_style:  Style,
+
 	// Highlighting
 	pub syntect_theme: PathBuf,
 }
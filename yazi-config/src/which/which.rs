@@ -12,6 +12,9 @@ pub struct Which {
 	pub sort_sensitive: bool,
 	pub sort_reverse:   bool,
 	pub sort_translit:  bool,
+
+	// Milliseconds to wait for the next key of a chord, 0 to wait forever
+	pub timeout: u64,
 }
 
 impl FromStr for Which {
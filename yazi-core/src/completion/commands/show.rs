@@ -1,4 +1,4 @@
-use std::{mem, ops::ControlFlow};
+use std::mem;
 
 use yazi_shared::{event::{Cmd, Data}, render};
 
@@ -25,35 +25,50 @@ impl From<Cmd> for Opt {
 }
 
 impl Completion {
+	// Prefix matches come first, then the ones containing the word, and last the ones
+	// that only have its characters in order, e.g. "tsw" for "tab_switch"
 	fn match_candidates(word: &str, cache: &[String]) -> Vec<String> {
 		let smart = !word.bytes().any(|c| c.is_ascii_uppercase());
 
-		let flow = cache.iter().try_fold(
-			(Vec::with_capacity(LIMIT), Vec::with_capacity(LIMIT)),
-			|(mut prefixed, mut fuzzy), s| {
-				if (smart && s.to_lowercase().starts_with(word)) || (!smart && s.starts_with(word)) {
-					if s != word {
-						prefixed.push(s);
-						if prefixed.len() >= LIMIT {
-							return ControlFlow::Break((prefixed, fuzzy));
-						}
-					}
-				} else if fuzzy.len() < LIMIT - prefixed.len() && s.contains(word) {
-					// here we don't break the control flow, since we want more exact matching.
-					fuzzy.push(s)
+		let mut tiers: [Vec<&String>; 3] = Default::default();
+		for s in cache {
+			let lower;
+			let t = if smart {
+				lower = s.to_lowercase();
+				&lower
+			} else {
+				s
+			};
+
+			let tier = if t.starts_with(word) {
+				if s == word {
+					continue;
 				}
-				ControlFlow::Continue((prefixed, fuzzy))
-			},
-		);
+				0
+			} else if t.contains(word) {
+				1
+			} else if Self::subsequence(word, t) {
+				2
+			} else {
+				continue;
+			};
 
-		let (mut prefixed, fuzzy) = match flow {
-			ControlFlow::Continue(v) => v,
-			ControlFlow::Break(v) => v,
-		};
-		if prefixed.len() < LIMIT {
-			prefixed.extend(fuzzy.into_iter().take(LIMIT - prefixed.len()))
+			// Later tiers only fill what's left, so there's no need to keep more of them
+			if tiers[tier].len() < LIMIT {
+				tiers[tier].push(s);
+			}
+			if tiers[0].len() >= LIMIT {
+				break;
+			}
 		}
-		prefixed.into_iter().map(ToOwned::to_owned).collect()
+
+		tiers.into_iter().flatten().take(LIMIT).map(ToOwned::to_owned).collect()
+	}
+
+	#[inline]
+	fn subsequence(word: &str, s: &str) -> bool {
+		let mut it = s.chars();
+		word.chars().all(|c| it.any(|x| x == c))
 	}
 
 	pub fn show(&mut self, opt: impl Into<Opt>) {
@@ -81,3 +96,23 @@ impl Completion {
 		render!();
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn matches(word: &str, cache: &[&str]) -> Vec<String> {
+		let cache: Vec<_> = cache.iter().map(|&s| s.to_owned()).collect();
+		Completion::match_candidates(word, &cache)
+	}
+
+	#[test]
+	fn test_match() {
+		let cache = ["tab_switch", "tab_swap", "switch", "tasks_show", "select"];
+		assert_eq!(matches("tab_sw", &cache), ["tab_switch", "tab_swap"]);
+		assert_eq!(matches("switch", &cache), ["tab_switch"]);
+		assert_eq!(matches("tsw", &cache), ["tab_switch", "tab_swap", "tasks_show"]);
+		assert_eq!(matches("sw", &cache), ["switch", "tab_switch", "tab_swap", "tasks_show"]);
+		assert_eq!(matches("TSW", &cache), Vec::<String>::new());
+	}
+}
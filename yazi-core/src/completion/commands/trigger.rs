@@ -13,6 +13,7 @@ const SEPARATOR: char = std::path::MAIN_SEPARATOR;
 
 pub struct Opt {
 	word:   String,
+	cands:  Option<Vec<String>>,
 	ticket: usize,
 }
 
@@ -20,6 +21,7 @@ impl From<Cmd> for Opt {
 	fn from(mut c: Cmd) -> Self {
 		Self {
 			word:   c.take_first_str().unwrap_or_default(),
+			cands:  c.take_any("cands"),
 			ticket: c.get("ticket").and_then(Data::as_usize).unwrap_or(0),
 		}
 	}
@@ -33,6 +35,16 @@ impl Completion {
 		}
 
 		self.ticket = opt.ticket;
+		if let Some(cands) = opt.cands {
+			return self.show(
+				Cmd::new("show")
+					.with_any("cache", cands)
+					.with("cache-name", "")
+					.with("word", opt.word)
+					.with("ticket", opt.ticket),
+			);
+		}
+
 		let Some((parent, child)) = Self::split_path(&opt.word) else {
 			return self.close(false);
 		};
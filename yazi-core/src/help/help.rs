@@ -1,8 +1,7 @@
 use crossterm::event::KeyCode;
-use unicode_width::UnicodeWidthStr;
 use yazi_adapter::Dimension;
-use yazi_config::{KEYMAP, keymap::{Chord, Key}};
-use yazi_shared::{Layer, render, render_and};
+use yazi_config::{KEYMAP, LOCALE, keymap::{Chord, Key}};
+use yazi_shared::{Layer, render, render_and, str_width};
 
 use super::HELP_MARGIN;
 use crate::input::Input;
@@ -11,7 +10,7 @@ use crate::input::Input;
 pub struct Help {
 	pub visible:         bool,
 	pub layer:           Layer,
-	pub(super) bindings: Vec<&'static Chord>,
+	pub(super) bindings: Vec<Chord>,
 
 	// Filter
 	pub(super) keyword:   String,
@@ -69,10 +68,11 @@ impl Help {
 
 		if kw.is_empty() {
 			self.keyword = String::new();
-			self.bindings = KEYMAP.get(self.layer).iter().collect();
+			self.bindings = KEYMAP.load().get(self.layer).clone();
 		} else if self.keyword != kw {
 			self.keyword = kw.to_owned();
-			self.bindings = KEYMAP.get(self.layer).iter().filter(|&c| c.contains(kw)).collect();
+			self.bindings =
+				KEYMAP.load().get(self.layer).iter().filter(|&c| c.contains(kw)).cloned().collect();
 		}
 
 		self.arrow(0);
@@ -88,12 +88,12 @@ impl Help {
 			.as_ref()
 			.map(|i| i.value())
 			.or(Some(self.keyword.as_str()).filter(|&s| !s.is_empty()))
-			.map(|s| format!("Filter: {}", s))
+			.map(|s| LOCALE.help_filter.replace("{keyword}", s))
 	}
 
 	// --- Bindings
 	#[inline]
-	pub fn window(&self) -> &[&Chord] {
+	pub fn window(&self) -> &[Chord] {
 		let end = (self.offset + Self::limit()).min(self.bindings.len());
 		&self.bindings[self.offset..end]
 	}
@@ -105,7 +105,7 @@ impl Help {
 			return None;
 		}
 		if let Some(kw) = self.keyword() {
-			return Some((kw.width() as u16, Dimension::available().rows));
+			return Some((str_width(&kw) as u16, Dimension::available().rows));
 		}
 		None
 	}
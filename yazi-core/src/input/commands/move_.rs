@@ -1,5 +1,4 @@
-use unicode_width::UnicodeWidthStr;
-use yazi_shared::{event::{Cmd, Data}, render};
+use yazi_shared::{event::{Cmd, Data}, render, str_width};
 
 use crate::input::{Input, op::InputOp, snap::InputSnap};
 
@@ -10,8 +9,9 @@ pub struct Opt {
 
 impl From<Cmd> for Opt {
 	fn from(c: Cmd) -> Self {
+		let step = c.first().and_then(Data::as_isize).unwrap_or(0);
 		Self {
-			step:         c.first().and_then(Data::as_isize).unwrap_or(0),
+			step:         step.saturating_mul(c.get("count").and_then(Data::as_isize).unwrap_or(1)),
 			in_operating: c.bool("in-operating"),
 		}
 	}
@@ -46,7 +46,7 @@ impl Input {
 		} else {
 			let delta = snap.mode.delta();
 			let s = snap.slice(snap.offset..snap.cursor + delta);
-			if s.width() >= limit {
+			if str_width(s) >= limit {
 				let s = s.chars().rev().collect::<String>();
 				snap.offset = snap.cursor - InputSnap::find_window(&s, 0, limit).end.saturating_sub(delta);
 			}
@@ -1,10 +1,10 @@
 use std::ops::Range;
 
 use tokio::sync::mpsc::UnboundedSender;
-use unicode_width::UnicodeWidthStr;
+use yazi_adapter::Dimension;
 use yazi_config::{INPUT, popup::Position};
 use yazi_plugin::CLIPBOARD;
-use yazi_shared::{InputError, render};
+use yazi_shared::{InputError, render, str_width};
 
 use super::{InputSnap, InputSnaps, mode::InputMode, op::InputOp};
 
@@ -29,7 +29,8 @@ pub struct Input {
 impl Input {
 	#[inline]
 	pub(super) fn limit(&self) -> usize {
-		self.position.offset.width.saturating_sub(INPUT.border()) as usize
+		let width = self.position.offset.width.min(Dimension::available().columns);
+		width.saturating_sub(INPUT.border()) as usize
 	}
 
 	pub fn type_str(&mut self, s: &str) {
@@ -112,7 +113,7 @@ impl Input {
 	#[inline]
 	pub fn cursor(&self) -> u16 {
 		let snap = self.snap();
-		snap.slice(snap.offset..snap.cursor).width() as u16
+		str_width(snap.slice(snap.offset..snap.cursor)) as u16
 	}
 
 	pub fn selected(&self) -> Option<Range<u16>> {
@@ -125,8 +126,8 @@ impl Input {
 		let win = snap.window(self.limit());
 		let Range { start, end } = start.max(win.start)..end.min(win.end);
 
-		let s = snap.slice(snap.offset..start).width() as u16;
-		Some(s..s + snap.slice(start..end).width() as u16)
+		let s = str_width(snap.slice(snap.offset..start)) as u16;
+		Some(s..s + str_width(snap.slice(start..end)) as u16)
 	}
 
 	#[inline]
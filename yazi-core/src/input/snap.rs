@@ -1,6 +1,6 @@
 use std::ops::Range;
 
-use unicode_width::UnicodeWidthChar;
+use yazi_shared::char_width;
 
 use super::{InputMode, InputOp};
 
@@ -33,8 +33,12 @@ impl InputSnap {
 	#[inline]
 	pub(super) fn reset(&mut self, limit: usize) {
 		self.cursor = self.cursor.min(self.value.chars().count().saturating_sub(self.mode.delta()));
-		self.offset =
-			self.offset.min(self.cursor.saturating_sub(Self::find_window(&self.rev(), 0, limit).end));
+
+		// Keep the cursor in the window, which is measured backwards from it rather than from the
+		// end of the value, as their widths differ once wide characters are mixed in
+		let end = (self.cursor + self.mode.delta()).min(self.count());
+		let rev: String = self.slice(0..end).chars().rev().collect();
+		self.offset = self.offset.min(end.saturating_sub(Self::find_window(&rev, 0, limit).end));
 	}
 }
 
@@ -61,9 +65,6 @@ impl InputSnap {
 		&self.value[s.unwrap()..e.unwrap()]
 	}
 
-	#[inline]
-	pub(super) fn rev(&self) -> String { self.value.chars().rev().collect::<String>() }
-
 	#[inline]
 	pub(super) fn window(&self, limit: usize) -> Range<usize> {
 		Self::find_window(&self.value, self.offset, limit)
@@ -77,7 +78,7 @@ impl InputSnap {
 			.enumerate()
 			.skip(offset)
 			.map_while(|(i, c)| {
-				width += c.width().unwrap_or(0);
+				width += char_width(c);
 				if width < limit { Some(i) } else { None }
 			})
 			.collect();
@@ -88,3 +89,30 @@ impl InputSnap {
 		*v.first().unwrap()..v.last().unwrap() + 1
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_find_window() {
+		assert_eq!(InputSnap::find_window("abcdef", 0, 4), 0..3);
+		assert_eq!(InputSnap::find_window("中文文件名", 0, 6), 0..2);
+		assert_eq!(InputSnap::find_window("中文文件名", 2, 7), 2..5);
+		assert_eq!(InputSnap::find_window("日本語のfile", 1, 8), 1..5);
+		assert_eq!(InputSnap::find_window("中", 0, 2), 0..0);
+	}
+
+	#[test]
+	fn test_reset_cjk() {
+		let mut snap = InputSnap::new("中文中文中文abc".to_owned(), 8);
+		assert_eq!((snap.cursor, snap.offset), (9, 4));
+
+		snap.cursor = 6;
+		snap.offset = usize::MAX;
+		snap.reset(8);
+
+		assert_eq!(snap.offset, 3);
+		assert_eq!(snap.window(8), 3..7);
+	}
+}
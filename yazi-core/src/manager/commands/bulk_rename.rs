@@ -6,12 +6,13 @@ use tokio::{fs::{self, OpenOptions}, io::{AsyncReadExt, AsyncWriteExt, stdin}};
 use yazi_config::{OPEN, PREVIEW};
 use yazi_dds::Pubsub;
 use yazi_proxy::{AppProxy, HIDER, TasksProxy, WATCHER};
+use yazi_scheduler::{JOURNAL, JournalOp};
 use yazi_shared::{fs::{File, FilesOp, Url, max_common_root, maybe_exists, paths_to_same_file}, terminal_clear};
 
 use crate::manager::Manager;
 
 impl Manager {
-	pub(super) fn bulk_rename(&self) {
+	pub(super) fn bulk_rename(&self, dry_run: bool) {
 		let Some(opener) = OPEN.block_opener("bulk.txt", "text/plain") else {
 			return AppProxy::notify_warn("Bulk rename", "No text opener found");
 		};
@@ -41,11 +42,16 @@ impl Manager {
 			AppProxy::stop().await;
 
 			let new: Vec<_> = fs::read_to_string(&tmp).await?.lines().map(PathBuf::from).collect();
-			Self::bulk_rename_do(root, old, new).await
+			Self::bulk_rename_do(root, old, new, dry_run).await
 		});
 	}
 
-	async fn bulk_rename_do(root: PathBuf, old: Vec<PathBuf>, new: Vec<PathBuf>) -> Result<()> {
+	async fn bulk_rename_do(
+		root: PathBuf,
+		old: Vec<PathBuf>,
+		new: Vec<PathBuf>,
+		dry_run: bool,
+	) -> Result<()> {
 		terminal_clear(&mut stderr())?;
 		if old.len() != new.len() {
 			eprintln!("Number of old and new differ, press ENTER to exit");
@@ -63,10 +69,19 @@ impl Manager {
 			for (o, n) in &todo {
 				writeln!(stderr, "{} -> {}", o.display(), n.display())?;
 			}
-			write!(stderr, "Continue to rename? (y/N): ")?;
+			if dry_run {
+				write!(stderr, "\nDry run, nothing has been renamed. Press ENTER to exit")?;
+			} else {
+				write!(stderr, "Continue to rename? (y/N): ")?;
+			}
 			stderr.flush()?;
 		}
 
+		if dry_run {
+			stdin().read_exact(&mut [0]).await?;
+			return Ok(());
+		}
+
 		let mut buf = [0; 10];
 		_ = stdin().read(&mut buf).await?;
 		if buf[0] != b'y' && buf[0] != b'Y' {
@@ -90,6 +105,12 @@ impl Manager {
 		}
 
 		if !succeeded.is_empty() {
+			JOURNAL.lock().record(
+				succeeded
+					.iter()
+					.map(|(o, n)| JournalOp::Move { from: o.clone(), to: n.url_owned() })
+					.collect(),
+			);
 			Pubsub::pub_from_bulk(succeeded.iter().map(|(o, n)| (o, &n.url)).collect());
 			FilesOp::rename(succeeded);
 		}
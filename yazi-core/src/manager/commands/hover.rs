@@ -1,6 +1,8 @@
 use std::{collections::HashSet, path::PathBuf};
 
 use yazi_dds::Pubsub;
+use yazi_plugin::external;
+use yazi_proxy::ManagerProxy;
 use yazi_shared::{event::{Cmd, Data}, fs::{Url, Urn}, render};
 
 use crate::manager::Manager;
@@ -33,6 +35,7 @@ impl Manager {
 
 		// Repeek
 		self.peek(false);
+		self.hover_grep();
 
 		// Refresh watcher
 		let mut to_watch = HashSet::with_capacity(3 * self.tabs.len());
@@ -49,6 +52,27 @@ impl Manager {
 
 		// Publish through DDS
 		Pubsub::pub_from_hover(self.active().idx, self.hovered().map(|h| &h.url));
+		ManagerProxy::hook("hover");
+	}
+
+	// Bring the first match of a file found by `rg` into view
+	fn hover_grep(&mut self) {
+		if let Some(handle) = self.active_mut().grep_line.take() {
+			handle.abort();
+		}
+
+		let Some((subject, args)) = self.active().grep.clone().filter(|_| self.cwd().is_search()) else {
+			return;
+		};
+		let Some(url) = self.hovered().filter(|h| !h.is_dir()).map(|h| h.url_owned()) else {
+			return;
+		};
+
+		self.active_mut().grep_line = Some(tokio::spawn(async move {
+			if let Some(line) = external::rg_line(&subject, &args, &url).await {
+				ManagerProxy::preview_line(line, &url);
+			}
+		}));
 	}
 
 	fn hover_do(&mut self, url: Url, tab: Option<usize>) {
@@ -0,0 +1,153 @@
+use std::{fs::Metadata, time::{SystemTime, UNIX_EPOCH}};
+
+use tokio::fs;
+use yazi_config::popup::SelectCfg;
+use yazi_proxy::SelectProxy;
+use yazi_shared::event::Cmd;
+
+use crate::manager::Manager;
+
+impl Manager {
+	pub fn info(&mut self, _: Cmd) {
+		let Some(hovered) = self.hovered() else { return };
+		let url = hovered.url.clone();
+		let mime = self.mimetype.get_owned(&url);
+
+		tokio::spawn(async move {
+			let Ok(meta) = fs::symlink_metadata(&url).await else { return };
+			let target = fs::read_link(&url).await.ok();
+
+			let mut items = vec![
+				line("Name", url.file_name().unwrap_or_default().to_string_lossy()),
+				line("Path", url.display()),
+				line("Type", kind(&meta)),
+				line("Size", format!("{} bytes", meta.len())),
+			];
+			if let Some(target) = target {
+				items.push(line("Target", target.display()));
+			}
+			if let Some(mime) = mime {
+				items.push(line("Mime", mime));
+			}
+
+			#[cfg(unix)]
+			{
+				use std::os::unix::fs::MetadataExt;
+
+				use uzers::{Groups, Users};
+				use yazi_shared::{USERS_CACHE, fs::permissions};
+
+				let user = USERS_CACHE.get_user_by_uid(meta.uid()).map(|u| u.name().to_owned());
+				let group = USERS_CACHE.get_group_by_gid(meta.gid()).map(|g| g.name().to_owned());
+				let mode = permissions(meta.mode() as _, false);
+				items.extend([
+					line("Mode", format!("{mode} ({:04o})", meta.mode() & 0o7777)),
+					line("Owner", match user {
+						Some(u) => u.to_string_lossy().into_owned(),
+						None => meta.uid().to_string(),
+					}),
+					line("Group", match group {
+						Some(g) => g.to_string_lossy().into_owned(),
+						None => meta.gid().to_string(),
+					}),
+					line("Links", meta.nlink()),
+					line("Inode", meta.ino()),
+				]);
+			}
+
+			for (name, time) in [
+				("Modified", meta.modified()),
+				("Accessed", meta.accessed()),
+				("Created", meta.created()),
+			] {
+				if let Ok(t) = time {
+					items.push(line(name, datetime(t)));
+				}
+			}
+
+			#[cfg(any(target_os = "linux", target_os = "macos"))]
+			items.extend(xattrs(&url));
+
+			SelectProxy::show(SelectCfg::info(items)).await.ok();
+		});
+	}
+}
+
+#[inline]
+fn line(key: &str, value: impl ToString) -> String {
+	format!("{:<10}{}", format!("{key}:"), value.to_string())
+}
+
+fn kind(meta: &Metadata) -> &'static str {
+	let ft = meta.file_type();
+	#[cfg(unix)]
+	{
+		use std::os::unix::fs::FileTypeExt;
+		if ft.is_block_device() {
+			return "Block device";
+		} else if ft.is_char_device() {
+			return "Character device";
+		} else if ft.is_fifo() {
+			return "FIFO";
+		} else if ft.is_socket() {
+			return "Socket";
+		}
+	}
+
+	if ft.is_dir() {
+		"Directory"
+	} else if ft.is_symlink() {
+		"Symbolic link"
+	} else {
+		"Regular file"
+	}
+}
+
+#[cfg(unix)]
+fn datetime(t: SystemTime) -> String {
+	let secs = t.duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs()) as libc::time_t;
+	let mut tm: libc::tm = unsafe { std::mem::zeroed() };
+	unsafe { libc::localtime_r(&secs, &mut tm) };
+
+	format!(
+		"{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+		tm.tm_year + 1900,
+		tm.tm_mon + 1,
+		tm.tm_mday,
+		tm.tm_hour,
+		tm.tm_min,
+		tm.tm_sec
+	)
+}
+
+#[cfg(windows)]
+fn datetime(t: SystemTime) -> String {
+	format!("{} seconds since the epoch", t.duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs()))
+}
+
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+fn xattrs(path: &std::path::Path) -> Vec<String> {
+	use yazi_shared::fs::{xattr_get, xattr_list};
+
+	let mut lines = vec![];
+	#[cfg(target_os = "macos")]
+	{
+		let tags = yazi_shared::fs::finder_tags(path);
+		if !tags.is_empty() {
+			lines.push(line("Tags", tags.join(", ")));
+		}
+	}
+
+	for name in xattr_list(path).unwrap_or_default() {
+		// Binary or lengthy values are summarized by their size
+		let value = match xattr_get(path, &name) {
+			Ok(b) => match std::str::from_utf8(&b) {
+				Ok(s) if s.len() <= 64 && !s.contains(char::is_control) => s.to_owned(),
+				_ => format!("({} bytes)", b.len()),
+			},
+			Err(_) => "(unreadable)".to_owned(),
+		};
+		lines.push(line("Xattr", format!("{name} = {value}")));
+	}
+	lines
+}
@@ -3,24 +3,35 @@ mod close;
 mod create;
 mod hardlink;
 mod hover;
+mod info;
 mod link;
 mod open;
+mod palette;
 mod paste;
 mod peek;
+mod preview_line;
 mod quit;
+mod ratio;
 mod refresh;
 mod remove;
 mod rename;
+mod restore;
 mod seek;
+mod send;
 mod suspend;
 mod tab_close;
 mod tab_create;
+mod tab_rename;
 mod tab_swap;
 mod tab_switch;
+mod undo;
+mod unfilter;
 mod unyank;
 mod update_files;
 mod update_mimetype;
 mod update_paged;
 mod update_task;
 mod update_yanked;
+mod xattr;
 mod yank;
+mod zen;
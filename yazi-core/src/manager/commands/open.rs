@@ -1,11 +1,11 @@
-use std::{borrow::Cow, ffi::OsString};
+use std::{borrow::Cow, collections::HashMap, ffi::OsString, ptr};
 
 use tracing::error;
 use yazi_boot::ARGS;
-use yazi_config::{OPEN, popup::SelectCfg};
+use yazi_config::{OPEN, open::Opener, popup::SelectCfg};
 use yazi_fs::Folder;
 use yazi_plugin::isolate;
-use yazi_proxy::{ManagerProxy, TasksProxy, options::OpenDoOpt};
+use yazi_proxy::{ManagerProxy, SelectProxy, TasksProxy, options::OpenDoOpt};
 use yazi_shared::{MIME_DIR, emit, event::{Cmd, EventQuit}, fs::{File, Url}};
 
 use crate::{manager::Manager, tasks::Tasks};
@@ -84,23 +84,60 @@ impl Manager {
 
 		if targets.is_empty() {
 			return;
-		} else if !opt.interactive {
-			return tasks.process_from_files(opt.hovered, targets);
 		}
 
-		let openers: Vec<_> = OPEN.common_openers(&targets);
+		ManagerProxy::hook("open");
+		if opt.interactive {
+			return Self::open_select(opt.hovered, targets, None);
+		}
+
+		// Files wanting different openers, let the user pick one for all, or keep them apart
+		let groups = Tasks::group_by_opener(&opt.hovered, &targets);
+		if groups.len() > 1 {
+			Self::open_select(opt.hovered, targets, Some(groups));
+		} else {
+			tasks.process_from_groups(groups);
+		}
+	}
+
+	fn open_select(
+		hovered: Url,
+		targets: Vec<(Url, String)>,
+		groups: Option<HashMap<&'static Opener, Vec<Url>>>,
+	) {
+		let mut openers = OPEN.common_openers(&targets);
 		if openers.is_empty() {
+			// None fits all of them, offer the ones that fit any
+			openers = OPEN.any_openers(&targets);
+		}
+
+		let mut items: Vec<_> = openers.iter().map(|o| o.desc.clone()).collect();
+		if let Some(g) = &groups {
+			items.insert(0, format!("Each with its default ({} openers)", g.len()));
+		} else if items.is_empty() {
 			return;
 		}
 
-		let urls = [opt.hovered].into_iter().chain(targets.into_iter().map(|(u, _)| u)).collect();
+		// What it would be opened with by default
+		let current = if groups.is_some() {
+			Some(0)
+		} else {
+			let (url, mime) = &targets[0];
+			let default = OPEN.openers(url, mime).and_then(|s| s.first().copied());
+			default.and_then(|d| openers.iter().position(|&o| ptr::eq(o, d)))
+		};
+
+		let urls = [hovered].into_iter().chain(targets.into_iter().map(|(u, _)| u)).collect();
 		tokio::spawn(async move {
-			let result = yazi_proxy::SelectProxy::show(SelectCfg::open(
-				openers.iter().map(|o| o.desc.clone()).collect(),
-			));
-			if let Ok(choice) = result.await {
-				TasksProxy::open_with(urls, Cow::Borrowed(openers[choice]));
+			let cfg = SelectCfg::open(items).with_current(current);
+			let Ok(mut choice) = SelectProxy::show(cfg).await else { return };
+			if let Some(groups) = groups {
+				if choice == 0 {
+					return groups.into_iter().for_each(|(o, u)| TasksProxy::open_with(u, Cow::Borrowed(o)));
+				}
+				choice -= 1;
 			}
+			TasksProxy::open_with(urls, Cow::Borrowed(openers[choice]));
 		});
 	}
 
@@ -0,0 +1,124 @@
+use std::str::FromStr;
+
+use yazi_config::{KEYMAP, popup::InputCfg};
+use yazi_proxy::{AppProxy, CompletionProxy, InputProxy};
+use yazi_shared::{InputError, Layer, emit, event::Cmd};
+
+use crate::manager::Manager;
+
+// Commands of the manager layer, and the prompt for their argument if they need one
+const COMMANDS: &[(&str, Option<&str>)] = &[
+	("escape", None),
+	("quit", None),
+	("close", None),
+	("suspend", None),
+	("arrow", Some("Step:")),
+	("goto", None),
+	("leave", None),
+	("enter", None),
+	("back", None),
+	("forward", None),
+	("cd", Some("Change directory:")),
+	("reveal", Some("Reveal:")),
+	("select", None),
+	("select_all", None),
+	("visual_mode", None),
+	("zoom", None),
+	("zen", None),
+	("preview_line", Some("Preview line:")),
+	("quicklook", None),
+	("open", None),
+	("yank", None),
+	("unyank", None),
+	("paste", None),
+	("link", None),
+	("hardlink", None),
+	("remove", None),
+	("create", None),
+	("rename", None),
+	("undo", None),
+	("restore", None),
+	("send", None),
+	("copy", Some("Copy (path, dirname, filename, name_without_ext, uri, preview):")),
+	("drag", None),
+	("shell", None),
+	("subshell", None),
+	("hidden", None),
+	("info", None),
+	("xattr", None),
+	("linemode", Some("Linemode:")),
+	("linemode --interactive", None),
+	("search", Some("Search via (fd, rg, none):")),
+	("filter", None),
+	("unfilter", None),
+	("find", None),
+	("find_arrow", None),
+	("sort", Some("Sort by:")),
+	("sort --interactive", None),
+	("ratio", Some("Ratio (parent, current, preview):")),
+	("tab_create", None),
+	("tab_close", None),
+	("tab_switch", Some("Switch to tab:")),
+	("tab_swap", Some("Swap with tab:")),
+	("tab_rename", Some("Tab title:")),
+	("tasks_show", None),
+	("notify_history", None),
+	("help", None),
+	("plugin", Some("Plugin:")),
+];
+
+// Commands whose prompted argument is split into several values
+const MULTI_VALUED: &[&str] = &["ratio"];
+
+impl Manager {
+	pub fn palette(&mut self, _: Cmd) {
+		tokio::spawn(async move {
+			let mut rx = InputProxy::show(InputCfg::palette());
+			while let Some(result) = rx.recv().await {
+				match result {
+					Ok(s) => return Self::palette_do(s).await,
+					// Only the command name is completed, not the arguments
+					Err(InputError::Completed(before, _)) if before.contains(char::is_whitespace) => {
+						CompletionProxy::close();
+					}
+					Err(InputError::Completed(before, ticket)) => {
+						CompletionProxy::trigger_with(&before, Self::palette_cands(), ticket);
+					}
+					_ => break,
+				}
+			}
+		});
+	}
+
+	async fn palette_do(s: String) {
+		let mut cmd = match Cmd::from_str(&s) {
+			Ok(c) => c,
+			Err(e) => return AppProxy::notify_warn("Command palette", e),
+		};
+
+		// Only prompt for a bare command, any argument like `--interactive` says enough
+		let prompt = COMMANDS.iter().find(|&&(name, _)| name == cmd.name).and_then(|&(_, p)| p);
+		if let Some(prompt) = prompt.filter(|_| cmd.args.is_empty()) {
+			let cfg = InputCfg { title: prompt.to_owned(), completion: false, ..InputCfg::palette() };
+			let Some(Ok(arg)) = InputProxy::show(cfg).recv().await else { return };
+
+			// Taken as a single value as is, e.g. a path with spaces, unless the command takes several
+			cmd = if MULTI_VALUED.contains(&cmd.name.as_str()) {
+				match Cmd::from_str(&format!("{s} {arg}")) {
+					Ok(c) => c,
+					Err(e) => return AppProxy::notify_warn("Command palette", e),
+				}
+			} else {
+				cmd.with(0, arg)
+			};
+		}
+
+		emit!(Call(cmd, Layer::Manager));
+	}
+
+	fn palette_cands() -> Vec<String> {
+		let mut cands: Vec<_> = COMMANDS.iter().map(|&(name, _)| name.to_owned()).collect();
+		cands.extend(KEYMAP.load().aliases.keys().cloned());
+		cands
+	}
+}
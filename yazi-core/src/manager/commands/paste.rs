@@ -1,28 +1,55 @@
+use std::fmt::Display;
+
+use yazi_proxy::AppProxy;
+use yazi_scheduler::Conflict;
 use yazi_shared::event::Cmd;
 
-use crate::{manager::Manager, tasks::Tasks};
+use crate::{manager::{Manager, Plan}, tasks::Tasks};
 
 pub struct Opt {
-	force:  bool,
-	follow: bool,
+	conflict: Conflict,
+	follow:   bool,
+	limit:    Option<u64>,
+	dry_run:  bool,
+	reveal:   bool,
 }
 
-impl From<Cmd> for Opt {
-	fn from(c: Cmd) -> Self { Self { force: c.bool("force"), follow: c.bool("follow") } }
+impl TryFrom<Cmd> for Opt {
+	type Error = anyhow::Error;
+
+	fn try_from(c: Cmd) -> Result<Self, Self::Error> {
+		Ok(Self {
+			conflict: Conflict::try_from(&c)?,
+			follow:   c.bool("follow"),
+			limit:    c.str("limit").and_then(|s| s.parse().ok()),
+			dry_run:  c.bool("dry-run"),
+			reveal:   c.bool("reveal"),
+		})
+	}
 }
 
 impl Manager {
-	pub fn paste(&mut self, opt: impl Into<Opt>, tasks: &Tasks) {
-		let opt = opt.into() as Opt;
+	pub fn paste(&mut self, opt: impl TryInto<Opt, Error = impl Display>, tasks: &Tasks) {
+		let opt = match opt.try_into() {
+			Ok(o) => o as Opt,
+			Err(e) => return AppProxy::notify_warn("Paste", e),
+		};
 		let (src, dest) = (self.yanked.iter().collect::<Vec<_>>(), self.cwd());
 
+		if opt.dry_run {
+			let (src, dest) = (src.into_iter().cloned().collect(), dest.clone());
+			let plan = Plan::paste(src, dest, self.yanked.cut, opt.conflict);
+			tokio::spawn(async move { plan.await.show().await });
+			return;
+		}
+
 		if self.yanked.cut {
-			tasks.file_cut(&src, dest, opt.force);
+			tasks.file_cut(&src, dest, opt.conflict, opt.limit, opt.reveal);
 
 			self.tabs.iter_mut().for_each(|t| _ = t.selected.remove_many(&src, false));
 			self.unyank(());
 		} else {
-			tasks.file_copy(&src, dest, opt.force, opt.follow);
+			tasks.file_copy(&src, dest, opt.conflict, opt.follow, opt.limit, opt.reveal);
 		}
 	}
 }
@@ -23,9 +23,15 @@ impl From<Cmd> for Opt {
 impl From<bool> for Opt {
 	fn from(force: bool) -> Self { Self { force, ..Default::default() } }
 }
+impl From<usize> for Opt {
+	fn from(skip: usize) -> Self { Self { skip: Some(skip), ..Default::default() } }
+}
 
 impl Manager {
 	pub fn peek(&mut self, opt: impl Into<Opt>) {
+		if self.tabs.zen {
+			return self.active_mut().preview.reset();
+		}
 		let Some(hovered) = self.hovered().cloned() else {
 			return self.active_mut().preview.reset();
 		};
@@ -35,6 +41,7 @@ impl Manager {
 
 		if !self.active().preview.same_url(&hovered.url) {
 			self.active_mut().preview.skip = folder.map(|f| f.0).unwrap_or_default();
+			self.active_mut().preview.line = None;
 		}
 		if !self.active().preview.same_file(&hovered, &mime) {
 			self.active_mut().preview.reset();
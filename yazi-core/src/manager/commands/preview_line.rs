@@ -0,0 +1,35 @@
+use yazi_config::LAYOUT;
+use yazi_shared::{event::{Cmd, Data}, fs::Url, render};
+
+use crate::manager::Manager;
+
+pub struct Opt {
+	line:    Option<usize>,
+	only_if: Option<Url>,
+}
+
+impl From<Cmd> for Opt {
+	fn from(mut c: Cmd) -> Self {
+		Self {
+			line:    c.first().and_then(Data::as_usize),
+			only_if: c.take("only-if").and_then(Data::into_url),
+		}
+	}
+}
+
+impl Manager {
+	// Scroll the preview to the 1-based `line` with it centered, and highlight it
+	pub fn preview_line(&mut self, opt: impl Into<Opt>) {
+		let opt = opt.into() as Opt;
+		let Some(line) = opt.line.filter(|&n| n > 0) else { return };
+
+		let Some(hovered) = self.hovered().filter(|h| !h.is_dir()) else { return };
+		if matches!(opt.only_if, Some(u) if u != hovered.url) {
+			return;
+		}
+
+		self.peek((line - 1).saturating_sub(LAYOUT.load().preview.height as usize / 2));
+		self.active_mut().preview.line = Some(line);
+		render!();
+	}
+}
@@ -0,0 +1,38 @@
+use mlua::{LuaSerdeExt, Table};
+use yazi_config::manager::ManagerRatio;
+use yazi_plugin::LUA;
+use yazi_proxy::AppProxy;
+use yazi_shared::{Layer, emit, event::Cmd};
+
+use crate::manager::Manager;
+
+pub struct Opt {
+	ratio: Vec<u16>,
+}
+
+impl From<Cmd> for Opt {
+	fn from(c: Cmd) -> Self {
+		Self {
+			ratio: (0..3).filter_map(|i| c.get(&i.to_string())?.as_usize()).map(|n| n as u16).collect(),
+		}
+	}
+}
+
+impl Manager {
+	pub fn ratio(&mut self, opt: impl Into<Opt>) {
+		let ratio = match ManagerRatio::try_from(opt.into().ratio) {
+			Ok(r) => r,
+			Err(e) => return AppProxy::notify_warn("Layout ratio", e),
+		};
+
+		let result = LUA
+			.globals()
+			.raw_get::<_, Table>("MANAGER")
+			.and_then(|t| t.raw_set("ratio", LUA.to_value(&ratio)?));
+
+		match result {
+			Ok(()) => emit!(Call(Cmd::new("resize"), Layer::App)),
+			Err(e) => AppProxy::notify_warn("Layout ratio", e),
+		}
+	}
+}
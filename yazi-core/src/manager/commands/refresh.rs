@@ -1,8 +1,9 @@
-use std::{env, path::MAIN_SEPARATOR};
+use std::{borrow::Cow, env, path::MAIN_SEPARATOR};
 
 use crossterm::{execute, terminal::SetTitle};
 use yazi_config::MANAGER;
-use yazi_shared::event::Cmd;
+use yazi_fs::FolderStage;
+use yazi_shared::{event::Cmd, fs::FilesOp};
 
 use crate::{manager::Manager, tasks::Tasks};
 
@@ -15,6 +16,8 @@ impl Manager {
 			execute!(std::io::stderr(), SetTitle(self.title())).ok();
 		}
 
+		self.adopt_listings(tasks);
+		self.active_mut().apply_overrides();
 		self.active_mut().apply_files_attrs();
 
 		if let Some(p) = self.parent() {
@@ -29,7 +32,33 @@ impl Manager {
 		tasks.prework_sorted(&self.current().files);
 	}
 
-	fn title(&self) -> String {
+	// Take over listings that other tabs have already loaded, instead of reading them again,
+	// going through the same path as the updates from the watcher
+	fn adopt_listings(&mut self, tasks: &Tasks) {
+		let idx = self.tabs.cursor;
+		let mut ops = vec![];
+		for folder in [Some(self.current()), self.parent()].into_iter().flatten() {
+			if folder.stage == FolderStage::Loaded {
+				continue;
+			}
+
+			let loaded = self.tabs.iter().enumerate().filter(|&(i, _)| i != idx).find_map(|(_, t)| {
+				[Some(&t.current), t.parent.as_ref(), t.history.get(&folder.url)]
+					.into_iter()
+					.flatten()
+					.find(|f| f.url == folder.url && f.stage == FolderStage::Loaded)
+			});
+			if let Some(f) = loaded {
+				ops.push(FilesOp::Full(f.url.clone(), f.files.all().cloned().collect(), f.cha));
+			}
+		}
+
+		for op in ops {
+			Self::update_tab(self.active_mut(), Cow::Owned(op), tasks);
+		}
+	}
+
+	pub fn title(&self) -> String {
 		let home = dirs::home_dir().unwrap_or_default();
 		let cwd = if let Ok(p) = self.cwd().strip_prefix(home) {
 			format!("~{}{}", MAIN_SEPARATOR, p.display())
@@ -1,12 +1,17 @@
+use std::time::Duration;
+
+use tokio::{fs, time::timeout};
 use yazi_config::popup::ConfirmCfg;
 use yazi_proxy::{ConfirmProxy, ManagerProxy};
-use yazi_shared::{event::Cmd, fs::Url};
+use yazi_shared::{event::Cmd, fs::{Url, calculate_size}};
 
-use crate::{manager::Manager, tasks::Tasks};
+use crate::{manager::{Manager, Plan}, tasks::Tasks};
 
 pub struct Opt {
 	force:       bool,
 	permanently: bool,
+	shred:       bool,
+	dry_run:     bool,
 	hovered:     bool,
 	targets:     Vec<Url>,
 }
@@ -16,6 +21,8 @@ impl From<Cmd> for Opt {
 		Self {
 			force:       c.bool("force"),
 			permanently: c.bool("permanently"),
+			shred:       c.bool("shred"),
+			dry_run:     c.bool("dry-run"),
 			hovered:     c.bool("hovered"),
 			targets:     c.take_any("targets").unwrap_or_default(),
 		}
@@ -38,19 +45,49 @@ impl Manager {
 			self.selected_or_hovered(true).cloned().collect()
 		};
 
-		if opt.force {
+		if opt.dry_run {
+			let plan = Plan::remove(opt.targets, opt.permanently, opt.shred);
+			tokio::spawn(async move { plan.await.show().await });
+			return;
+		}
+
+		// Shredding is irreversible, always ask for confirmation
+		if opt.force && !opt.shred {
 			return self.remove_do(opt, tasks);
 		}
 
 		tokio::spawn(async move {
-			let result = ConfirmProxy::show(if opt.permanently {
-				ConfirmCfg::delete(&opt.targets)
+			// Walking huge trees would hold the dialog back, so fall back to the sizes
+			// of the top-level entries after a while
+			let walk = timeout(Duration::from_millis(500), async {
+				let mut n = 0;
+				for u in &opt.targets {
+					n += calculate_size(u).await;
+				}
+				n
+			});
+
+			let (size, partial) = match walk.await {
+				Ok(n) => (n, false),
+				Err(_) => {
+					let mut n = 0;
+					for u in &opt.targets {
+						n += fs::symlink_metadata(u).await.map_or(0, |m| m.len());
+					}
+					(n, true)
+				}
+			};
+
+			let result = ConfirmProxy::show(if opt.shred {
+				ConfirmCfg::shred(&opt.targets, size, partial)
+			} else if opt.permanently {
+				ConfirmCfg::delete(&opt.targets, size, partial)
 			} else {
-				ConfirmCfg::trash(&opt.targets)
+				ConfirmCfg::trash(&opt.targets, size, partial)
 			});
 
 			if result.await {
-				ManagerProxy::remove_do(opt.targets, opt.permanently);
+				ManagerProxy::remove_do(opt.targets, opt.permanently, opt.shred);
 			}
 		});
 	}
@@ -67,6 +104,10 @@ impl Manager {
 		}
 
 		self.yanked.catchup_revision(false);
-		tasks.file_remove(opt.targets, opt.permanently);
+		if opt.shred {
+			tasks.file_shred(opt.targets);
+		} else {
+			tasks.file_remove(opt.targets, opt.permanently);
+		}
 	}
 }
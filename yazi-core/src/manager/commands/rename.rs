@@ -5,13 +5,15 @@ use tokio::fs;
 use yazi_config::popup::{ConfirmCfg, InputCfg};
 use yazi_dds::Pubsub;
 use yazi_proxy::{ConfirmProxy, InputProxy, TabProxy, WATCHER};
-use yazi_shared::{event::Cmd, fs::{File, FilesOp, Url, UrnBuf, maybe_exists, ok_or_not_found, paths_to_same_file, realname}};
+use yazi_scheduler::{JOURNAL, JournalOp};
+use yazi_shared::{event::Cmd, fs::{File, FilesOp, Url, UrnBuf, maybe_exists, ok_or_not_found, paths_to_same_file, realname, unlossy}};
 
-use crate::manager::Manager;
+use crate::manager::{Manager, Plan};
 
 pub struct Opt {
 	hovered: bool,
 	force:   bool,
+	dry_run: bool,
 	empty:   String,
 	cursor:  String,
 }
@@ -21,6 +23,7 @@ impl From<Cmd> for Opt {
 		Self {
 			hovered: c.bool("hovered"),
 			force:   c.bool("force"),
+			dry_run: c.bool("dry-run"),
 			empty:   c.take_str("empty").unwrap_or_default(),
 			cursor:  c.take_str("cursor").unwrap_or_default(),
 		}
@@ -38,7 +41,7 @@ impl Manager {
 
 		let opt = opt.into() as Opt;
 		if !opt.hovered && !self.active().selected.is_empty() {
-			return self.bulk_rename();
+			return self.bulk_rename(opt.dry_run);
 		}
 
 		let name = Self::empty_url_part(&hovered, &opt.empty);
@@ -49,7 +52,8 @@ impl Manager {
 				.rev()
 				.position(|c| c == '.')
 				.map(|i| name.chars().count() - i - 1)
-				.filter(|&i| i != 0),
+				// A leading dot is a dotfile, unless only the extension was kept
+				.filter(|&i| i != 0 || opt.empty == "stem"),
 			_ => None,
 		};
 
@@ -64,8 +68,10 @@ impl Manager {
 				return;
 			}
 
-			let new = Url::from(hovered.parent().unwrap().join(name));
-			if opt.force || !maybe_exists(&new).await || paths_to_same_file(&hovered, &new).await {
+			let new = Url::from(hovered.parent().unwrap().join(unlossy(hovered.name(), &name)));
+			if opt.dry_run {
+				Plan::rename(hovered, new).await.show().await;
+			} else if opt.force || !maybe_exists(&new).await || paths_to_same_file(&hovered, &new).await {
 				Self::rename_do(tab, hovered, new).await.ok();
 			} else if ConfirmProxy::show(ConfirmCfg::overwrite(&new)).await {
 				Self::rename_do(tab, hovered, new).await.ok();
@@ -86,6 +92,7 @@ impl Manager {
 			FilesOp::Deleting(p_new.clone(), HashSet::from_iter([UrnBuf::from(o)])).emit();
 		}
 		Pubsub::pub_from_rename(tab, &old, &new);
+		JOURNAL.lock().record(vec![JournalOp::Move { from: old.clone(), to: new.clone() }]);
 
 		let file = File::from(new.clone()).await?;
 		if p_new == p_old {
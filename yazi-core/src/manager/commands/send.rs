@@ -0,0 +1,48 @@
+use std::fmt::Display;
+
+use yazi_proxy::AppProxy;
+use yazi_scheduler::Conflict;
+use yazi_shared::event::Cmd;
+
+use crate::{manager::Manager, tasks::Tasks};
+
+pub struct Opt {
+	cut:      bool,
+	conflict: Conflict,
+	follow:   bool,
+}
+
+impl TryFrom<Cmd> for Opt {
+	type Error = anyhow::Error;
+
+	fn try_from(c: Cmd) -> Result<Self, Self::Error> {
+		Ok(Self { cut: c.bool("cut"), conflict: Conflict::try_from(&c)?, follow: c.bool("follow") })
+	}
+}
+
+impl Manager {
+	pub fn send(&mut self, opt: impl TryInto<Opt, Error = impl Display>, tasks: &Tasks) {
+		let opt = match opt.try_into() {
+			Ok(o) => o as Opt,
+			Err(e) => return AppProxy::notify_warn("Send", e),
+		};
+		let Some(other) = self.tabs.other() else {
+			return AppProxy::notify_warn("Send", "There is no other pane to send the files to");
+		};
+
+		let dest = self.tabs[other].cwd().clone();
+		if !dest.is_regular() {
+			return AppProxy::notify_warn("Send", "Cannot send files to a search result");
+		}
+
+		let src: Vec<_> = self.active().selected_or_hovered(false).cloned().collect();
+		let src: Vec<_> = src.iter().collect();
+		if opt.cut {
+			tasks.file_cut(&src, &dest, opt.conflict, None, false);
+			self.tabs.iter_mut().for_each(|t| _ = t.selected.remove_many(&src, false));
+		} else {
+			tasks.file_copy(&src, &dest, opt.conflict, opt.follow, None, false);
+			self.active_mut().escape_select();
+		}
+	}
+}
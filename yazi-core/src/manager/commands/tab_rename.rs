@@ -0,0 +1,32 @@
+use yazi_config::popup::InputCfg;
+use yazi_proxy::{Answer, PromptProxy};
+use yazi_shared::{Layer, emit, event::Cmd, render};
+
+use crate::manager::Tabs;
+
+pub struct Opt {
+	title: Option<String>,
+}
+
+impl From<Cmd> for Opt {
+	fn from(mut c: Cmd) -> Self { Self { title: c.take_first_str() } }
+}
+
+impl Tabs {
+	pub fn rename(&mut self, opt: impl Into<Opt>) {
+		let Some(title) = opt.into().title else {
+			let value = self.active().title.clone().unwrap_or_default();
+			tokio::spawn(async move {
+				let cfg = InputCfg::tab_rename().with_value(value);
+				if let Some(Answer::Input(title)) = PromptProxy::show(cfg).await {
+					emit!(Call(Cmd::args("tab_rename", &[title]), Layer::Manager));
+				}
+			});
+			return;
+		};
+
+		// An empty title goes back to showing the name of the CWD
+		self.active_mut().title = Some(title).filter(|s| !s.is_empty());
+		render!();
+	}
+}
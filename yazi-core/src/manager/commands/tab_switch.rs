@@ -5,18 +5,26 @@ use crate::manager::Tabs;
 pub struct Opt {
 	step:     isize,
 	relative: bool,
+	other:    bool,
 }
 
 impl From<Cmd> for Opt {
 	fn from(c: Cmd) -> Self {
-		Self { step: c.first().and_then(Data::as_isize).unwrap_or(0), relative: c.bool("relative") }
+		Self {
+			step:     c.first().and_then(Data::as_isize).unwrap_or(0),
+			relative: c.bool("relative"),
+			other:    c.bool("other"),
+		}
 	}
 }
 
 impl Tabs {
 	pub fn switch(&mut self, opt: impl Into<Opt>) {
 		let opt = opt.into() as Opt;
-		let idx = if opt.relative {
+		let idx = if opt.other {
+			let Some(idx) = self.other() else { return };
+			idx
+		} else if opt.relative {
 			(self.cursor as isize + opt.step).rem_euclid(self.items.len() as isize) as usize
 		} else {
 			opt.step as usize
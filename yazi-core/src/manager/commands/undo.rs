@@ -0,0 +1,7 @@
+use yazi_shared::event::Cmd;
+
+use crate::{manager::Manager, tasks::Tasks};
+
+impl Manager {
+	pub fn undo(&mut self, _: Cmd, tasks: &Tasks) { tasks.file_undo(); }
+}
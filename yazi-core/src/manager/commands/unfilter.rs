@@ -0,0 +1,37 @@
+use yazi_proxy::ManagerProxy;
+use yazi_shared::{event::Cmd, render};
+
+use crate::manager::Manager;
+
+pub struct Opt;
+
+impl From<Cmd> for Opt {
+	fn from(_: Cmd) -> Self { Self }
+}
+impl From<()> for Opt {
+	fn from(_: ()) -> Self { Self }
+}
+
+impl Manager {
+	// Clear the filters of every directory in every tab, including the ones left behind
+	pub fn unfilter(&mut self, _: impl Into<Opt>) {
+		let mut changed = false;
+		for tab in self.tabs.iter_mut() {
+			let hovered = tab.current.hovered().map(|f| f.urn_owned());
+			if tab.current.files.set_filter(None) {
+				tab.current.repos(hovered.as_ref().map(|u| u.as_urn()));
+				ManagerProxy::hover(None, tab.idx);
+				changed = true;
+			}
+
+			for folder in tab.parent.iter_mut().chain(tab.history.values_mut()) {
+				changed |= folder.files.set_filter(None);
+			}
+		}
+
+		if changed {
+			ManagerProxy::update_paged();
+			render!();
+		}
+	}
+}
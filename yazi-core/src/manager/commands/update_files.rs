@@ -1,6 +1,6 @@
 use std::borrow::Cow;
 
-use yazi_fs::Folder;
+use yazi_fs::FolderStage;
 use yazi_proxy::ManagerProxy;
 use yazi_shared::{event::Cmd, fs::FilesOp, render};
 
@@ -43,7 +43,7 @@ impl Manager {
 		self.active_mut().apply_files_attrs();
 	}
 
-	fn update_tab(tab: &mut Tab, op: Cow<FilesOp>, tasks: &Tasks) {
+	pub(super) fn update_tab(tab: &mut Tab, op: Cow<FilesOp>, tasks: &Tasks) {
 		let url = op.cwd();
 		tab.selected.apply_op(&op);
 
@@ -73,11 +73,24 @@ impl Manager {
 	}
 
 	fn update_current(tab: &mut Tab, op: Cow<FilesOp>, tasks: &Tasks) {
-		let hovered = tab.current.hovered().filter(|_| tab.current.tracing).map(|h| h.urn_owned());
+		// Once loaded, a reload keeps the hovered file even if the cursor never moved
+		let hovered = tab
+			.current
+			.hovered()
+			.filter(|_| tab.current.tracing || tab.current.stage == FolderStage::Loaded)
+			.map(|h| h.urn_owned());
 		let calc = !matches!(*op, FilesOp::Size(..) | FilesOp::Deleting(..));
 
+		// Modified in place, which leaves the listing as is but not the preview
+		let modified = tab.current.hovered().is_some_and(|h| {
+			matches!(&*op, FilesOp::Updating(_, m) | FilesOp::Upserting(_, m) if m.contains_key(h.urn()))
+		});
+
 		let foreign = matches!(op, Cow::Borrowed(_));
 		if !tab.current.update(op.into_owned()) {
+			if modified && !foreign {
+				ManagerProxy::peek(false);
+			}
 			return;
 		}
 
@@ -95,7 +108,7 @@ impl Manager {
 
 	fn update_hovered(tab: &mut Tab, op: Cow<FilesOp>) {
 		let url = op.cwd();
-		let folder = tab.history.entry(url.clone()).or_insert_with(|| Folder::from(url));
+		let folder = tab.history.get_or_insert(url);
 
 		let foreign = matches!(op, Cow::Borrowed(_));
 		if !folder.update(op.into_owned()) {
@@ -112,8 +125,11 @@ impl Manager {
 			|(p, n)| matches!(*op, FilesOp::Deleting(ref parent, ref urns) if *parent == p && urns.contains(n)),
 		);
 
-		let folder = tab.history.entry(op.cwd().clone()).or_insert_with(|| Folder::from(op.cwd()));
-		let hovered = folder.hovered().filter(|_| folder.tracing).map(|h| h.urn_owned());
+		let folder = tab.history.get_or_insert(op.cwd());
+		let hovered = folder
+			.hovered()
+			.filter(|_| folder.tracing || folder.stage == FolderStage::Loaded)
+			.map(|h| h.urn_owned());
 		if folder.update(op.into_owned()) {
 			folder.repos(hovered.as_ref().map(|u| u.as_urn()));
 		}
@@ -31,8 +31,12 @@ impl Manager {
 			return;
 		}
 
+		// Visible rows go in their own batch, so they don't wait for the precached pages
+		tasks.fetch_paged(self.current().window(), &self.mimetype);
+
 		let targets = self.current().paginate(opt.page.unwrap_or(self.current().page));
 		tasks.fetch_paged(targets, &self.mimetype);
 		tasks.preload_paged(targets, &self.mimetype);
+		tasks.prework_paged(targets, &self.current().files);
 	}
 }
@@ -0,0 +1,97 @@
+use std::io;
+
+use yazi_config::popup::InputCfg;
+use yazi_proxy::{Answer, AppProxy, PromptProxy};
+use yazi_shared::{event::Cmd, fs::Url};
+
+use crate::manager::Manager;
+
+pub struct Opt {
+	name:   Option<String>,
+	value:  Option<String>,
+	remove: bool,
+	tag:    bool,
+}
+
+impl From<Cmd> for Opt {
+	fn from(mut c: Cmd) -> Self {
+		Self {
+			name:   c.take_first_str(),
+			value:  c.take_str("1"),
+			remove: c.bool("remove"),
+			tag:    c.bool("tag"),
+		}
+	}
+}
+
+impl Manager {
+	pub fn xattr(&mut self, opt: impl Into<Opt>) {
+		let mut opt = opt.into() as Opt;
+		if !cfg!(any(target_os = "linux", target_os = "macos")) {
+			return AppProxy::notify_warn("Xattr", "Extended attributes are not supported here");
+		} else if opt.tag && !cfg!(target_os = "macos") {
+			return AppProxy::notify_warn("Xattr", "Finder tags are only available on macOS");
+		}
+
+		let targets: Vec<_> = self.selected_or_hovered(false).cloned().collect();
+		if targets.is_empty() {
+			return;
+		}
+
+		tokio::spawn(async move {
+			if opt.name.is_none() {
+				let Some(Answer::Input(s)) = PromptProxy::show(InputCfg::xattr()).await else { return };
+
+				// `name=value` sets, `name=` removes
+				let Some((name, value)) = s.split_once('=') else { return };
+				opt.remove = value.is_empty();
+				(opt.name, opt.value) = (Some(name.trim().to_owned()), Some(value.to_owned()));
+			}
+
+			let Some(name) = opt.name.filter(|s| !s.is_empty()) else { return };
+			let result = tokio::task::spawn_blocking(move || {
+				Self::xattr_do(&targets, &name, opt.value.as_deref(), opt.remove, opt.tag)
+			});
+
+			match result.await {
+				Ok(Err(e)) => AppProxy::notify_warn("Xattr", e),
+				Err(e) => AppProxy::notify_warn("Xattr", e),
+				Ok(Ok(())) => {}
+			}
+		});
+	}
+
+	#[cfg(any(target_os = "linux", target_os = "macos"))]
+	fn xattr_do(
+		targets: &[Url],
+		name: &str,
+		value: Option<&str>,
+		remove: bool,
+		tag: bool,
+	) -> io::Result<()> {
+		use yazi_shared::fs::{xattr_remove, xattr_set};
+
+		for url in targets {
+			if tag {
+				Self::xattr_tag(url, name, remove)?;
+			} else if remove {
+				xattr_remove(url, name)?;
+			} else {
+				xattr_set(url, name, value.unwrap_or_default().as_bytes())?;
+			}
+		}
+		Ok(())
+	}
+
+	#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+	fn xattr_do(_: &[Url], _: &str, _: Option<&str>, _: bool, _: bool) -> io::Result<()> { Ok(()) }
+
+	#[cfg(target_os = "macos")]
+	fn xattr_tag(url: &Url, tag: &str, remove: bool) -> io::Result<()> {
+		use yazi_shared::fs::{finder_tag_add, finder_tag_remove};
+		if remove { finder_tag_remove(url, tag) } else { finder_tag_add(url, tag) }
+	}
+
+	#[cfg(not(target_os = "macos"))]
+	fn xattr_tag(_: &Url, _: &str, _: bool) -> io::Result<()> { Ok(()) }
+}
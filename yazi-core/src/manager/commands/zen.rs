@@ -0,0 +1,12 @@
+use yazi_shared::{Layer, emit, event::Cmd};
+
+use crate::manager::Manager;
+
+impl Manager {
+	pub fn zen(&mut self, _: Cmd) {
+		self.tabs.zen = !self.tabs.zen;
+
+		// Lay out again, the preview is skipped while its pane is hidden
+		emit!(Call(Cmd::new("resize"), Layer::App));
+	}
+}
@@ -2,6 +2,8 @@ mod commands;
 mod linked;
 mod manager;
 mod mimetype;
+mod plan;
+mod session;
 mod tabs;
 mod watcher;
 mod yanked;
@@ -9,6 +11,8 @@ mod yanked;
 pub use linked::*;
 pub use manager::*;
 pub use mimetype::*;
+pub use plan::*;
+pub use session::*;
 pub use tabs::*;
 pub use watcher::*;
 pub use yanked::*;
@@ -0,0 +1,116 @@
+use std::path::Path;
+
+use tokio::fs;
+use yazi_config::popup::ConfirmCfg;
+use yazi_proxy::ConfirmProxy;
+use yazi_scheduler::Conflict;
+use yazi_shared::fs::{Url, maybe_exists, paths_to_same_file};
+
+// The plan of a dry run, which resolves conflicts and permission issues of
+// a batch operation without touching the filesystem.
+#[derive(Default)]
+pub struct Plan(Vec<String>);
+
+impl Plan {
+	pub async fn paste(src: Vec<Url>, dest: Url, cut: bool, conflict: Conflict) -> Self {
+		let mut plan = Self::default();
+		let verb = if cut { "move" } else { "copy" };
+
+		if !Self::writable(&dest).await {
+			plan.push(format!("Permission denied, cannot write into {dest:?}"));
+		}
+
+		for from in src {
+			let Ok(meta) = fs::symlink_metadata(&from).await else {
+				plan.push(format!("Would skip {from:?}, it no longer exists"));
+				continue;
+			};
+
+			let to = dest.join(from.file_name().unwrap());
+			if to.starts_with(&from) && to != from {
+				plan.push(format!("Cannot {verb} {from:?} into itself"));
+				continue;
+			} else if cut && !Self::writable(from.parent().unwrap_or(&from)).await {
+				plan.push(format!("Permission denied, cannot move {from:?} out of its directory"));
+			}
+
+			if !maybe_exists(&to).await {
+				plan.push(format!("Would {verb} {from:?} to {to:?}"));
+				continue;
+			} else if !matches!(conflict, Conflict::Unique | Conflict::Rename) && from == to {
+				plan.push(format!("Would skip {from:?}, it's the same file"));
+				continue;
+			}
+
+			match conflict {
+				Conflict::Unique | Conflict::Rename => match conflict.resolve(to).await {
+					Ok(to) => plan.push(format!("Would {verb} {from:?} to {to:?}")),
+					Err(e) => plan.push(format!("Cannot {verb} {from:?}: {e}")),
+				},
+				_ if meta.is_dir() => plan.push(format!("Would merge {from:?} into {to:?}")),
+				Conflict::Overwrite => plan.push(format!("Would overwrite {to:?} with {from:?}")),
+				_ if conflict.admit(&meta, &to).await => {
+					plan.push(format!("Would overwrite {to:?} with the newer {from:?}"))
+				}
+				_ => plan.push(format!("Would skip {from:?}, {to:?} already exists")),
+			}
+		}
+		plan
+	}
+
+	pub async fn remove(targets: Vec<Url>, permanently: bool, shred: bool) -> Self {
+		let mut plan = Self::default();
+		let verb = if shred {
+			"shred"
+		} else if permanently {
+			"permanently delete"
+		} else {
+			"trash"
+		};
+
+		for target in targets {
+			if !maybe_exists(&target).await {
+				plan.push(format!("Would skip {target:?}, it no longer exists"));
+			} else if !Self::writable(target.parent().unwrap_or(&target)).await {
+				plan.push(format!("Permission denied, cannot {verb} {target:?}"));
+			} else {
+				plan.push(format!("Would {verb} {target:?}"));
+			}
+		}
+		plan
+	}
+
+	pub async fn rename(old: Url, new: Url) -> Self {
+		let mut plan = Self::default();
+		if !Self::writable(new.parent().unwrap_or(&new)).await {
+			plan.push(format!("Permission denied, cannot rename {old:?} to {new:?}"));
+		} else if maybe_exists(&new).await && !paths_to_same_file(&old, &new).await {
+			plan.push(format!("Would rename {old:?} to {new:?}, overwriting the existing file"));
+		} else {
+			plan.push(format!("Would rename {old:?} to {new:?}"));
+		}
+		plan
+	}
+
+	pub async fn show(self) { ConfirmProxy::show(ConfirmCfg::dry_run(self.0)).await; }
+
+	#[inline]
+	fn push(&mut self, line: String) { self.0.push(line); }
+
+	#[cfg(unix)]
+	async fn writable(path: &Path) -> bool {
+		use std::{ffi::CString, os::unix::ffi::OsStrExt};
+
+		let Ok(path) = CString::new(path.as_os_str().as_bytes()) else {
+			return false;
+		};
+		tokio::task::spawn_blocking(move || unsafe { libc::access(path.as_ptr(), libc::W_OK) == 0 })
+			.await
+			.unwrap_or(false)
+	}
+
+	#[cfg(windows)]
+	async fn writable(path: &Path) -> bool {
+		fs::metadata(path).await.is_ok_and(|m| !m.permissions().readonly())
+	}
+}
@@ -0,0 +1,112 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+use yazi_boot::{ARGS, BOOT};
+use yazi_config::MANAGER;
+use yazi_shared::fs::Url;
+
+use super::Tabs;
+use crate::tab::{Config, Tab};
+
+// The tabs of the last session, saved on quit and restored with `--continue`
+#[derive(Deserialize, Serialize)]
+pub struct Session {
+	cursor: usize,
+	tabs:   Vec<SessionTab>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct SessionTab {
+	cwd:     PathBuf,
+	hovered: Option<PathBuf>,
+	#[serde(default)]
+	title:   Option<String>,
+
+	// Sorting
+	sort_by:        String,
+	sort_sensitive: bool,
+	sort_reverse:   bool,
+	sort_dir_first: bool,
+	sort_translit:  bool,
+
+	// Display
+	linemode:    String,
+	show_hidden: bool,
+}
+
+impl Session {
+	pub fn save(tabs: &Tabs) {
+		let session = Self {
+			// Search tabs aren't saved, so don't count the ones before the cursor
+			cursor: tabs.iter().take(tabs.cursor).filter(|t| t.cwd().is_regular()).count(),
+			tabs:   tabs.iter().filter(|t| t.cwd().is_regular()).map(SessionTab::from).collect(),
+		};
+
+		if let Ok(s) = serde_json::to_string(&session) {
+			std::fs::write(Self::path(), s).ok();
+		}
+	}
+
+	// Entries given on the command line always take precedence over the session
+	pub(super) fn load() -> Option<Self> {
+		if !ARGS.entries.is_empty() || !(ARGS.continue_ || MANAGER.restore_session) {
+			return None;
+		}
+
+		let s = std::fs::read_to_string(Self::path()).ok()?;
+		serde_json::from_str::<Self>(&s).ok().filter(|s| !s.tabs.is_empty())
+	}
+
+	pub(super) fn restore(self) -> Tabs {
+		let mut tabs = Tabs {
+			cursor: self.cursor.min(self.tabs.len() - 1),
+			items:  self.tabs.iter().map(|_| Tab::default()).collect(),
+			zen:    false,
+		};
+		tabs.reorder();
+
+		for (tab, s) in tabs.items.iter_mut().zip(self.tabs) {
+			tab.title = s.title;
+			tab.conf = Config {
+				sort_by:        s.sort_by.parse().unwrap_or(MANAGER.sort_by),
+				sort_sensitive: s.sort_sensitive,
+				sort_reverse:   s.sort_reverse,
+				sort_dir_first: s.sort_dir_first,
+				sort_translit:  s.sort_translit,
+
+				linemode:    s.linemode,
+				show_hidden: s.show_hidden,
+			};
+
+			match s.hovered {
+				Some(h) => tab.reveal(Url::from(h)),
+				None => tab.cd(Url::from(s.cwd)),
+			}
+		}
+		tabs
+	}
+
+	#[inline]
+	fn path() -> PathBuf { BOOT.state_dir.join(".session") }
+}
+
+impl From<&Tab> for SessionTab {
+	fn from(tab: &Tab) -> Self {
+		// Save the settings without the per-directory overrides applied
+		let conf = tab.base.as_ref().unwrap_or(&tab.conf);
+		Self {
+			cwd:     tab.cwd().to_path_buf(),
+			hovered: tab.current.hovered().map(|h| h.url.to_path_buf()),
+			title:   tab.title.clone(),
+
+			sort_by:        conf.sort_by.to_string(),
+			sort_sensitive: conf.sort_sensitive,
+			sort_reverse:   conf.sort_reverse,
+			sort_dir_first: conf.sort_dir_first,
+			sort_translit:  conf.sort_translit,
+
+			linemode:    conf.linemode.clone(),
+			show_hidden: tab.hidden_default.unwrap_or(conf.show_hidden),
+		}
+	}
+}
@@ -1,21 +1,32 @@
 use std::ops::{Deref, DerefMut};
 
 use yazi_boot::BOOT;
+use yazi_config::MANAGER;
 use yazi_dds::Pubsub;
 use yazi_proxy::ManagerProxy;
 use yazi_shared::fs::Url;
 
+use super::Session;
 use crate::tab::Tab;
 
 pub struct Tabs {
 	pub cursor:       usize,
 	pub(super) items: Vec<Tab>,
+	// Only the file list is shown, set by `zen`
+	pub zen:          bool,
 }
 
 impl Tabs {
 	pub fn make() -> Self {
-		let mut tabs =
-			Self { cursor: 0, items: (0..BOOT.cwds.len()).map(|_| Tab::default()).collect() };
+		if let Some(session) = Session::load() {
+			return session.restore();
+		}
+
+		let mut tabs = Self {
+			cursor: 0,
+			items:  (0..BOOT.cwds.len()).map(|_| Tab::default()).collect(),
+			zen:    false,
+		};
 		tabs.reorder();
 
 		for (i, tab) in tabs.iter_mut().enumerate() {
@@ -26,9 +37,24 @@ impl Tabs {
 				tab.reveal(Url::from(BOOT.cwds[i].join(file)));
 			}
 		}
+
+		// Start with a pair of tabs in the dual-pane layout
+		if MANAGER.dual_pane && tabs.items.len() == 1 {
+			let mut tab = Tab { idx: 1, ..Default::default() };
+			tab.cd(tabs.items[0].cwd().clone());
+			tabs.items.push(tab);
+		}
 		tabs
 	}
 
+	// The tab shown next to the active one in the dual-pane layout,
+	// where tabs are paired up as 1 and 2, 3 and 4, etc. None without that layout
+	#[inline]
+	pub fn other(&self) -> Option<usize> {
+		let idx = self.cursor ^ 1;
+		(MANAGER.dual_pane && idx < self.items.len()).then_some(idx)
+	}
+
 	#[inline]
 	pub(super) fn absolute(&self, rel: isize) -> usize {
 		if rel > 0 {
@@ -3,13 +3,13 @@ use std::{collections::{HashMap, HashSet}, time::Duration};
 use anyhow::Result;
 use notify::{PollWatcher, RecommendedWatcher, RecursiveMode, Watcher as _Watcher};
 use parking_lot::RwLock;
-use tokio::{fs, pin, sync::{mpsc::{self, UnboundedReceiver}, watch}};
+use tokio::{pin, sync::{mpsc::{self, UnboundedReceiver}, watch}};
 use tokio_stream::{StreamExt, wrappers::UnboundedReceiverStream};
 use tracing::error;
 use yazi_fs::{Files, Folder};
 use yazi_plugin::isolate;
 use yazi_proxy::WATCHER;
-use yazi_shared::{RoCell, fs::{Cha, File, FilesOp, Url, realname_unchecked}};
+use yazi_shared::{Layer, RoCell, Xdg, emit, event::Cmd, fs::{Cha, File, FilesOp, Url, canonicalize, realname_unchecked}};
 
 use super::Linked;
 
@@ -45,6 +45,9 @@ impl Watcher {
 		}
 
 		tokio::spawn(Self::fan_out(out_rx));
+		if let Err(e) = Self::watch_config() {
+			error!("Failed to watch the config directory: {e}");
+		}
 		Self { in_tx, out_tx }
 	}
 
@@ -132,7 +135,7 @@ impl Watcher {
 				};
 
 				let u = &file.url;
-				let eq = (!file.is_link() && fs::canonicalize(u).await.is_ok_and(|p| p == ***u))
+				let eq = (!file.is_link() && canonicalize(u).await.is_ok_and(|p| p == ***u))
 					|| realname_unchecked(u, &mut cached).await.is_ok_and(|s| urn.as_urn() == s);
 
 				if !eq {
@@ -155,6 +158,36 @@ impl Watcher {
 		}
 	}
 
+	fn watch_config() -> Result<()> {
+		let (tx, rx) = mpsc::unbounded_channel();
+		let handler = move |res: Result<notify::Event, notify::Error>| {
+			let Ok(event) = res else { return };
+			if event.kind.is_access() {
+				return;
+			}
+
+			// yazi.toml isn't hot-reloadable, only read at startup
+			let names = ["keymap.toml", "theme.toml"];
+			if event.paths.iter().any(|p| p.file_name().is_some_and(|n| names.iter().any(|&s| n == s))) {
+				tx.send(()).ok();
+			}
+		};
+
+		let mut watcher = RecommendedWatcher::new(handler, notify::Config::default())?;
+		watcher.watch(&Xdg::config_dir(), RecursiveMode::NonRecursive)?;
+
+		tokio::spawn(async move {
+			let _watcher = watcher;
+			let rx = UnboundedReceiverStream::new(rx).chunks_timeout(100, Duration::from_millis(300));
+			pin!(rx);
+
+			while rx.next().await.is_some() {
+				emit!(Call(Cmd::new("reload"), Layer::App));
+			}
+		});
+		Ok(())
+	}
+
 	async fn sync_linked() {
 		let mut new = WATCHED.read().clone();
 
@@ -166,7 +199,7 @@ impl Watcher {
 
 		async fn go(todo: HashSet<Url>) {
 			for from in todo {
-				let Ok(to) = fs::canonicalize(&from).await else { continue };
+				let Ok(to) = canonicalize(&from).await else { continue };
 
 				if to != **from && WATCHED.read().contains(&from) {
 					LINKED.write().insert(from, Url::from(to));
@@ -0,0 +1,17 @@
+use yazi_config::popup::SelectCfg;
+use yazi_proxy::SelectProxy;
+use yazi_shared::event::Cmd;
+
+use crate::notify::Notify;
+
+impl Notify {
+	pub fn history(&mut self, _: Cmd) {
+		if self.history.is_empty() {
+			return;
+		}
+
+		// Newest first, nothing happens on selection
+		let items = self.history.iter().rev().cloned().collect();
+		tokio::spawn(SelectProxy::show(SelectCfg::history(items)));
+	}
+}
@@ -2,12 +2,22 @@ use std::time::Instant;
 
 use yazi_shared::{Layer, emit, event::Cmd};
 
-use crate::notify::{Message, Notify};
+use crate::notify::{Message, NOTIFY_HISTORY, Notify};
 
 impl Notify {
 	pub fn push(&mut self, msg: impl Into<Message>) {
 		let mut msg = msg.into() as Message;
 
+		if self.history.len() >= NOTIFY_HISTORY {
+			self.history.pop_front();
+		}
+		self.history.push_back(format!(
+			"{} {}: {}",
+			msg.level.icon(),
+			msg.title,
+			msg.content.lines().collect::<Vec<_>>().join(" ")
+		));
+
 		let instant = Instant::now();
 		msg.timeout += instant - self.messages.first().map_or(instant, |m| m.instant);
 		self.messages.push(msg);
@@ -1,7 +1,7 @@
 use std::time::{Duration, Instant};
 
-use unicode_width::UnicodeWidthStr;
 use yazi_proxy::options::{NotifyLevel, NotifyOpt};
+use yazi_shared::str_width;
 
 use super::NOTIFY_BORDER;
 
@@ -19,9 +19,9 @@ pub struct Message {
 impl From<NotifyOpt> for Message {
 	fn from(opt: NotifyOpt) -> Self {
 		let title = opt.title.lines().next().unwrap_or_default();
-		let title_width = title.width() + (opt.level.icon().width() + /* Space */ 1);
+		let title_width = str_width(title) + (str_width(&opt.level.icon()) + /* Space */ 1);
 
-		let max_width = opt.content.lines().map(|s| s.width()).max().unwrap_or(0).max(title_width);
+		let max_width = opt.content.lines().map(str_width).max().unwrap_or(0).max(title_width);
 
 		Self {
 			title:   title.to_owned(),
@@ -45,7 +45,7 @@ impl Message {
 
 		let mut lines = 0;
 		for line in self.content.lines() {
-			lines += (line.width() + 1).div_ceil(width as usize)
+			lines += (str_width(line) + 1).div_ceil(width as usize)
 		}
 
 		lines + NOTIFY_BORDER as usize
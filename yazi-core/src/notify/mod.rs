@@ -7,3 +7,4 @@ pub use notify::*;
 
 pub const NOTIFY_BORDER: u16 = 2;
 pub const NOTIFY_SPACING: u16 = 1;
+pub const NOTIFY_HISTORY: usize = 100;
@@ -1,4 +1,4 @@
-use std::ops::ControlFlow;
+use std::{collections::VecDeque, ops::ControlFlow};
 
 use ratatui::layout::Rect;
 use tokio::task::JoinHandle;
@@ -9,6 +9,9 @@ use super::{Message, NOTIFY_SPACING};
 pub struct Notify {
 	pub(super) tick_handle: Option<JoinHandle<()>>,
 	pub messages:           Vec<Message>,
+
+	// Every message pushed so far, for reviewing the expired ones
+	pub(super) history: VecDeque<String>,
 }
 
 impl Notify {
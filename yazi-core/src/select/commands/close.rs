@@ -23,6 +23,7 @@ impl Select {
 
 		self.cursor = 0;
 		self.offset = 0;
+		self.current = None;
 		self.visible = false;
 		render!();
 	}
@@ -27,6 +27,9 @@ impl Select {
 		self.title = opt.cfg.title;
 		self.items = opt.cfg.items;
 		self.position = opt.cfg.position;
+		self.current = opt.cfg.current.filter(|&i| i < self.items.len());
+		self.cursor = self.current.unwrap_or(0);
+		self.reflow();
 
 		self.callback = Some(opt.tx);
 		self.visible = true;
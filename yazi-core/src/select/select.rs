@@ -1,5 +1,6 @@
 use anyhow::Result;
 use tokio::sync::oneshot::Sender;
+use yazi_adapter::Dimension;
 use yazi_config::{SELECT, popup::Position};
 
 #[derive(Default)]
@@ -10,6 +11,7 @@ pub struct Select {
 
 	pub(super) offset:   usize,
 	pub(super) cursor:   usize,
+	pub(super) current:  Option<usize>,
 	pub(super) callback: Option<Sender<Result<usize>>>,
 
 	pub visible: bool,
@@ -22,9 +24,18 @@ impl Select {
 		&self.items[self.offset..end]
 	}
 
+	// Keep the cursor in view once the popup has shrunk with the terminal
+	pub fn reflow(&mut self) {
+		let limit = self.limit();
+		if self.cursor >= self.offset + limit {
+			self.offset = (self.cursor + 1).saturating_sub(limit);
+		}
+	}
+
 	#[inline]
 	pub(super) fn limit(&self) -> usize {
-		self.position.offset.height.saturating_sub(SELECT.border()) as usize
+		let height = self.position.offset.height.min(Dimension::available().rows);
+		height.saturating_sub(SELECT.border()) as usize
 	}
 }
 
@@ -34,4 +45,7 @@ impl Select {
 
 	#[inline]
 	pub fn rel_cursor(&self) -> usize { self.cursor - self.offset }
+
+	#[inline]
+	pub fn rel_current(&self) -> Option<usize> { self.current?.checked_sub(self.offset) }
 }
@@ -1,3 +1,4 @@
+use yazi_config::MANAGER;
 use yazi_fs::Step;
 use yazi_proxy::ManagerProxy;
 use yazi_shared::{event::{Cmd, Data}, render};
@@ -16,7 +17,8 @@ impl From<Cmd> for Opt {
 			_ => Step::default(),
 		};
 
-		Self { step }
+		let count = c.get("count").and_then(Data::as_usize).unwrap_or(1);
+		Self { step: step.times(count) }
 	}
 }
 
@@ -26,7 +28,11 @@ impl From<isize> for Opt {
 
 impl Tab {
 	pub fn arrow(&mut self, opt: impl Into<Opt>) {
-		let opt = opt.into() as Opt;
+		let mut opt = opt.into() as Opt;
+		if MANAGER.cursor_wrap {
+			opt.step = self.wrap_step(opt.step);
+		}
+
 		if !self.current.arrow(opt.step) {
 			return;
 		}
@@ -44,4 +50,14 @@ impl Tab {
 		ManagerProxy::hover(None, self.idx);
 		render!();
 	}
+
+	// Moving a single step past either end wraps around to the other one
+	fn wrap_step(&self, step: Step) -> Step {
+		let (cursor, max) = (self.current.cursor, self.current.files.len().saturating_sub(1));
+		match step {
+			Step::Fixed(1) if cursor == max => Step::prev(max),
+			Step::Fixed(-1) if cursor == 0 => Step::next(max),
+			s => s,
+		}
+	}
 }
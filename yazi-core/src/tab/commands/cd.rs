@@ -1,31 +1,51 @@
-use std::{mem, time::Duration};
+use std::{iter, mem, time::Duration};
 
 use tokio::{fs, pin};
 use tokio_stream::{StreamExt, wrappers::UnboundedReceiverStream};
-use yazi_config::popup::InputCfg;
+use yazi_config::{MANAGER, manager::FilterPolicy, popup::{InputCfg, SelectCfg}};
 use yazi_dds::Pubsub;
-use yazi_proxy::{CompletionProxy, InputProxy, ManagerProxy, TabProxy};
-use yazi_shared::{Debounce, InputError, event::{Cmd, Data}, fs::{Url, expand_path}, render};
+use yazi_fs::{Mtp, Remote};
+use yazi_proxy::{AppProxy, CompletionProxy, InputProxy, ManagerProxy, SelectProxy, TabProxy};
+use yazi_shared::{Debounce, InputError, event::{Cmd, Data}, fs::{Url, expand_path, strip_verbatim}, render};
 
 use crate::tab::Tab;
 
 pub struct Opt {
 	target:      Url,
 	interactive: bool,
+	ancestor:    bool,
+	device:      bool,
+	drive:       bool,
+	remote:      Option<Remote>,
 }
 
 impl From<Cmd> for Opt {
 	fn from(mut c: Cmd) -> Self {
-		let mut target = c.take_first().and_then(Data::into_url).unwrap_or_default();
+		let first = c.take_first();
+		let remote = first.as_ref().and_then(Data::as_str).and_then(Remote::parse);
+
+		let mut target = first.and_then(Data::into_url).unwrap_or_default();
 		if target.is_regular() {
 			target = Url::from(expand_path(&target));
 		}
 
-		Self { target, interactive: c.bool("interactive") }
+		Self {
+			target,
+			interactive: c.bool("interactive"),
+			ancestor:    c.bool("ancestor"),
+			device:      c.bool("device"),
+			drive:       c.bool("drive"),
+			remote,
+		}
 	}
 }
 impl From<Url> for Opt {
-	fn from(target: Url) -> Self { Self { target, interactive: false } }
+	fn from(mut target: Url) -> Self {
+		if target.is_regular() {
+			target = Url::from(strip_verbatim(target.into_path()));
+		}
+		Self { target, interactive: false, ancestor: false, device: false, drive: false, remote: None }
+	}
 }
 
 impl Tab {
@@ -37,6 +57,14 @@ impl Tab {
 		let opt = opt.into() as Opt;
 		if opt.interactive {
 			return self.cd_interactive();
+		} else if opt.ancestor {
+			return self.cd_ancestor();
+		} else if opt.device {
+			return Self::cd_device();
+		} else if opt.drive {
+			return Self::cd_drive();
+		} else if let Some(remote) = opt.remote {
+			return Self::cd_remote(remote);
 		}
 
 		if opt.target == *self.cwd() {
@@ -50,7 +78,12 @@ impl Tab {
 
 		// Current
 		let rep = self.history.remove_or(&opt.target);
-		let rep = mem::replace(&mut self.current, rep);
+		let mut rep = mem::replace(&mut self.current, rep);
+		match MANAGER.filter_policy {
+			FilterPolicy::Keep => {}
+			FilterPolicy::Clear => _ = rep.files.set_filter(None),
+			FilterPolicy::Inherit => _ = self.current.files.set_filter(rep.files.filter().cloned()),
+		}
 		if rep.url.is_regular() {
 			self.history.insert(rep.url.to_owned(), rep);
 		}
@@ -67,6 +100,7 @@ impl Tab {
 
 		Pubsub::pub_from_cd(self.idx, self.cwd());
 		ManagerProxy::refresh();
+		ManagerProxy::hook("cd");
 		render!();
 	}
 
@@ -80,6 +114,10 @@ impl Tab {
 			while let Some(result) = rx.next().await {
 				match result {
 					Ok(s) => {
+						if let Some(remote) = Remote::parse(&s) {
+							return Self::cd_remote(remote);
+						}
+
 						let u = Url::from(expand_path(s));
 						let Ok(meta) = fs::metadata(&u).await else {
 							return;
@@ -99,4 +137,61 @@ impl Tab {
 			}
 		});
 	}
+
+	fn cd_remote(remote: Remote) {
+		tokio::spawn(async move {
+			match remote.mount().await {
+				Ok(p) => TabProxy::cd(&Url::from(p)),
+				Err(e) => AppProxy::notify_warn("Mount", e),
+			}
+		});
+	}
+
+	fn cd_device() {
+		tokio::spawn(async move {
+			let devices = match Mtp::devices().await {
+				Ok(d) if d.is_empty() => return AppProxy::notify_warn("Devices", "No device found"),
+				Ok(d) => d,
+				Err(e) => return AppProxy::notify_warn("Devices", e),
+			};
+
+			let items = devices.iter().map(|(desc, _)| desc.clone()).collect();
+			if let Ok(choice) = SelectProxy::show(SelectCfg::device(items)).await {
+				if let Some(remote) = Remote::parse(&devices[choice].1) {
+					Self::cd_remote(remote);
+				}
+			}
+		});
+	}
+
+	// Windows only, as the parent of the drive roots
+	pub(super) fn cd_drive() {
+		#[cfg(windows)]
+		tokio::spawn(async move {
+			let drives = yazi_fs::drives().await;
+			let items = drives.iter().map(|p| p.to_string_lossy().into_owned()).collect();
+			if let Ok(choice) = SelectProxy::show(SelectCfg::drive(items)).await {
+				TabProxy::cd(&Url::from(&drives[choice]));
+			}
+		});
+	}
+
+	fn cd_ancestor(&self) {
+		if !self.cwd().is_regular() {
+			return;
+		}
+
+		// Closest first
+		let ancestors: Vec<_> = iter::successors(self.cwd().parent_url(), |u| u.parent_url()).collect();
+		if ancestors.is_empty() {
+			return;
+		}
+
+		tokio::spawn(async move {
+			let items = ancestors.iter().map(|u| u.to_string()).collect();
+			if let Ok(choice) = SelectProxy::show(SelectCfg::ancestor(items)).await {
+				TabProxy::cd(&ancestors[choice]);
+			}
+		});
+	}
 }
@@ -1,33 +1,54 @@
 use std::ffi::{OsStr, OsString};
 
+use tokio::{fs::{self, File}, io::{AsyncBufReadExt, BufReader}};
+use yazi_config::{LAYOUT, PLUGIN};
 use yazi_plugin::CLIPBOARD;
-use yazi_shared::event::Cmd;
+use yazi_proxy::AppProxy;
+use yazi_shared::{MIME_DIR, event::Cmd};
 
 use crate::tab::Tab;
 
 pub struct Opt {
 	type_: String,
+	lines: Option<(usize, usize)>,
+	all:   bool,
 }
 
 impl From<Cmd> for Opt {
-	fn from(mut c: Cmd) -> Self { Self { type_: c.take_first_str().unwrap_or_default() } }
+	fn from(mut c: Cmd) -> Self {
+		Self {
+			type_: c.take_first_str().unwrap_or_default(),
+			lines: c.take_str("lines").and_then(|s| {
+				let (a, b) = s.split_once('-')?;
+				Some((a.trim().parse().ok()?, b.trim().parse().ok()?))
+			}),
+			all:   c.bool("all"),
+		}
+	}
 }
 
 impl Tab {
 	pub fn copy(&mut self, opt: impl Into<Opt>) {
 		let opt = opt.into() as Opt;
-		if !self.try_escape_visual() {
+		if opt.type_ == "preview" {
+			return self.copy_preview(opt);
+		} else if !self.try_escape_visual() {
 			return;
 		}
 
 		let mut s = OsString::new();
 		let mut it = self.selected_or_hovered(true).peekable();
 		while let Some(u) = it.next() {
+			let uri;
 			s.push(match opt.type_.as_str() {
 				"path" => u.as_os_str(),
 				"dirname" => u.parent().map_or(OsStr::new(""), |p| p.as_os_str()),
 				"filename" => u.file_name().unwrap_or(OsStr::new("")),
 				"name_without_ext" => u.file_stem().unwrap_or(OsStr::new("")),
+				"uri" => {
+					uri = u.to_file_uri();
+					OsStr::new(&uri)
+				}
 				_ => return,
 			});
 			if it.peek().is_some() {
@@ -37,4 +58,46 @@ impl Tab {
 
 		futures::executor::block_on(CLIPBOARD.set(s));
 	}
+
+	// The lines on screen, the 1-based inclusive `--lines=start-end`, or `--all` of them.
+	// Lines are read from the file, so only the text previewer shows the same thing
+	fn copy_preview(&self, opt: Opt) {
+		let Some(lock) = self.preview.lock.as_ref().filter(|l| l.mime != MIME_DIR) else { return };
+		match PLUGIN.previewer(&lock.url, &lock.mime) {
+			Some(p) if p.run.name == "code" => {}
+			_ => return AppProxy::notify_warn("Copy", "Only text previews can be copied"),
+		}
+		let (start, end) = opt.lines.map_or_else(
+			|| (lock.skip, lock.skip + LAYOUT.load().preview.height as usize),
+			|(a, b)| (a.saturating_sub(1), b),
+		);
+
+		let url = lock.url.clone();
+		tokio::spawn(async move {
+			let result = if opt.all {
+				fs::read_to_string(&url).await
+			} else {
+				async {
+					let mut it = BufReader::new(File::open(&url).await?).lines();
+					let (mut s, mut i) = (String::new(), 0);
+					while let Some(line) = it.next_line().await? {
+						if i >= end {
+							break;
+						} else if i >= start {
+							s.push_str(&line);
+							s.push('\n');
+						}
+						i += 1;
+					}
+					Ok::<_, std::io::Error>(s)
+				}
+				.await
+			};
+
+			match result {
+				Ok(s) => CLIPBOARD.set(s.trim_end_matches('\n')).await,
+				Err(e) => AppProxy::notify_warn("Copy", format!("Cannot copy the preview: {e}")),
+			}
+		});
+	}
 }
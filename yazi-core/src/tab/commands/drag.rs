@@ -0,0 +1,39 @@
+use std::borrow::Cow;
+
+use yazi_config::open::Opener;
+use yazi_proxy::{AppProxy, TasksProxy};
+use yazi_shared::{bin_exists, event::Cmd};
+
+use crate::tab::Tab;
+
+// Tried in order, the first one found in `$PATH` wins
+const TOOLS: [&str; 3] = ["dragon-drop", "dragon", "ripdrag"];
+
+impl Tab {
+	pub fn drag(&mut self, _: Cmd) {
+		if !self.try_escape_visual() {
+			return;
+		}
+
+		let targets: Vec<_> = self.selected_or_hovered(true).cloned().collect();
+		if targets.is_empty() {
+			return;
+		}
+
+		let Some(bin) = TOOLS.into_iter().find(|&bin| cfg!(unix) && bin_exists(bin)) else {
+			return AppProxy::notify_warn("Drag", "Neither `dragon` nor `ripdrag` is installed");
+		};
+
+		TasksProxy::open_with(
+			targets,
+			Cow::Owned(Opener {
+				run:    format!(r#"{bin} --and-exit --all "$@""#),
+				block:  false,
+				orphan: true,
+				desc:   "Drag".to_owned(),
+				for_:   None,
+				spread: true,
+			}),
+		);
+	}
+}
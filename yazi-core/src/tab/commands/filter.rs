@@ -11,17 +11,19 @@ use crate::tab::Tab;
 
 #[derive(Default)]
 pub struct Opt {
-	pub query: String,
-	pub case:  FilterCase,
-	pub done:  bool,
+	pub query:   String,
+	pub case:    FilterCase,
+	pub literal: bool,
+	pub done:    bool,
 }
 
 impl From<Cmd> for Opt {
 	fn from(mut c: Cmd) -> Self {
 		Self {
-			query: c.take_first_str().unwrap_or_default(),
-			case:  FilterCase::from(&c),
-			done:  c.bool("done"),
+			query:   c.take_first_str().unwrap_or_default(),
+			case:    FilterCase::from(&c),
+			literal: c.bool("literal"),
+			done:    c.bool("done"),
 		}
 	}
 }
@@ -30,7 +32,7 @@ impl Tab {
 	pub fn filter(&mut self, opt: impl Into<Opt>) {
 		let opt = opt.into() as Opt;
 		tokio::spawn(async move {
-			let rx = InputProxy::show(InputCfg::filter());
+			let rx = InputProxy::show(InputCfg::filter(&opt.case.describe(opt.literal)));
 
 			let rx = Debounce::new(UnboundedReceiverStream::new(rx), Duration::from_millis(50));
 			pin!(rx);
@@ -40,9 +42,10 @@ impl Tab {
 				let (Ok(s) | Err(InputError::Typed(s))) = result else { continue };
 
 				emit!(Call(
-					Cmd::args("filter_do", &[s])
-						.with_bool("smart", opt.case == FilterCase::Smart)
-						.with_bool("insensitive", opt.case == FilterCase::Insensitive)
+					opt
+						.case
+						.apply(Cmd::args("filter_do", &[s]))
+						.with_bool("literal", opt.literal)
 						.with_bool("done", done),
 					Layer::Manager
 				));
@@ -55,7 +58,7 @@ impl Tab {
 
 		let filter = if opt.query.is_empty() {
 			None
-		} else if let Ok(f) = Filter::new(&opt.query, opt.case) {
+		} else if let Ok(f) = Filter::new(&opt.query, opt.case, opt.literal) {
 			Some(f)
 		} else {
 			return;
@@ -10,14 +10,20 @@ use yazi_shared::{Debounce, InputError, Layer, emit, event::Cmd, render};
 use crate::tab::{Finder, Tab};
 
 pub struct Opt {
-	query: Option<String>,
-	prev:  bool,
-	case:  FilterCase,
+	query:   Option<String>,
+	prev:    bool,
+	case:    FilterCase,
+	literal: bool,
 }
 
 impl From<Cmd> for Opt {
 	fn from(mut c: Cmd) -> Self {
-		Self { query: c.take_first_str(), prev: c.bool("previous"), case: FilterCase::from(&c) }
+		Self {
+			query:   c.take_first_str(),
+			prev:    c.bool("previous"),
+			case:    FilterCase::from(&c),
+			literal: c.bool("literal"),
+		}
 	}
 }
 
@@ -33,17 +39,18 @@ impl Tab {
 	pub fn find(&mut self, opt: impl Into<Opt>) {
 		let opt = opt.into() as Opt;
 		tokio::spawn(async move {
-			let rx = InputProxy::show(InputCfg::find(opt.prev));
+			let rx = InputProxy::show(InputCfg::find(opt.prev, &opt.case.describe(opt.literal)));
 
 			let rx = Debounce::new(UnboundedReceiverStream::new(rx), Duration::from_millis(50));
 			pin!(rx);
 
 			while let Some(Ok(s)) | Some(Err(InputError::Typed(s))) = rx.next().await {
 				emit!(Call(
-					Cmd::args("find_do", &[s])
+					opt
+						.case
+						.apply(Cmd::args("find_do", &[s]))
 						.with_bool("previous", opt.prev)
-						.with_bool("smart", opt.case == FilterCase::Smart)
-						.with_bool("insensitive", opt.case == FilterCase::Insensitive),
+						.with_bool("literal", opt.literal),
 					Layer::Manager
 				));
 			}
@@ -60,7 +67,7 @@ impl Tab {
 			return;
 		}
 
-		let Ok(finder) = Finder::new(&query, opt.case) else {
+		let Ok(finder) = Finder::new(&query, opt.case, opt.literal) else {
 			return;
 		};
 		if matches!(&self.finder, Some(f) if f.filter == finder.filter) {
@@ -0,0 +1,53 @@
+use yazi_config::popup::InputCfg;
+use yazi_proxy::{AppProxy, InputProxy};
+use yazi_shared::{Layer, emit, event::{Cmd, Data}};
+
+use crate::tab::Tab;
+
+pub struct Opt {
+	target: Option<String>,
+}
+
+impl From<Cmd> for Opt {
+	fn from(mut c: Cmd) -> Self {
+		// A count typed before the key is the 1-based index to go to, e.g. `5G`
+		match c.get("count").and_then(Data::as_usize) {
+			Some(n) => Self { target: Some(n.to_string()) },
+			None => Self { target: c.take_first_str() },
+		}
+	}
+}
+
+impl Tab {
+	pub fn goto(&mut self, opt: impl Into<Opt>) {
+		let opt = opt.into() as Opt;
+		let Some(target) = opt.target else {
+			return Self::goto_interactive();
+		};
+
+		// Either a 1-based index, or a percentage of the list
+		let max = self.current.files.len().saturating_sub(1);
+		let idx = if let Some(p) = target.strip_suffix('%') {
+			p.parse::<usize>().ok().map(|p| p.min(100) * max / 100)
+		} else {
+			target.parse::<usize>().ok().map(|n| n.saturating_sub(1).min(max))
+		};
+
+		let Some(idx) = idx else {
+			return AppProxy::notify_warn("Goto", format!("Invalid position `{target}`"));
+		};
+
+		self.arrow(idx as isize - self.current.cursor as isize);
+	}
+
+	fn goto_interactive() {
+		tokio::spawn(async move {
+			let mut rx = InputProxy::show(InputCfg::goto());
+			if let Some(Ok(s)) = rx.recv().await {
+				if !s.is_empty() {
+					emit!(Call(Cmd::args("goto", &[s]), Layer::Manager));
+				}
+			}
+		});
+	}
+}
@@ -3,14 +3,27 @@ use yazi_shared::event::Cmd;
 
 use crate::tab::Tab;
 
+// Directories to remember the toggle for, the least recently toggled are forgotten first
+const HIDDENS_LIMIT: usize = 1000;
+
 impl Tab {
 	pub fn hidden(&mut self, mut c: Cmd) {
-		self.conf.show_hidden = match c.take_first_str().as_deref() {
+		let state = match c.take_first_str().as_deref() {
 			Some("show") => true,
 			Some("hide") => false,
 			_ => !self.conf.show_hidden,
 		};
 
+		// Remembered for this directory, and the default for the ones not toggled yet
+		let cwd = self.cwd().clone();
+		self.hiddens.shift_remove(&cwd);
+		self.hiddens.insert(cwd, state);
+		if self.hiddens.len() > HIDDENS_LIMIT {
+			self.hiddens.shift_remove_index(0);
+		}
+		self.hidden_default = Some(state);
+		self.conf.show_hidden = state;
+
 		let hovered = self.current.hovered().map(|f| f.url_owned());
 		self.apply_files_attrs();
 
@@ -12,12 +12,17 @@ impl From<Cmd> for Opt {
 
 impl Tab {
 	pub fn leave(&mut self, _: impl Into<Opt>) {
-		self
+		let parent = self
 			.current
 			.hovered()
 			.and_then(|h| h.url.parent_url())
 			.filter(|u| u != self.cwd())
-			.or_else(|| self.cwd().parent_url())
-			.map(|u| self.cd(u.into_regular()));
+			.or_else(|| self.cwd().parent_url());
+
+		match parent {
+			Some(u) => self.cd(u.into_regular()),
+			// Above the roots are the drives on Windows
+			None => Self::cd_drive(),
+		}
 	}
 }
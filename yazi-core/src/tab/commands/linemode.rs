@@ -1,16 +1,36 @@
+use yazi_config::popup::SelectCfg;
+use yazi_proxy::{SelectProxy, TabProxy};
 use yazi_shared::{event::Cmd, render};
 
 use crate::tab::Tab;
 
+const LINEMODES: [&str; 6] = ["none", "size", "ctime", "mtime", "permissions", "owner"];
+
 impl Tab {
 	pub fn linemode(&mut self, mut c: Cmd) {
-		render!(self.conf.patch(|new| {
-			let Some(mode) = c.take_first_str() else {
-				return;
-			};
-			if !mode.is_empty() && mode.len() <= 20 {
-				new.linemode = mode;
+		if c.bool("interactive") {
+			return self.linemode_select();
+		}
+
+		let Some(mode) = c.take_first_str().filter(|m| !m.is_empty() && m.len() <= 20) else {
+			return;
+		};
+		render!(self.patch_conf(|new| new.linemode.clone_from(&mode)));
+	}
+
+	fn linemode_select(&self) {
+		// A custom linemode from a plugin is listed too, while it's the current one
+		let mut items: Vec<_> = LINEMODES.into_iter().map(ToOwned::to_owned).collect();
+		if !items.contains(&self.conf.linemode) {
+			items.push(self.conf.linemode.clone());
+		}
+
+		let current = items.iter().position(|s| *s == self.conf.linemode);
+		tokio::spawn(async move {
+			let cfg = SelectCfg::linemode(items.clone()).with_current(current);
+			if let Ok(choice) = SelectProxy::show(cfg).await {
+				TabProxy::linemode(&items[choice]);
 			}
-		}));
+		});
 	}
 }
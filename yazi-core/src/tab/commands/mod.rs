@@ -2,19 +2,24 @@ mod arrow;
 mod back;
 mod cd;
 mod copy;
+mod drag;
 mod enter;
 mod escape;
 mod filter;
 mod find;
 mod forward;
+mod goto;
 mod hidden;
 mod leave;
 mod linemode;
 mod preview;
+mod quicklook;
 mod reveal;
 mod search;
 mod select;
 mod select_all;
 mod shell;
 mod sort;
+mod subshell;
 mod visual_mode;
+mod zoom;
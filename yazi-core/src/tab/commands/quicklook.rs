@@ -0,0 +1,32 @@
+use std::borrow::Cow;
+
+use yazi_config::open::Opener;
+use yazi_proxy::{AppProxy, TasksProxy};
+use yazi_shared::event::Cmd;
+
+use crate::tab::Tab;
+
+impl Tab {
+	pub fn quicklook(&mut self, _: Cmd) {
+		if !cfg!(target_os = "macos") {
+			return AppProxy::notify_warn("Quick Look", "Quick Look is only available on macOS");
+		}
+
+		let targets: Vec<_> = self.hovered_and_selected(true).cloned().collect();
+		if targets.is_empty() {
+			return;
+		}
+
+		TasksProxy::open_with(
+			targets,
+			Cow::Owned(Opener {
+				run:    r#"qlmanage -p "$@""#.to_owned(),
+				block:  false,
+				orphan: true,
+				desc:   "Quick Look".to_owned(),
+				for_:   None,
+				spread: true,
+			}),
+		);
+	}
+}
@@ -1,5 +1,5 @@
 use yazi_proxy::ManagerProxy;
-use yazi_shared::{event::{Cmd, Data}, fs::{File, FilesOp, Url, expand_path}};
+use yazi_shared::{event::{Cmd, Data}, fs::{File, FilesOp, Url, expand_path, strip_verbatim}};
 
 use crate::tab::Tab;
 
@@ -18,7 +18,12 @@ impl From<Cmd> for Opt {
 	}
 }
 impl From<Url> for Opt {
-	fn from(target: Url) -> Self { Self { target } }
+	fn from(mut target: Url) -> Self {
+		if target.is_regular() {
+			target = Url::from(strip_verbatim(target.into_path()));
+		}
+		Self { target }
+	}
 }
 
 impl Tab {
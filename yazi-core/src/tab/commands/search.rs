@@ -46,22 +46,34 @@ impl Tab {
 
 		let cwd = self.cwd().to_search(&opt.subject);
 		let hidden = self.conf.show_hidden;
+		self.grep = (opt.via == SearchOptVia::Rg).then(|| (opt.subject.clone(), opt.args.clone()));
 
 		self.search = Some(tokio::spawn(async move {
-			let rx = if opt.via == SearchOptVia::Rg {
-				external::rg(external::RgOpt {
+			let rx = match opt.via {
+				SearchOptVia::Rg => external::rg(external::RgOpt {
 					cwd: cwd.clone(),
 					hidden,
 					subject: opt.subject,
 					args: opt.args,
-				})
-			} else {
-				external::fd(external::FdOpt {
+				}),
+				SearchOptVia::Mdfind => external::mdfind(external::MdfindOpt {
 					cwd: cwd.clone(),
 					hidden,
 					subject: opt.subject,
 					args: opt.args,
-				})
+				}),
+				SearchOptVia::Es => external::es(external::EsOpt {
+					cwd: cwd.clone(),
+					hidden,
+					subject: opt.subject,
+					args: opt.args,
+				}),
+				_ => external::fd(external::FdOpt {
+					cwd: cwd.clone(),
+					hidden,
+					subject: opt.subject,
+					args: opt.args,
+				}),
 			}?;
 
 			let rx = UnboundedReceiverStream::new(rx).chunks_timeout(1000, Duration::from_millis(300));
@@ -81,6 +93,7 @@ impl Tab {
 		if let Some(handle) = self.search.take() {
 			handle.abort();
 		}
+		self.grep = None;
 		if self.cwd().is_search() {
 			let rep = self.history.remove_or(&self.cwd().to_regular());
 			drop(mem::replace(&mut self.current, rep));
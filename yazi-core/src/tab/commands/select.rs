@@ -1,6 +1,6 @@
 use std::borrow::Cow;
 
-use yazi_proxy::AppProxy;
+use yazi_proxy::{AppProxy, ManagerProxy};
 use yazi_shared::{event::{Cmd, Data}, fs::Url, render, render_and};
 
 use crate::tab::Tab;
@@ -37,7 +37,9 @@ impl<'a> Tab {
 			None => render_and!(self.selected.remove(&url) || self.selected.add(&url)),
 		};
 
-		if !b {
+		if b {
+			ManagerProxy::hook("select");
+		} else {
 			AppProxy::notify_warn(
 				"Select one",
 				"This file cannot be selected, due to path nesting conflict.",
@@ -1,26 +1,63 @@
 use std::str::FromStr;
 
-use yazi_config::manager::SortBy;
-use yazi_proxy::ManagerProxy;
+use yazi_config::{manager::SortBy, popup::SelectCfg};
+use yazi_proxy::{ManagerProxy, SelectProxy, TabProxy};
 use yazi_shared::event::Cmd;
 
 use crate::{tab::Tab, tasks::Tasks};
 
+const SORTS: [SortBy; 8] = [
+	SortBy::Alphabetical,
+	SortBy::Natural,
+	SortBy::Modified,
+	SortBy::Created,
+	SortBy::Size,
+	SortBy::Extension,
+	SortBy::Random,
+	SortBy::None,
+];
+
 impl Tab {
 	pub fn sort(&mut self, mut c: Cmd, tasks: &Tasks) {
-		let conf = &mut self.conf;
-		if let Some(by) = c.take_first_str() {
-			conf.sort_by = SortBy::from_str(&by).unwrap_or_default();
+		if c.bool("interactive") {
+			return self.sort_select();
 		}
 
-		conf.sort_reverse = c.maybe_bool("reverse").unwrap_or(conf.sort_reverse);
-		conf.sort_dir_first = c.maybe_bool("dir-first").unwrap_or(conf.sort_dir_first);
-		conf.sort_sensitive = c.maybe_bool("sensitive").unwrap_or(conf.sort_sensitive);
-		conf.sort_translit = c.maybe_bool("translit").unwrap_or(conf.sort_translit);
+		let by = c.take_first_str().map(|s| SortBy::from_str(&s).unwrap_or_default());
+		let reverse = c.maybe_bool("reverse");
+		let dir_first = c.maybe_bool("dir-first");
+		let sensitive = c.maybe_bool("sensitive");
+		let translit = c.maybe_bool("translit");
+
+		self.patch_conf(|conf| {
+			conf.sort_by = by.unwrap_or(conf.sort_by);
+			conf.sort_reverse = reverse.unwrap_or(conf.sort_reverse);
+			conf.sort_dir_first = dir_first.unwrap_or(conf.sort_dir_first);
+			conf.sort_sensitive = sensitive.unwrap_or(conf.sort_sensitive);
+			conf.sort_translit = translit.unwrap_or(conf.sort_translit);
+		});
 
 		self.apply_files_attrs();
 		ManagerProxy::update_paged();
 
 		tasks.prework_sorted(&self.current.files);
 	}
+
+	fn sort_select(&self) {
+		let choices: Vec<_> = SORTS.into_iter().flat_map(|by| [(by, false), (by, true)]).collect();
+		let current = choices.iter().position(|&c| c == (self.conf.sort_by, self.conf.sort_reverse));
+
+		let items = choices
+			.iter()
+			.map(|(by, reverse)| if *reverse { format!("{by} (reverse)") } else { by.to_string() })
+			.collect();
+
+		tokio::spawn(async move {
+			let cfg = SelectCfg::sort(items).with_current(current);
+			if let Ok(choice) = SelectProxy::show(cfg).await {
+				let (by, reverse) = choices[choice];
+				TabProxy::sort(&by.to_string(), reverse);
+			}
+		});
+	}
 }
@@ -0,0 +1,33 @@
+use std::borrow::Cow;
+
+use yazi_config::open::Opener;
+use yazi_proxy::TasksProxy;
+use yazi_shared::event::Cmd;
+
+use crate::tab::Tab;
+
+impl Tab {
+	pub fn subshell(&mut self, _: Cmd) {
+		if !self.try_escape_visual() {
+			return;
+		}
+
+		// Started in the CWD, and yazi comes back once it exits
+		#[cfg(unix)]
+		let run = r#""${SHELL:-sh}""#;
+		#[cfg(windows)]
+		let run = "%COMSPEC%";
+
+		TasksProxy::open_with(
+			vec![],
+			Cow::Owned(Opener {
+				run:    run.to_owned(),
+				block:  true,
+				orphan: false,
+				desc:   "Subshell".to_owned(),
+				for_:   None,
+				spread: true,
+			}),
+		);
+	}
+}
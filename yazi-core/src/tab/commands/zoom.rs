@@ -0,0 +1,12 @@
+use yazi_shared::{Layer, emit, event::Cmd};
+
+use crate::tab::Tab;
+
+impl Tab {
+	pub fn zoom(&mut self, _: Cmd) {
+		self.preview.zoomed = !self.preview.zoomed;
+
+		// Lay out again and redo the preview for the new area
+		emit!(Call(Cmd::new("resize"), Layer::App));
+	}
+}
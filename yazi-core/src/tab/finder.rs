@@ -11,8 +11,8 @@ pub struct Finder {
 }
 
 impl Finder {
-	pub(super) fn new(s: &str, case: FilterCase) -> Result<Self> {
-		Ok(Self { filter: Filter::new(s, case)?, matched: Default::default(), revision: 0 })
+	pub(super) fn new(s: &str, case: FilterCase, literal: bool) -> Result<Self> {
+		Ok(Self { filter: Filter::new(s, case, literal)?, matched: Default::default(), revision: 0 })
 	}
 
 	pub(super) fn prev(&self, files: &Files, cursor: usize, include: bool) -> Option<isize> {
@@ -1,26 +1,55 @@
-use std::{collections::HashMap, ops::{Deref, DerefMut}};
+use std::{collections::{HashMap, VecDeque}, ops::{Deref, DerefMut}};
 
 use yazi_fs::Folder;
 use yazi_shared::fs::Url;
 
+// The number of visited directories whose cursor and offset are remembered
+const LIMIT: usize = 100;
+
 #[derive(Default)]
-pub struct History(HashMap<Url, Folder>);
+pub struct History {
+	inner: HashMap<Url, Folder>,
+	order: VecDeque<Url>,
+}
 
 impl Deref for History {
 	type Target = HashMap<Url, Folder>;
 
 	#[inline]
-	fn deref(&self) -> &Self::Target { &self.0 }
+	fn deref(&self) -> &Self::Target { &self.inner }
 }
 
 impl DerefMut for History {
 	#[inline]
-	fn deref_mut(&mut self) -> &mut Self::Target { &mut self.0 }
+	fn deref_mut(&mut self) -> &mut Self::Target { &mut self.inner }
 }
 
 impl History {
+	pub fn insert(&mut self, url: Url, folder: Folder) -> Option<Folder> {
+		if let Some(i) = self.order.iter().position(|u| *u == url) {
+			self.order.remove(i);
+		}
+		self.order.push_back(url.clone());
+
+		// Forget the least recently left directories
+		while self.order.len() > LIMIT {
+			if let Some(u) = self.order.pop_front() {
+				self.inner.remove(&u);
+			}
+		}
+
+		self.inner.insert(url, folder)
+	}
+
+	pub fn get_or_insert(&mut self, url: &Url) -> &mut Folder {
+		if !self.inner.contains_key(url) {
+			self.insert(url.clone(), Folder::from(url));
+		}
+		self.inner.get_mut(url).unwrap()
+	}
+
 	#[inline]
 	pub fn remove_or(&mut self, url: &Url) -> Folder {
-		self.0.remove(url).unwrap_or_else(|| Folder::from(url))
+		self.inner.remove(url).unwrap_or_else(|| Folder::from(url))
 	}
 }
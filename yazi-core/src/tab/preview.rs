@@ -13,9 +13,14 @@ use yazi_shared::{MIME_DIR, fs::{Cha, File, FilesOp, Url}, render};
 pub struct Preview {
 	pub lock: Option<PreviewLock>,
 	pub skip: usize,
+	// 1-based, highlighted after `preview_line`
+	pub line: Option<usize>,
 
 	previewer_ct:  Option<CancellationToken>,
 	folder_loader: Option<JoinHandle<()>>,
+
+	// Expanded to the whole terminal
+	pub zoomed: bool,
 }
 
 impl Preview {
@@ -1,10 +1,11 @@
 use std::iter;
 
 use anyhow::Result;
+use indexmap::IndexMap;
 use ratatui::layout::Rect;
 use tokio::task::JoinHandle;
 use yazi_adapter::Dimension;
-use yazi_config::{LAYOUT, popup::{Origin, Position}};
+use yazi_config::{LAYOUT, MANAGER, popup::{Origin, Position}};
 use yazi_fs::{Folder, FolderStage};
 use yazi_shared::{fs::Url, render};
 
@@ -14,6 +15,8 @@ use crate::tab::Selected;
 #[derive(Default)]
 pub struct Tab {
 	pub idx:     usize,
+	// Shown in the tab bar instead of the CWD's name, set by `tab_rename`
+	pub title:   Option<String>,
 	pub mode:    Mode,
 	pub conf:    Config,
 	pub current: Folder,
@@ -26,6 +29,18 @@ pub struct Tab {
 	pub preview: Preview,
 	pub finder:  Option<Finder>,
 	pub search:  Option<JoinHandle<Result<()>>>,
+	// Subject and arguments of the running `rg` search, to locate the match in each result
+	pub grep:      Option<(String, Vec<String>)>,
+	pub grep_line: Option<JoinHandle<()>>,
+
+	// The CWD the per-directory settings were last applied for
+	pub(crate) applied:        Option<Url>,
+	// The settings before `overrides` for the CWD were applied
+	pub(crate) base:           Option<Config>,
+	// `show_hidden` as last toggled in each directory in this session, least recent first
+	pub(crate) hiddens:        IndexMap<Url, bool>,
+	// `show_hidden` as last toggled anywhere, for the directories not toggled yet
+	pub(crate) hidden_default: Option<bool>,
 }
 
 impl Tab {
@@ -33,6 +48,9 @@ impl Tab {
 		if let Some(handle) = self.search.take() {
 			handle.abort();
 		}
+		if let Some(handle) = self.grep_line.take() {
+			handle.abort();
+		}
 	}
 }
 
@@ -42,7 +60,8 @@ impl Tab {
 	pub fn cwd(&self) -> &Url { &self.current.url }
 
 	pub fn hovered_rect(&self) -> Option<Rect> {
-		let y = self.current.files.position(self.current.hovered()?.urn())? - self.current.offset;
+		self.current.hovered()?;
+		let y = self.current.cursor - self.current.offset;
 
 		let mut rect = LAYOUT.load().current;
 		rect.y = rect.y.saturating_sub(1) + y as u16;
@@ -91,13 +110,72 @@ impl Tab {
 		self.current.hovered().filter(|&h| h.is_dir()).and_then(|h| self.history.get(&h.url))
 	}
 
+	// Swap in the settings for the CWD from `overrides`, restoring the previous ones on leaving.
+	// Only done once the CWD changes, so changes made by the user in the meantime are kept
+	pub fn apply_overrides(&mut self) {
+		if self.applied.as_ref() == Some(self.cwd()) {
+			return;
+		}
+		self.applied = Some(self.cwd().clone());
+
+		let old = self.conf.clone();
+		if let Some(base) = self.base.take() {
+			self.conf = base;
+		}
+
+		let o = MANAGER.overrides.iter().find(|o| o.path == ***self.cwd());
+		if let Some(o) = o {
+			self.base = Some(self.conf.clone());
+
+			let c = &mut self.conf;
+			c.sort_by = o.sort_by.unwrap_or(c.sort_by);
+			c.sort_sensitive = o.sort_sensitive.unwrap_or(c.sort_sensitive);
+			c.sort_reverse = o.sort_reverse.unwrap_or(c.sort_reverse);
+			c.sort_dir_first = o.sort_dir_first.unwrap_or(c.sort_dir_first);
+			c.sort_translit = o.sort_translit.unwrap_or(c.sort_translit);
+			if let Some(linemode) = &o.linemode {
+				c.linemode.clone_from(linemode);
+			}
+		}
+
+		// A toggle made here earlier wins over the override, which wins over the last toggle
+		let hidden = self
+			.hiddens
+			.get(self.cwd())
+			.copied()
+			.or(o.and_then(|o| o.show_hidden))
+			.or(self.hidden_default);
+		if let Some(hidden) = hidden {
+			self.conf.show_hidden = hidden;
+		}
+
+		render!(self.conf != old);
+	}
+
+	// Take over the settings of another tab in the same directory
+	pub(crate) fn inherit_conf(&mut self, other: &Self) {
+		self.conf = other.conf.clone();
+		self.base = other.base.clone();
+		self.applied = other.applied.clone();
+		self.hidden_default = other.hidden_default;
+	}
+
+	// Change the settings both for the CWD and for where the overrides don't apply
+	pub(super) fn patch_conf<F: Fn(&mut Config)>(&mut self, f: F) -> bool {
+		if let Some(base) = &mut self.base {
+			f(base);
+		}
+		self.conf.patch(f)
+	}
+
 	pub fn apply_files_attrs(&mut self) {
 		let apply = |f: &mut Folder| {
 			if f.stage == FolderStage::Loading {
 				return render!();
 			}
 
-			let hovered = f.hovered().filter(|_| f.tracing).map(|h| h.urn_owned());
+			// Stay on the same file rather than the same position
+			let hovered = f.hovered().map(|h| h.urn_owned());
 			f.files.set_show_hidden(self.conf.show_hidden);
 			f.files.set_sorter(self.conf.sorter());
 
@@ -1,29 +1,49 @@
 use std::collections::HashSet;
 
 use tracing::debug;
-use yazi_shared::fs::Url;
+use yazi_config::popup::SelectCfg;
+use yazi_proxy::{AppProxy, SelectProxy};
+use yazi_scheduler::{Conflict, JOURNAL, JournalOp};
+use yazi_shared::fs::{Url, maybe_exists, must_exists};
 
 use super::Tasks;
 
 impl Tasks {
-	pub fn file_cut(&self, src: &[&Url], dest: &Url, force: bool) {
-		for &u in src {
+	pub fn file_cut(
+		&self,
+		src: &[&Url],
+		dest: &Url,
+		conflict: Conflict,
+		limit: Option<u64>,
+		reveal: bool,
+	) {
+		let batch = JOURNAL.lock().begin();
+		for (i, &u) in src.iter().enumerate() {
 			let to = dest.join(u.file_name().unwrap());
-			if force && *u == to {
+			if !matches!(conflict, Conflict::Unique | Conflict::Rename) && *u == to {
 				debug!("file_cut: same file, skipping {:?}", to);
 			} else {
-				self.scheduler.file_cut(u.clone(), to, force);
+				let reveal = reveal && i == 0;
+				self.scheduler.file_cut(u.clone(), to, conflict, limit, Some(batch), reveal);
 			}
 		}
 	}
 
-	pub fn file_copy(&self, src: &[&Url], dest: &Url, force: bool, follow: bool) {
-		for &u in src {
+	pub fn file_copy(
+		&self,
+		src: &[&Url],
+		dest: &Url,
+		conflict: Conflict,
+		follow: bool,
+		limit: Option<u64>,
+		reveal: bool,
+	) {
+		for (i, &u) in src.iter().enumerate() {
 			let to = dest.join(u.file_name().unwrap());
-			if force && *u == to {
+			if !matches!(conflict, Conflict::Unique | Conflict::Rename) && *u == to {
 				debug!("file_copy: same file, skipping {:?}", to);
 			} else {
-				self.scheduler.file_copy(u.clone(), to, force, follow);
+				self.scheduler.file_copy(u.clone(), to, conflict, follow, limit, reveal && i == 0);
 			}
 		}
 	}
@@ -51,12 +71,59 @@ impl Tasks {
 	}
 
 	pub fn file_remove(&self, targets: Vec<Url>, permanently: bool) {
+		let batch = if permanently { None } else { Some(JOURNAL.lock().begin()) };
 		for u in targets {
 			if permanently {
-				self.scheduler.file_delete(u);
+				self.scheduler.file_delete(u, false);
 			} else {
-				self.scheduler.file_trash(u);
+				self.scheduler.file_trash(u, batch);
 			}
 		}
 	}
+
+	pub fn file_shred(&self, targets: Vec<Url>) {
+		for u in targets {
+			self.scheduler.file_delete(u, true);
+		}
+	}
+
+	pub fn file_undo(&self) {
+		let Some(ops) = JOURNAL.lock().pop() else {
+			return AppProxy::notify_warn("Undo", "Nothing to undo");
+		};
+
+		let scheduler = self.scheduler.clone();
+		tokio::spawn(async move {
+			for op in ops.into_iter().rev() {
+				match op {
+					JournalOp::Move { from, to } => {
+						if !must_exists(&to).await {
+							AppProxy::notify_warn("Undo", format!("{to:?} no longer exists, skipped"));
+						} else if maybe_exists(&from).await {
+							AppProxy::notify_warn("Undo", format!("{from:?} already exists, skipped"));
+						} else {
+							scheduler.file_cut(to, from, Conflict::Overwrite, None, None, false);
+						}
+					}
+					JournalOp::Trash { target } => scheduler.file_restore(target),
+				}
+			}
+		});
+	}
+
+	pub fn file_restore(&self) {
+		let scheduler = self.scheduler.clone();
+		tokio::spawn(async move {
+			let trashed = match scheduler.file_trashed().await {
+				Ok(v) if v.is_empty() => return AppProxy::notify_warn("Restore", "The trash is empty"),
+				Ok(v) => v,
+				Err(e) => return AppProxy::notify_warn("Restore", e),
+			};
+
+			let items = trashed.iter().map(|u| u.to_string()).collect();
+			if let Ok(choice) = SelectProxy::show(SelectCfg::restore(items)).await {
+				scheduler.file_restore(trashed[choice].clone());
+			}
+		});
+	}
 }
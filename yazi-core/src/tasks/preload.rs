@@ -1,4 +1,4 @@
-use yazi_config::{PLUGIN, manager::SortBy, plugin::MAX_PREWORKERS};
+use yazi_config::{PLUGIN, TASKS, manager::SortBy, plugin::MAX_PREWORKERS};
 use yazi_fs::Files;
 use yazi_shared::{MIME_DIR, fs::File};
 
@@ -63,15 +63,22 @@ impl Tasks {
 	}
 
 	pub fn prework_sorted(&self, targets: &Files) {
-		if targets.sorter().by != SortBy::Size {
-			return;
+		if targets.sorter().by == SortBy::Size {
+			self.prework_sizes(targets, targets.iter());
 		}
+	}
+
+	pub fn prework_paged(&self, paged: &[File], files: &Files) {
+		if TASKS.precache_sizes {
+			self.prework_sizes(files, paged.iter());
+		}
+	}
 
+	fn prework_sizes<'a>(&self, files: &Files, targets: impl Iterator<Item = &'a File>) {
 		let targets: Vec<_> = {
 			let loading = self.scheduler.prework.size_loading.read();
 			targets
-				.iter()
-				.filter(|f| f.is_dir() && !targets.sizes.contains_key(f.urn()) && !loading.contains(&f.url))
+				.filter(|f| f.is_dir() && !files.sizes.contains_key(f.urn()) && !loading.contains(&f.url))
 				.map(|f| &f.url)
 				.collect()
 		};
@@ -7,13 +7,25 @@ use super::Tasks;
 
 impl Tasks {
 	pub fn process_from_files(&self, hovered: Url, targets: Vec<(Url, String)>) {
+		self.process_from_groups(Self::group_by_opener(&hovered, &targets));
+	}
+
+	// Files sharing the same default opener, each group led by the hovered file
+	pub fn group_by_opener(
+		hovered: &Url,
+		targets: &[(Url, String)],
+	) -> HashMap<&'static Opener, Vec<Url>> {
 		let mut openers = HashMap::new();
 		for (url, mime) in targets {
-			if let Some(opener) = OPEN.openers(&url, mime).and_then(|o| o.first().copied()) {
-				openers.entry(opener).or_insert_with(|| vec![hovered.clone()]).push(url);
+			if let Some(opener) = OPEN.openers(url, mime).and_then(|o| o.first().copied()) {
+				openers.entry(opener).or_insert_with(|| vec![hovered.clone()]).push(url.clone());
 			}
 		}
-		for (opener, args) in openers {
+		openers
+	}
+
+	pub fn process_from_groups(&self, groups: HashMap<&'static Opener, Vec<Url>>) {
+		for (opener, args) in groups {
 			self.process_from_opener(
 				Cow::Borrowed(opener),
 				args.into_iter().map(|u| u.into_path().into_os_string()).collect(),
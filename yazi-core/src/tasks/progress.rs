@@ -11,6 +11,16 @@ pub struct TasksProgress {
 	pub processed: u64,
 }
 
+impl TasksProgress {
+	// Same as the one in the status bar, `None` when there's no task
+	pub fn percent(&self) -> Option<u8> {
+		if self.total == 0 {
+			return None;
+		}
+		Some((self.processed * 100).checked_div(self.found).map_or(99, |p| p.min(99)) as u8)
+	}
+}
+
 impl From<&Ongoing> for TasksProgress {
 	fn from(ongoing: &Ongoing) -> Self {
 		let mut progress = Self::default();
@@ -3,7 +3,9 @@ use std::{sync::Arc, time::Duration};
 use parking_lot::Mutex;
 use tokio::{task::JoinHandle, time::sleep};
 use yazi_adapter::Dimension;
-use yazi_scheduler::{Ongoing, Scheduler, TaskSummary};
+use yazi_config::popup::ConfirmCfg;
+use yazi_proxy::ConfirmProxy;
+use yazi_scheduler::{Ongoing, Pending, Scheduler, TaskKind, TaskSummary};
 use yazi_shared::{Layer, emit, event::Cmd};
 
 use super::{TASKS_BORDER, TASKS_PADDING, TASKS_PERCENT, TasksProgress};
@@ -20,7 +22,7 @@ pub struct Tasks {
 
 impl Tasks {
 	pub fn serve() -> Self {
-		let scheduler = Scheduler::serve();
+		let scheduler = Arc::new(Scheduler::serve());
 		let ongoing = scheduler.ongoing.clone();
 
 		let handle = tokio::spawn(async move {
@@ -36,8 +38,23 @@ impl Tasks {
 			}
 		});
 
+		tokio::spawn({
+			let scheduler = scheduler.clone();
+			async move {
+				let ops = Pending::take();
+				if ops.is_empty() {
+					return;
+				}
+
+				let names = ops.iter().map(ToString::to_string).collect();
+				if ConfirmProxy::show(ConfirmCfg::resume(names)).await {
+					scheduler.resume(ops);
+				}
+			}
+		});
+
 		Self {
-			scheduler: Arc::new(scheduler),
+			scheduler,
 			handle,
 
 			visible: false,
@@ -62,6 +79,15 @@ impl Tasks {
 		self.ongoing().lock().values().take(Self::limit()).map(Into::into).collect()
 	}
 
+	// Whether any user task is still running, the failed ones aside
+	pub fn is_busy(&self) -> bool {
+		self
+			.ongoing()
+			.lock()
+			.values()
+			.any(|t| t.kind == TaskKind::User && (t.fail == 0 || t.succ + t.fail < t.total))
+	}
+
 	#[inline]
 	pub fn ongoing(&self) -> &Arc<Mutex<Ongoing>> { &self.scheduler.ongoing }
 }
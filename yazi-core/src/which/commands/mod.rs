@@ -1,2 +1,3 @@
 mod callback;
 mod show;
+mod timeout;
@@ -39,6 +39,7 @@ impl Which {
 
 		self.visible = true;
 		self.silent = opt.silent;
+		self.arm();
 		render!();
 	}
 
@@ -46,15 +47,17 @@ impl Which {
 		self.layer = layer;
 		self.times = 1;
 		self.cands = KEYMAP
+			.load()
 			.get(layer)
 			.iter()
 			.filter(|c| c.on.len() > 1 && c.on[0] == key)
-			.map(|c| c.into())
+			.map(|c| c.clone().into())
 			.collect();
 
 		WhichSorter::default().sort(&mut self.cands);
 		self.visible = true;
 		self.silent = false;
+		self.arm();
 		render!();
 	}
 }
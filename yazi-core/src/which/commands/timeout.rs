@@ -0,0 +1,28 @@
+use yazi_shared::{event::{Cmd, Data}, render};
+
+use crate::which::Which;
+
+pub struct Opt {
+	epoch: usize,
+}
+
+impl TryFrom<Cmd> for Opt {
+	type Error = ();
+
+	fn try_from(c: Cmd) -> Result<Self, Self::Error> {
+		Ok(Self { epoch: c.first().and_then(Data::as_usize).ok_or(())? })
+	}
+}
+
+impl Which {
+	pub fn timeout(&mut self, opt: impl TryInto<Opt>) {
+		let Ok(opt) = opt.try_into() else {
+			return;
+		};
+
+		if self.visible && opt.epoch == self.epoch {
+			self.reset();
+			render!();
+		}
+	}
+}
@@ -1,11 +1,18 @@
-use yazi_config::keymap::{ChordCow, Key};
-use yazi_shared::{Layer, emit, render, render_and};
+use std::{collections::VecDeque, time::Duration};
+
+use tokio::time::sleep;
+use yazi_config::{WHICH, keymap::{ChordCow, Key}};
+use yazi_shared::{Layer, emit, event::{Cmd, Data}, render, render_and};
 
 #[derive(Default)]
 pub struct Which {
 	pub(super) layer: Layer,
 	pub times:        usize,
 	pub cands:        Vec<ChordCow>,
+	pub(super) epoch: usize,
+
+	// The digits typed before a key, e.g. the "5" of "5j"
+	pub count: Option<usize>,
 
 	// Visibility
 	pub visible: bool,
@@ -20,19 +27,70 @@ impl Which {
 		if self.cands.is_empty() {
 			self.reset();
 		} else if self.cands.len() == 1 {
-			emit!(Seq(self.cands.remove(0).into_seq(), self.layer));
+			let seq = self.cands.remove(0).into_seq();
+			emit!(Seq(self.counted(seq), self.layer));
 			self.reset();
 		} else if let Some(i) = self.cands.iter().position(|c| c.on.len() == self.times) {
-			emit!(Seq(self.cands.remove(i).into_seq(), self.layer));
+			let seq = self.cands.remove(i).into_seq();
+			emit!(Seq(self.counted(seq), self.layer));
 			self.reset();
+		} else {
+			self.arm();
+		}
+
+		render_and!(true)
+	}
+
+	// A digit starts or extends the count, unless it's a leading "0", or is bound
+	// to a command of its own and no count is in progress
+	pub fn count(&mut self, key: Key, bound: bool) -> bool {
+		let Some(d) = key.plain().and_then(|c| c.to_digit(10)) else {
+			return false;
+		};
+		if self.count.is_none() && (d == 0 || bound) {
+			return false;
 		}
 
+		self.count = Some(self.count.unwrap_or(0).saturating_mul(10).saturating_add(d as usize));
 		render_and!(true)
 	}
 
-	fn reset(&mut self) {
+	// Hand the count over to every command of the chord, as its `count` argument
+	pub fn counted(&mut self, mut seq: VecDeque<Cmd>) -> VecDeque<Cmd> {
+		if let Some(n) = self.count.take() {
+			for cmd in &mut seq {
+				cmd.args.insert("count".to_owned(), Data::Integer(n as i64));
+			}
+		}
+		seq
+	}
+
+	// The count and the keys typed so far for the chord in progress
+	pub fn pending(&self) -> Option<String> {
+		let count = self.count.map(|n| n.to_string()).unwrap_or_default();
+		let Some(chord) = self.cands.first().filter(|_| self.visible) else {
+			return Some(count).filter(|s| !s.is_empty());
+		};
+		Some(count + &chord.on.iter().take(self.times).map(ToString::to_string).collect::<String>())
+	}
+
+	pub(super) fn arm(&mut self) {
+		self.epoch += 1;
+		if WHICH.timeout == 0 {
+			return;
+		}
+
+		let epoch = self.epoch;
+		tokio::spawn(async move {
+			sleep(Duration::from_millis(WHICH.timeout)).await;
+			emit!(Call(Cmd::args("timeout", &[epoch]), Layer::Which));
+		});
+	}
+
+	pub(super) fn reset(&mut self) {
 		self.times = 0;
 		self.cands.clear();
+		self.count = None;
 
 		self.visible = false;
 		self.silent = false;
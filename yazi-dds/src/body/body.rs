@@ -2,7 +2,7 @@ use anyhow::{Result, bail};
 use mlua::{ExternalResult, IntoLua, Lua, Value};
 use serde::Serialize;
 
-use super::{BodyBulk, BodyBye, BodyCd, BodyCustom, BodyDelete, BodyHey, BodyHi, BodyHover, BodyMove, BodyRename, BodyTab, BodyTrash, BodyYank};
+use super::{BodyBulk, BodyBye, BodyCd, BodyCustom, BodyDelete, BodyHey, BodyHi, BodyHover, BodyMove, BodyRename, BodyTab, BodyTask, BodyTrash, BodyYank};
 use crate::Payload;
 
 #[derive(Debug, Serialize)]
@@ -20,6 +20,7 @@ pub enum Body<'a> {
 	Move(BodyMove<'a>),
 	Trash(BodyTrash<'a>),
 	Delete(BodyDelete<'a>),
+	Task(BodyTask<'a>),
 	Custom(BodyCustom),
 }
 
@@ -38,6 +39,7 @@ impl Body<'static> {
 			"move" => Self::Move(serde_json::from_str(body)?),
 			"trash" => Self::Trash(serde_json::from_str(body)?),
 			"delete" => Self::Delete(serde_json::from_str(body)?),
+			"task" => Self::Task(serde_json::from_str(body)?),
 			_ => BodyCustom::from_str(kind, body)?,
 		})
 	}
@@ -62,6 +64,7 @@ impl Body<'static> {
 				| "move"
 				| "trash"
 				| "delete"
+				| "task"
 		) {
 			bail!("Cannot construct system event");
 		}
@@ -94,6 +97,7 @@ impl<'a> Body<'a> {
 			Self::Move(_) => "move",
 			Self::Trash(_) => "trash",
 			Self::Delete(_) => "delete",
+			Self::Task(_) => "task",
 			Self::Custom(b) => b.kind.as_str(),
 		}
 	}
@@ -122,6 +126,7 @@ impl IntoLua<'_> for Body<'static> {
 			Self::Move(b) => b.into_lua(lua),
 			Self::Trash(b) => b.into_lua(lua),
 			Self::Delete(b) => b.into_lua(lua),
+			Self::Task(b) => b.into_lua(lua),
 			Self::Custom(b) => b.into_lua(lua),
 		}
 	}
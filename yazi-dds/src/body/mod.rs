@@ -12,6 +12,7 @@ mod hover;
 mod move_;
 mod rename;
 mod tab;
+mod task;
 mod trash;
 mod yank;
 
@@ -27,5 +28,6 @@ pub use hover::*;
 pub use move_::*;
 pub use rename::*;
 pub use tab::*;
+pub use task::*;
 pub use trash::*;
 pub use yank::*;
@@ -0,0 +1,38 @@
+use std::borrow::Cow;
+
+use mlua::{IntoLua, Lua, Value};
+use serde::{Deserialize, Serialize};
+
+use super::Body;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BodyTask<'a> {
+	pub id:   usize,
+	pub name: Cow<'a, str>,
+}
+
+impl<'a> BodyTask<'a> {
+	#[inline]
+	pub fn borrowed(id: usize, name: &'a str) -> Body<'a> {
+		Self { id, name: Cow::Borrowed(name) }.into()
+	}
+}
+
+impl BodyTask<'static> {
+	#[inline]
+	pub fn owned(id: usize, name: &str) -> Body<'static> {
+		Self { id, name: Cow::Owned(name.to_owned()) }.into()
+	}
+}
+
+impl<'a> From<BodyTask<'a>> for Body<'a> {
+	fn from(value: BodyTask<'a>) -> Self { Self::Task(value) }
+}
+
+impl IntoLua<'_> for BodyTask<'static> {
+	fn into_lua(self, lua: &Lua) -> mlua::Result<Value> {
+		lua
+			.create_table_from([("id", self.id.into_lua(lua)?), ("name", self.name.into_lua(lua)?)])?
+			.into_lua(lua)
+	}
+}
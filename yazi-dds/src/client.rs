@@ -102,6 +102,33 @@ impl Client {
 		Ok(())
 	}
 
+	/// Connect to an existing server to ask an instance about its state, and
+	/// wait for the JSON reply.
+	pub async fn query(receiver: u64, what: &str) -> Result<String> {
+		let payload = format!(
+			"{}\ndds-query,{receiver},{ID},{}\n",
+			Payload::new(BodyHi::borrowed(HashSet::from(["dds-reply"]))),
+			serde_json::to_string(what)?
+		);
+
+		let (mut lines, mut writer) = Stream::connect().await?;
+		writer.write_all(payload.as_bytes()).await?;
+		writer.flush().await?;
+
+		let reply = time::timeout(time::Duration::from_secs(3), async {
+			while let Ok(Some(line)) = lines.next_line().await {
+				if line.starts_with("dds-reply,") {
+					return line.splitn(4, ',').nth(3).map(ToOwned::to_owned);
+				}
+			}
+			None
+		});
+
+		let reply = reply.await.ok().flatten();
+		writer.write_all(format!("{}\n", Payload::new(BodyBye::owned())).as_bytes()).await.ok();
+		reply.context("No reply from the instance")
+	}
+
 	/// Connect to an existing server and listen in on the messages that are being
 	/// sent by other yazi instances:
 	///   - If no server is running, fail right away;
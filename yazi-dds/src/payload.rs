@@ -1,8 +1,9 @@
 use std::{fmt::Display, io::Write, str::FromStr};
 
 use anyhow::{Result, anyhow};
+use tracing::error;
 use yazi_boot::BOOT;
-use yazi_shared::{Layer, emit, event::Cmd};
+use yazi_shared::{Layer, emit, event::{Cmd, Data}};
 
 use crate::{ID, body::Body};
 
@@ -46,8 +47,40 @@ impl<'a> Payload<'a> {
 impl Payload<'static> {
 	pub(super) fn emit(self) {
 		self.try_flush();
+		if self.body.kind() == "dds-emit" {
+			if let Body::Custom(b) = self.body {
+				Self::emit_cmd(b.data);
+			}
+			return;
+		}
+		if self.body.kind() == "dds-query" {
+			if let Body::Custom(b) = self.body {
+				Self::emit_query(self.sender, b.data);
+			}
+			return;
+		}
 		emit!(Call(Cmd::new("accept_payload").with_any("payload", self), Layer::App));
 	}
+
+	// Commands sent from outside by `ya emit`, as a list of the name and arguments
+	fn emit_cmd(data: Data) {
+		let Data::List(list) = data else { return };
+		let args: Vec<_> = list
+			.into_iter()
+			.filter_map(|d| if let Data::String(s) = d { Some(s) } else { None })
+			.collect();
+
+		match Cmd::try_from(args) {
+			Ok(cmd) => emit!(Call(cmd, Layer::Manager)),
+			Err(e) => error!("Could not parse the emitted command: {e}"),
+		}
+	}
+
+	// Queries sent from outside by `ya query`, answered to the sender
+	fn emit_query(sender: u64, data: Data) {
+		let Data::String(what) = data else { return };
+		emit!(Call(Cmd::new("accept_query").with("what", what).with("sender", sender), Layer::App));
+	}
 }
 
 impl FromStr for Payload<'static> {
@@ -89,6 +122,7 @@ impl Display for Payload<'_> {
 			Body::Move(b) => serde_json::to_string(b),
 			Body::Trash(b) => serde_json::to_string(b),
 			Body::Delete(b) => serde_json::to_string(b),
+			Body::Task(b) => serde_json::to_string(b),
 			Body::Custom(b) => serde_json::to_string(b),
 		};
 
@@ -3,9 +3,9 @@ use std::collections::{HashMap, HashSet};
 use mlua::Function;
 use parking_lot::RwLock;
 use yazi_boot::BOOT;
-use yazi_shared::{RoCell, fs::Url};
+use yazi_shared::{RoCell, event::Data, fs::Url};
 
-use crate::{Client, ID, PEERS, body::{Body, BodyBulk, BodyCd, BodyDelete, BodyHi, BodyHover, BodyMove, BodyMoveItem, BodyRename, BodyTab, BodyTrash, BodyYank}};
+use crate::{Client, ID, PEERS, body::{Body, BodyBulk, BodyCd, BodyCustom, BodyDelete, BodyHi, BodyHover, BodyMove, BodyMoveItem, BodyRename, BodyTab, BodyTask, BodyTrash, BodyYank}};
 
 pub static LOCAL: RoCell<RwLock<HashMap<String, HashMap<String, Function<'static>>>>> =
 	RoCell::new();
@@ -82,12 +82,22 @@ impl Pubsub {
 
 	pub fn pub_from_hi() -> bool {
 		let abilities = REMOTE.read().keys().cloned().collect();
-		let abilities = BOOT.remote_events.union(&abilities).map(|s| s.as_str()).collect();
+		let mut abilities: HashSet<_> =
+			BOOT.remote_events.union(&abilities).map(|s| s.as_str()).collect();
+
+		// Always accept the commands sent by `ya emit`, and the queries by `ya query`
+		abilities.insert("dds-emit");
+		abilities.insert("dds-query");
 
 		Client::push(BodyHi::borrowed(abilities));
 		true
 	}
 
+	pub fn pub_from_query(receiver: u64, data: Data) {
+		let body = Body::Custom(BodyCustom { kind: "dds-reply".to_owned(), data });
+		Client::push(body.with_receiver(receiver));
+	}
+
 	pub fn pub_from_cd(tab: usize, url: &Url) {
 		if LOCAL.read().contains_key("cd") {
 			Self::pub_(BodyCd::dummy(tab));
@@ -196,6 +206,18 @@ impl Pubsub {
 		}
 	}
 
+	pub fn pub_from_task(id: usize, name: &str) {
+		if LOCAL.read().contains_key("task") {
+			Self::pub_(BodyTask::owned(id, name));
+		}
+		if PEERS.read().values().any(|p| p.able("task")) {
+			Client::push(BodyTask::borrowed(id, name));
+		}
+		if BOOT.local_events.contains("task") {
+			BodyTask::borrowed(id, name).with_receiver(*ID).flush();
+		}
+	}
+
 	#[inline]
 	fn any_remote_own(kind: &str) -> bool {
 		REMOTE.read().contains_key(kind)  // Owned abilities
@@ -1,8 +1,9 @@
-use std::{collections::VecDeque, sync::atomic::Ordering};
+use std::{collections::VecDeque, sync::atomic::Ordering, time::{Duration, Instant}};
 
 use anyhow::Result;
 use crossterm::event::KeyEvent;
-use yazi_config::keymap::Key;
+use tokio::time::timeout;
+use yazi_config::{MANAGER, keymap::Key};
 use yazi_core::input::InputMode;
 use yazi_shared::{Layer, emit, event::{Cmd, Event, NEED_RENDER}};
 
@@ -17,32 +18,32 @@ pub(crate) struct App {
 impl App {
 	pub(crate) async fn serve() -> Result<()> {
 		let term = Term::start()?;
-		let (mut rx, signals) = (Event::take(), Signals::start()?);
+		let (mut rx, signals) = (Event::take(), Signals::start(true)?);
 
 		Lives::register()?;
 		let mut app = Self { cx: Ctx::make(), term: Some(term), signals };
 		app.render();
 
-		let mut times = 0;
-		let mut events = Vec::with_capacity(200);
-		while rx.recv_many(&mut events, 50).await > 0 {
-			for event in events.drain(..) {
-				times += 1;
-				app.dispatch(event)?;
+		let interval = Duration::from_secs(1) / MANAGER.max_fps as u32;
+		let (mut events, mut last) = (Vec::with_capacity(200), Instant::now());
+		loop {
+			// With a render pending, wait for more events only until the next frame is due
+			let n = if NEED_RENDER.load(Ordering::Relaxed) {
+				let left = interval.saturating_sub(last.elapsed());
+				timeout(left, rx.recv_many(&mut events, 50)).await.unwrap_or(usize::MAX)
+			} else {
+				rx.recv_many(&mut events, 50).await
+			};
+			if n == 0 {
+				break;
 			}
 
-			if !NEED_RENDER.swap(false, Ordering::Relaxed) {
-				continue;
+			for event in events.drain(..) {
+				app.dispatch(event)?;
 			}
 
-			if times >= 50 {
-				times = 0;
-				app.render();
-			} else if let Ok(event) = rx.try_recv() {
-				events.push(event);
-				emit!(Render);
-			} else {
-				times = 0;
+			if NEED_RENDER.load(Ordering::Relaxed) && last.elapsed() >= interval {
+				last = Instant::now();
 				app.render();
 			}
 		}
@@ -50,7 +51,7 @@ impl App {
 	}
 
 	#[inline]
-	fn dispatch(&mut self, event: Event) -> Result<()> {
+	pub(super) fn dispatch(&mut self, event: Event) -> Result<()> {
 		match event {
 			Event::Call(cmd, layer) => self.dispatch_call(cmd, layer),
 			Event::Seq(cmds, layer) => self.dispatch_seq(cmds, layer),
@@ -65,7 +66,9 @@ impl App {
 	}
 
 	#[inline]
-	fn dispatch_call(&mut self, cmd: Cmd, layer: Layer) { Executor::new(self).execute(cmd, layer); }
+	pub(super) fn dispatch_call(&mut self, cmd: Cmd, layer: Layer) {
+		Executor::new(self).execute(cmd, layer);
+	}
 
 	#[inline]
 	fn dispatch_seq(&mut self, mut cmds: VecDeque<Cmd>, layer: Layer) {
@@ -0,0 +1,24 @@
+use yazi_dds::Pubsub;
+use yazi_shared::event::{Cmd, Data};
+
+use crate::app::App;
+
+impl App {
+	pub(crate) fn accept_query(&mut self, cmd: Cmd) {
+		let Some(sender) = cmd.str("sender").and_then(|s| s.parse().ok()) else {
+			return;
+		};
+
+		let manager = &self.cx.manager;
+		let data = match cmd.str("what").unwrap_or_default() {
+			"current-dir" => Data::Url(manager.cwd().clone()),
+			"hovered" => manager.hovered().map_or(Data::Nil, |h| Data::Url(h.url_owned())),
+			"selection" => {
+				Data::List(manager.active().selected.keys().map(|u| Data::Url(u.clone())).collect())
+			}
+			_ => return,
+		};
+
+		Pubsub::pub_from_query(sender, data);
+	}
+}
@@ -0,0 +1,28 @@
+use std::io::Write;
+
+use yazi_shared::{event::{Cmd, Data}, fs::Url};
+
+use crate::app::App;
+
+pub struct Opt {
+	dir: Option<Url>,
+}
+
+impl From<Cmd> for Opt {
+	fn from(mut c: Cmd) -> Self { Self { dir: c.take_first().and_then(Data::into_url) } }
+}
+
+impl App {
+	// Only worth it when the change is out of sight
+	pub(crate) fn bell(&mut self, opt: impl Into<Opt>) {
+		let opt = opt.into() as Opt;
+		if opt.dir.as_ref() == Some(self.cx.manager.cwd()) {
+			return;
+		}
+
+		if let Some(term) = &mut self.term {
+			let w = term.backend_mut();
+			w.write_all(b"\x07").and_then(|()| w.flush()).ok();
+		}
+	}
+}
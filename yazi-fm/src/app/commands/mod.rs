@@ -1,8 +1,11 @@
 mod accept_payload;
+mod accept_query;
+mod bell;
 mod mouse;
 mod notify;
 mod plugin;
 mod quit;
+mod reload;
 mod render;
 mod resize;
 mod resume;
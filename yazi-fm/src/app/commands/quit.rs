@@ -1,6 +1,8 @@
 use std::ffi::OsString;
 
 use yazi_boot::ARGS;
+use yazi_core::manager::Session;
+use yazi_fs::Remote;
 use yazi_shared::event::EventQuit;
 
 use crate::{Term, app::App};
@@ -9,8 +11,10 @@ impl App {
 	pub(crate) fn quit(&mut self, opt: EventQuit) -> ! {
 		self.cx.tasks.shutdown();
 		self.cx.manager.shutdown();
+		Session::save(&self.cx.manager.tabs);
 		futures::executor::block_on(yazi_dds::shutdown());
 		futures::executor::block_on(yazi_dds::STATE.drain()).ok();
+		Remote::unmount_all();
 
 		if !opt.no_cwd_file {
 			self.cwd_to_file();
@@ -0,0 +1,26 @@
+use std::time::Duration;
+
+use yazi_plugin::LUA;
+use yazi_proxy::{AppProxy, options::{NotifyLevel, NotifyOpt}};
+use yazi_shared::event::Cmd;
+
+use crate::app::App;
+
+impl App {
+	pub(crate) fn reload(&mut self, _: Cmd) {
+		if let Err(e) = yazi_config::reload() {
+			return AppProxy::notify_error("Config reload", format!("{e:#}"));
+		}
+		if let Err(e) = yazi_plugin::Config::new(&LUA).install_theme() {
+			return AppProxy::notify_error("Config reload", e);
+		}
+
+		AppProxy::notify(NotifyOpt {
+			title:   "Config reload".to_owned(),
+			content: "Keymap and theme reloaded".to_owned(),
+			level:   NotifyLevel::Info,
+			timeout: Duration::from_secs(5),
+		});
+		self.resize(());
+	}
+}
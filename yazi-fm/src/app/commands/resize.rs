@@ -15,6 +15,14 @@ impl From<()> for Opt {
 impl App {
 	pub(crate) fn resize(&mut self, _: impl Into<Opt>) {
 		self.cx.manager.active_mut().preview.reset();
+
+		// Popups may have shrunk, scroll their cursors back into view
+		if self.cx.input.visible {
+			self.cx.input.move_(0);
+		}
+		if self.cx.select.visible {
+			self.cx.select.reflow();
+		}
 		self.render();
 
 		self.cx.manager.current_mut().sync_page(true);
@@ -1,4 +1,9 @@
+use std::io::stderr;
+
+use crossterm::{execute, style::Print, terminal::SetTitle};
 use ratatui::backend::Backend;
+use yazi_adapter::tcsi;
+use yazi_config::{MANAGER, TASKS};
 use yazi_core::tasks::TasksProgress;
 use yazi_shared::{event::Cmd, render};
 
@@ -23,8 +28,10 @@ impl App {
 		};
 
 		// Update the progress of all tasks.
+		self.cx.tasks.progress = opt.progress;
+		self.report_progress();
+
 		let tasks = &mut self.cx.tasks;
-		tasks.progress = opt.progress;
 
 		// If the task manager is visible, update the summaries with a complete render.
 		if tasks.visible {
@@ -55,4 +62,26 @@ impl App {
 			Ok(())
 		});
 	}
+
+	// Show the progress outside the UI as well, for minimized terminals
+	fn report_progress(&self) {
+		if self.term.is_none() {
+			return;
+		}
+
+		let percent = self.cx.tasks.progress.percent();
+		if TASKS.progress_title && !MANAGER.title_format.is_empty() {
+			let title = self.cx.manager.title();
+			let title = percent.map_or_else(|| title.clone(), |p| format!("[{p}%] {title}"));
+			execute!(stderr(), SetTitle(title)).ok();
+		}
+
+		if TASKS.progress_osc {
+			let s = match percent {
+				Some(p) => format!("\x1b]9;4;1;{p}\x1b\\"),
+				None => "\x1b]9;4;0;0\x1b\\".to_owned(),
+			};
+			execute!(stderr(), Print(tcsi(&s))).ok();
+		}
+	}
 }
@@ -0,0 +1,92 @@
+use std::{io::BufRead, str::FromStr, time::Duration};
+
+use anyhow::Result;
+use serde_json::{Value, json};
+use tokio::{sync::mpsc::UnboundedReceiver, time::{sleep, timeout}};
+use yazi_boot::ARGS;
+use yazi_shared::{Layer, event::{Cmd, Event}};
+
+use crate::{Ctx, Signals, app::App, lives::Lives};
+
+impl App {
+	// Run the commands given by `--run`, or read from stdin without any, against
+	// the manager without drawing, and print a line of JSON after each of them
+	pub(crate) async fn headless() -> Result<()> {
+		let mut rx = Event::take();
+
+		Lives::register()?;
+		let mut app = Self { cx: Ctx::make(), term: None, signals: Signals::start(false)? };
+
+		let lines: Vec<_> = if ARGS.run.is_empty() {
+			tokio::task::spawn_blocking(|| {
+				std::io::stdin().lock().lines().map_while(Result::ok).collect()
+			})
+			.await?
+		} else {
+			ARGS.run.clone()
+		};
+
+		let mut running = app.settle(&mut rx).await;
+		for line in lines.iter().map(|s| s.trim()).filter(|s| !s.is_empty() && !s.starts_with('#')) {
+			if !running {
+				break;
+			}
+
+			let report = match Cmd::from_str(line) {
+				Ok(cmd) => {
+					app.dispatch_call(cmd, Layer::Manager);
+					running = app.settle(&mut rx).await;
+					app.report(line)
+				}
+				Err(e) => json!({ "cmd": line, "ok": false, "error": e.to_string() }),
+			};
+			println!("{report}");
+		}
+
+		// Wait for the file operations started by the commands to finish
+		while app.cx.tasks.is_busy() {
+			sleep(Duration::from_millis(100)).await;
+			app.settle(&mut rx).await;
+		}
+
+		let failed: Vec<_> = {
+			let ongoing = app.cx.tasks.ongoing().lock();
+			ongoing.values().filter(|t| t.fail > 0).map(|t| t.name.clone()).collect()
+		};
+		println!("{}", json!({ "done": true, "failed": failed }));
+
+		app.cx.tasks.shutdown();
+		app.cx.manager.shutdown();
+		yazi_dds::shutdown().await;
+		Ok(())
+	}
+
+	// Handle the events a command caused, until none has come in for a while,
+	// returns `false` if one of them asked to quit
+	async fn settle(&mut self, rx: &mut UnboundedReceiver<Event>) -> bool {
+		let mut events = Vec::with_capacity(50);
+		while let Ok(n) = timeout(Duration::from_millis(100), rx.recv_many(&mut events, 50)).await {
+			if n == 0 {
+				return false;
+			}
+			for event in events.drain(..) {
+				match event {
+					Event::Quit(_) => return false,
+					event => _ = self.dispatch(event),
+				}
+			}
+		}
+		true
+	}
+
+	fn report(&self, line: &str) -> Value {
+		let manager = &self.cx.manager;
+		json!({
+			"cmd": line,
+			"ok": true,
+			"cwd": manager.cwd(),
+			"hovered": manager.hovered().map(|h| &h.url),
+			"selected": manager.active().selected.keys().collect::<Vec<_>>(),
+		})
+	}
+}
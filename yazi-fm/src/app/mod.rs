@@ -1,4 +1,5 @@
 mod app;
 mod commands;
+mod headless;
 
 pub(crate) use app::*;
@@ -16,6 +16,7 @@ impl<'a> Completion<'a> {
 
 impl<'a> Widget for Completion<'a> {
 	fn render(self, rect: Rect, buf: &mut Buffer) {
+		let theme = THEME.load();
 		let items: Vec<_> = self
 			.cx
 			.completion
@@ -24,16 +25,16 @@ impl<'a> Widget for Completion<'a> {
 			.enumerate()
 			.map(|(i, x)| {
 				let icon = if x.ends_with(MAIN_SEPARATOR) {
-					&THEME.completion.icon_folder
+					&theme.completion.icon_folder
 				} else {
-					&THEME.completion.icon_file
+					&theme.completion.icon_file
 				};
 
 				let mut item = ListItem::new(format!(" {icon} {x}"));
 				if i == self.cx.completion.rel_cursor() {
-					item = item.style(THEME.completion.active);
+					item = item.style(theme.completion.active);
 				} else {
-					item = item.style(THEME.completion.inactive);
+					item = item.style(theme.completion.inactive);
 				}
 
 				item
@@ -58,7 +59,7 @@ impl<'a> Widget for Completion<'a> {
 		yazi_plugin::elements::Clear::default().render(area, buf);
 		List::new(items)
 			.block(
-				Block::bordered().border_type(BorderType::Rounded).border_style(THEME.completion.border),
+				Block::bordered().border_type(BorderType::Rounded).border_style(theme.completion.border),
 			)
 			.render(area, buf);
 	}
@@ -1,4 +1,5 @@
-use ratatui::{buffer::Buffer, widgets::Widget};
+use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
+use yazi_config::THEME;
 
 use crate::Ctx;
 
@@ -12,7 +13,7 @@ impl<'a> Preview<'a> {
 }
 
 impl Widget for Preview<'_> {
-	fn render(self, area: ratatui::layout::Rect, buf: &mut Buffer) {
+	fn render(self, area: Rect, buf: &mut Buffer) {
 		let preview = &self.cx.manager.active().preview;
 		let Some(lock) = &preview.lock else {
 			return;
@@ -25,5 +26,12 @@ impl Widget for Preview<'_> {
 		for w in &lock.data {
 			w.clone_render(buf);
 		}
+
+		if let Some(y) = preview.line.and_then(|n| (n - 1).checked_sub(lock.skip)) {
+			if y < area.height as usize {
+				let style = THEME.load().manager.preview_line;
+				buf.set_style(Rect { y: area.y + y as u16, height: 1, ..area }, style);
+			}
+		}
 	}
 }
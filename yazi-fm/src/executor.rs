@@ -1,5 +1,7 @@
+use yazi_config::KEYMAP;
 use yazi_core::input::InputMode;
-use yazi_shared::{Layer, event::Cmd};
+use yazi_proxy::AppProxy;
+use yazi_shared::{Layer, emit, event::Cmd};
 
 use crate::app::App;
 
@@ -36,12 +38,15 @@ impl<'a> Executor<'a> {
 		}
 
 		on!(accept_payload);
+		on!(accept_query);
+		on!(bell);
 		on!(notify);
 		on!(plugin);
 		on!(plugin_do);
 		on!(update_notify);
 		on!(update_progress);
 		on!(resize);
+		on!(reload);
 		on!(stop);
 		on!(resume);
 	}
@@ -71,17 +76,26 @@ impl<'a> Executor<'a> {
 		on!(MANAGER, update_paged, &self.app.cx.tasks);
 		on!(MANAGER, update_yanked);
 		on!(MANAGER, hover);
+		on!(MANAGER, info);
+		on!(MANAGER, xattr);
 		on!(MANAGER, peek);
 		on!(MANAGER, seek);
+		on!(MANAGER, preview_line);
 		on!(MANAGER, refresh, &self.app.cx.tasks);
 		on!(MANAGER, quit, &self.app.cx.tasks);
 		on!(MANAGER, close, &self.app.cx.tasks);
 		on!(MANAGER, suspend);
+		on!(MANAGER, ratio);
+		on!(MANAGER, zen);
+		on!(MANAGER, palette);
 		on!(ACTIVE, escape);
 		on!(ACTIVE, preview);
+		on!(ACTIVE, zoom);
+		on!(ACTIVE, quicklook);
 
 		// Navigation
 		on!(ACTIVE, arrow);
+		on!(ACTIVE, goto);
 		on!(ACTIVE, leave);
 		on!(ACTIVE, enter);
 		on!(ACTIVE, back);
@@ -99,6 +113,7 @@ impl<'a> Executor<'a> {
 		on!(MANAGER, open_do, &self.app.cx.tasks);
 		on!(MANAGER, yank);
 		on!(MANAGER, unyank);
+		on!(MANAGER, unfilter);
 		on!(MANAGER, paste, &self.app.cx.tasks);
 		on!(MANAGER, link, &self.app.cx.tasks);
 		on!(MANAGER, hardlink, &self.app.cx.tasks);
@@ -106,8 +121,13 @@ impl<'a> Executor<'a> {
 		on!(MANAGER, remove_do, &self.app.cx.tasks);
 		on!(MANAGER, create);
 		on!(MANAGER, rename);
+		on!(MANAGER, undo, &self.app.cx.tasks);
+		on!(MANAGER, restore, &self.app.cx.tasks);
+		on!(MANAGER, send, &self.app.cx.tasks);
 		on!(ACTIVE, copy);
+		on!(ACTIVE, drag);
 		on!(ACTIVE, shell);
+		on!(ACTIVE, subshell);
 		on!(ACTIVE, hidden);
 		on!(ACTIVE, linemode);
 		on!(ACTIVE, search);
@@ -130,15 +150,23 @@ impl<'a> Executor<'a> {
 		on!(TABS, close);
 		on!(TABS, switch);
 		on!(TABS, swap);
+		on!(TABS, rename);
 
 		match cmd.name.as_bytes() {
 			// Tasks
 			b"tasks_show" => self.app.cx.tasks.toggle(()),
+			// Notify
+			b"notify_history" => self.app.cx.notify.history(cmd),
 			// Help
 			b"help" => self.app.cx.help.toggle(Layer::Manager),
 			// Plugin
 			b"plugin" => self.app.plugin(cmd),
-			_ => {}
+			// Alias
+			_ => match KEYMAP.load().alias(&cmd.name) {
+				Some(Ok(run)) => emit!(Seq(run.into(), Layer::Manager)),
+				Some(Err(e)) => AppProxy::notify_warn("Alias", e),
+				None => {}
+			},
 		}
 	}
 
@@ -320,5 +348,6 @@ impl<'a> Executor<'a> {
 
 		on!(show);
 		on!(callback);
+		on!(timeout);
 	}
 }
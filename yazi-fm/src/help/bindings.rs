@@ -13,6 +13,7 @@ impl<'a> Bindings<'a> {
 
 impl Widget for Bindings<'_> {
 	fn render(self, area: Rect, buf: &mut Buffer) {
+		let theme = THEME.load();
 		let bindings = &self.cx.help.window();
 		if bindings.is_empty() {
 			return;
@@ -20,16 +21,16 @@ impl Widget for Bindings<'_> {
 
 		// On
 		let col1: Vec<_> =
-			bindings.iter().map(|c| ListItem::new(c.on()).style(THEME.help.on)).collect();
+			bindings.iter().map(|c| ListItem::new(c.on()).style(theme.help.on)).collect();
 
 		// Run
 		let col2: Vec<_> =
-			bindings.iter().map(|c| ListItem::new(c.run()).style(THEME.help.run)).collect();
+			bindings.iter().map(|c| ListItem::new(c.run()).style(theme.help.run)).collect();
 
 		// Desc
 		let col3: Vec<_> = bindings
 			.iter()
-			.map(|c| ListItem::new(c.desc().unwrap_or("-".into())).style(THEME.help.desc))
+			.map(|c| ListItem::new(c.desc().unwrap_or("-".into())).style(theme.help.desc))
 			.collect();
 
 		let chunks = layout::Layout::horizontal([
@@ -42,7 +43,7 @@ impl Widget for Bindings<'_> {
 		let cursor = self.cx.help.rel_cursor() as u16;
 		buf.set_style(
 			Rect { x: area.x, y: area.y + cursor, width: area.width, height: 1 },
-			THEME.help.hovered,
+			theme.help.hovered,
 		);
 
 		List::new(col1).render(chunks[0], buf);
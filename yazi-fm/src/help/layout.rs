@@ -12,7 +12,7 @@ impl<'a> Layout<'a> {
 	pub fn new(cx: &'a Ctx) -> Self { Self { cx } }
 
 	fn tips() -> String {
-		match KEYMAP.help.iter().find(|&c| c.run.iter().any(|c| c.name == "filter")) {
+		match KEYMAP.load().help.iter().find(|&c| c.run.iter().any(|c| c.name == "filter")) {
 			Some(c) => format!(" (Press `{}` to filter)", c.on()),
 			None => String::new(),
 		}
@@ -27,7 +27,7 @@ impl<'a> Widget for Layout<'a> {
 		let chunks = layout::Layout::vertical([Constraint::Fill(1), Constraint::Length(1)]).split(area);
 		Line::styled(
 			help.keyword().unwrap_or_else(|| format!("{}.help{}", help.layer, Self::tips())),
-			THEME.help.footer,
+			THEME.load().help.footer,
 		)
 		.render(chunks[1], buf);
 
@@ -33,6 +33,7 @@ impl<'a> Input<'a> {
 
 impl<'a> Widget for Input<'a> {
 	fn render(self, win: Rect, buf: &mut Buffer) {
+		let theme = THEME.load();
 		let input = &self.cx.input;
 		let area = self.cx.manager.area(input.position);
 
@@ -41,10 +42,10 @@ impl<'a> Widget for Input<'a> {
 			.block(
 				Block::bordered()
 					.border_type(BorderType::Rounded)
-					.border_style(THEME.input.border)
-					.title(Line::styled(&input.title, THEME.input.title)),
+					.border_style(theme.input.border)
+					.title(Line::styled(&input.title, theme.input.title)),
 			)
-			.style(THEME.input.value)
+			.style(theme.input.value)
 			.render(area, buf);
 
 		if let Some(Range { start, end }) = input.selected() {
@@ -53,7 +54,7 @@ impl<'a> Widget for Input<'a> {
 
 			buf.set_style(
 				Rect { x, y, width: (end - start).min(win.width - x), height: 1.min(win.height - y) },
-				THEME.input.selected,
+				theme.input.selected,
 			)
 		}
 
@@ -59,7 +59,8 @@ impl File {
 				let mime =
 					if me.is_dir() { MIME_DIR } else { cx.manager.mimetype.get(&me.url).unwrap_or_default() };
 
-				Ok(THEME.filetypes.iter().find(|&x| x.matches(me, mime)).map(|x| Style::from(x.style)))
+				let theme = THEME.load();
+				Ok(theme.filetypes.iter().find(|&x| x.matches(me, mime)).map(|x| Style::from(x.style)))
 			});
 			reg.add_method("is_hovered", |_, me, ()| Ok(me.idx == me.folder().cursor));
 			reg.add_method("is_yanked", |lua, me, ()| {
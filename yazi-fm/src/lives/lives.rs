@@ -26,6 +26,7 @@ impl Lives {
 		super::Tab::register(&LUA)?;
 		super::Tabs::register(&LUA)?;
 		super::Tasks::register(&LUA)?;
+		super::Which::register(&LUA)?;
 		super::Yanked::register(&LUA)?;
 
 		Ok(())
@@ -49,6 +50,7 @@ impl Lives {
 					("active", super::Tab::make(cx.manager.active())?),
 					("tabs", super::Tabs::make(&cx.manager.tabs)?),
 					("tasks", super::Tasks::make(&cx.tasks)?),
+					("which", super::Which::make(&cx.which)?),
 					("yanked", super::Yanked::make(&cx.manager.yanked)?),
 				])?,
 			)?;
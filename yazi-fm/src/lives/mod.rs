@@ -14,6 +14,7 @@ mod selected;
 mod tab;
 mod tabs;
 mod tasks;
+mod which;
 mod yanked;
 
 use config::*;
@@ -30,6 +31,7 @@ use selected::*;
 use tab::*;
 use tabs::*;
 use tasks::*;
+use which::*;
 use yanked::*;
 
 type CtxRef<'lua> = mlua::UserDataRef<'lua, crate::Ctx>;
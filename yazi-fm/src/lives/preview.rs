@@ -24,6 +24,7 @@ impl Preview {
 	pub(super) fn register(lua: &Lua) -> mlua::Result<()> {
 		lua.register_userdata_type::<Self>(|reg| {
 			reg.add_field_method_get("skip", |_, me| Ok(me.skip));
+			reg.add_field_method_get("zoomed", |_, me| Ok(me.zoomed));
 			reg.add_field_method_get("folder", |_, me| {
 				me.tab()
 					.hovered_folder()
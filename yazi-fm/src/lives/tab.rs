@@ -26,6 +26,9 @@ impl Tab {
 			reg.add_method("name", |lua, me, ()| {
 				lua.create_string(me.current.url.name().as_encoded_bytes())
 			});
+			reg.add_field_method_get("title", |lua, me| {
+				me.title.as_ref().map(|s| lua.create_string(s)).transpose()
+			});
 
 			reg.add_field_method_get("mode", |_, me| Mode::make(&me.mode));
 			reg.add_field_method_get("conf", |_, me| Config::make(&me.conf));
@@ -0,0 +1,30 @@
+use std::ops::Deref;
+
+use mlua::{AnyUserData, Lua, UserDataFields};
+
+use super::SCOPE;
+
+pub(super) struct Which {
+	inner: *const yazi_core::which::Which,
+}
+
+impl Deref for Which {
+	type Target = yazi_core::which::Which;
+
+	fn deref(&self) -> &Self::Target { unsafe { &*self.inner } }
+}
+
+impl Which {
+	#[inline]
+	pub(super) fn make(inner: &yazi_core::which::Which) -> mlua::Result<AnyUserData<'static>> {
+		SCOPE.create_any_userdata(Self { inner })
+	}
+
+	pub(super) fn register(lua: &Lua) -> mlua::Result<()> {
+		lua.register_userdata_type::<Self>(|reg| {
+			reg.add_field_method_get("pending", |_, me| Ok(me.pending()))
+		})?;
+
+		Ok(())
+	}
+}
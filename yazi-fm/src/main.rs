@@ -35,8 +35,14 @@ use router::*;
 use signals::*;
 use term::*;
 
-#[tokio::main]
-async fn main() -> anyhow::Result<()> {
+fn main() -> anyhow::Result<()> {
+	// Before the runtime spawns any thread, as `--config-dir` sets the environment
+	yazi_boot::init_args();
+
+	tokio::runtime::Builder::new_multi_thread().enable_all().build()?.block_on(run())
+}
+
+async fn run() -> anyhow::Result<()> {
 	Panic::install();
 	Logs::start()?;
 
@@ -44,9 +50,14 @@ async fn main() -> anyhow::Result<()> {
 
 	yazi_shared::init();
 
+	if !yazi_boot::ARGS.headless {
+		if let Some(light) = yazi_adapter::Emulator::query_background() {
+			yazi_shared::set_light_background(light);
+		}
+	}
 	yazi_config::init()?;
 
-	yazi_adapter::init();
+	if yazi_boot::ARGS.headless { yazi_adapter::init_headless() } else { yazi_adapter::init() }
 
 	yazi_boot::init();
 
@@ -59,5 +70,5 @@ async fn main() -> anyhow::Result<()> {
 	yazi_core::init();
 
 	yazi_dds::serve();
-	app::App::serve().await
+	if yazi_boot::ARGS.headless { app::App::headless().await } else { app::App::serve().await }
 }
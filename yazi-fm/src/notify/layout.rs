@@ -58,8 +58,8 @@ impl<'a> Widget for Layout<'a> {
 					Block::bordered()
 						.border_type(BorderType::Rounded)
 						.title(format!("{} {}", m.level.icon(), m.title))
-						.title_style(*m.level.style())
-						.border_style(*m.level.style()),
+						.title_style(m.level.style())
+						.border_style(m.level.style()),
 				)
 				.render(rect, buf);
 		}
@@ -1,5 +1,5 @@
 use yazi_config::{KEYMAP, keymap::{Chord, Key}};
-use yazi_shared::{Layer, emit};
+use yazi_shared::{Layer, emit, render};
 
 use crate::app::App;
 
@@ -30,7 +30,7 @@ impl<'a> Router<'a> {
 		} else if cx.help.visible {
 			self.matches(Layer::Help, key)
 		} else if cx.input.visible {
-			self.matches(Layer::Input, key)
+			self.count(Layer::Input, key) || self.matches(Layer::Input, key)
 		} else if cx.confirm.visible {
 			self.matches(Layer::Confirm, key)
 		} else if cx.select.visible {
@@ -38,13 +38,13 @@ impl<'a> Router<'a> {
 		} else if cx.tasks.visible {
 			self.matches(Layer::Tasks, key)
 		} else {
-			self.matches(Layer::Manager, key)
+			self.count(Layer::Manager, key) || self.matches(Layer::Manager, key)
 		}
 	}
 
 	#[inline]
 	fn matches(&mut self, layer: Layer, key: Key) -> bool {
-		for ctrl @ Chord { on, .. } in KEYMAP.get(layer) {
+		for ctrl @ Chord { on, .. } in KEYMAP.load().get(layer) {
 			if on.is_empty() || on[0] != key {
 				continue;
 			}
@@ -52,10 +52,20 @@ impl<'a> Router<'a> {
 			if on.len() > 1 {
 				self.app.cx.which.show_with(key, layer);
 			} else {
-				emit!(Seq(ctrl.to_seq(), layer));
+				emit!(Seq(self.app.cx.which.counted(ctrl.to_seq()), layer));
 			}
 			return true;
 		}
+
+		if self.app.cx.which.count.take().is_some() {
+			render!();
+		}
 		false
 	}
+
+	#[inline]
+	fn count(&mut self, layer: Layer, key: Key) -> bool {
+		let bound = KEYMAP.load().get(layer).iter().any(|c| c.on.first() == Some(&key));
+		self.app.cx.which.count(key, bound)
+	}
 }
@@ -13,6 +13,7 @@ impl<'a> Select<'a> {
 
 impl<'a> Widget for Select<'a> {
 	fn render(self, _: Rect, buf: &mut Buffer) {
+		let theme = THEME.load();
 		let select = &self.cx.select;
 		let area = self.cx.manager.area(select.position);
 
@@ -21,11 +22,12 @@ impl<'a> Widget for Select<'a> {
 			.iter()
 			.enumerate()
 			.map(|(i, v)| {
+				let mark = if Some(i) == select.rel_current() { " ✓" } else { "" };
 				if i != select.rel_cursor() {
-					return ListItem::new(format!("  {v}")).style(THEME.select.inactive);
+					return ListItem::new(format!("  {v}{mark}")).style(theme.select.inactive);
 				}
 
-				ListItem::new(format!(" {v}")).style(THEME.select.active)
+				ListItem::new(format!(" {v}{mark}")).style(theme.select.active)
 			})
 			.collect();
 
@@ -35,7 +37,7 @@ impl<'a> Widget for Select<'a> {
 				Block::bordered()
 					.title(select.title())
 					.border_type(BorderType::Rounded)
-					.border_style(THEME.select.border),
+					.border_style(theme.select.border),
 			)
 			.render(area, buf);
 	}
@@ -2,7 +2,9 @@ use anyhow::Result;
 use crossterm::event::{Event as CrosstermEvent, EventStream, KeyEvent, KeyEventKind};
 use futures::StreamExt;
 use tokio::{select, sync::{mpsc, oneshot}};
+use yazi_adapter::{ADAPTOR, TMUX};
 use yazi_config::MANAGER;
+use yazi_proxy::ManagerProxy;
 use yazi_shared::event::Event;
 
 pub(super) struct Signals {
@@ -10,9 +12,9 @@ pub(super) struct Signals {
 }
 
 impl Signals {
-	pub(super) fn start() -> Result<Self> {
+	pub(super) fn start(term: bool) -> Result<Self> {
 		let (tx, rx) = mpsc::unbounded_channel();
-		Self::spawn(rx)?;
+		Self::spawn(rx, term)?;
 
 		Ok(Self { tx })
 	}
@@ -67,11 +69,17 @@ impl Signals {
 			}
 			CrosstermEvent::Paste(str) => Event::Paste(str).emit(),
 			CrosstermEvent::Resize(..) => Event::Resize.emit(),
+			// Images passed through tmux aren't part of the pane, and would stay over what replaces it
+			CrosstermEvent::FocusLost if *TMUX => _ = ADAPTOR.image_hide(),
+			CrosstermEvent::FocusGained if *TMUX => ManagerProxy::peek(true),
 			_ => {}
 		}
 	}
 
-	fn spawn(mut rx: mpsc::UnboundedReceiver<(bool, Option<oneshot::Sender<()>>)>) -> Result<()> {
+	fn spawn(
+		mut rx: mpsc::UnboundedReceiver<(bool, Option<oneshot::Sender<()>>)>,
+		term: bool,
+	) -> Result<()> {
 		#[cfg(unix)]
 		use libc::{SIGCONT, SIGHUP, SIGQUIT, SIGTERM, SIGTSTP};
 
@@ -85,7 +93,7 @@ impl Signals {
 		#[cfg(windows)]
 		let mut sys = tokio_stream::empty();
 
-		let mut term = Some(EventStream::new());
+		let mut term = term.then(EventStream::new);
 
 		tokio::spawn(async move {
 			loop {
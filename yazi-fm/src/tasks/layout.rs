@@ -1,5 +1,5 @@
 use ratatui::{buffer::Buffer, layout::{self, Alignment, Constraint, Rect}, text::Line, widgets::{Block, BorderType, List, ListItem, Padding, Widget}};
-use yazi_config::THEME;
+use yazi_config::{LOCALE, THEME};
 use yazi_core::tasks::TASKS_PERCENT;
 
 use crate::Ctx;
@@ -30,15 +30,16 @@ impl<'a> Layout<'a> {
 
 impl<'a> Widget for Layout<'a> {
 	fn render(self, area: Rect, buf: &mut Buffer) {
+		let theme = THEME.load();
 		let area = Self::area(area);
 
 		yazi_plugin::elements::Clear::default().render(area, buf);
 		let block = Block::bordered()
-			.title(Line::styled("Tasks", THEME.tasks.title))
+			.title(Line::styled(LOCALE.task_title.as_str(), theme.tasks.title))
 			.title_alignment(Alignment::Center)
 			.padding(Padding::symmetric(1, 1))
 			.border_type(BorderType::Rounded)
-			.border_style(THEME.tasks.border);
+			.border_style(theme.tasks.border);
 		block.clone().render(area, buf);
 
 		let tasks = &self.cx.tasks;
@@ -50,7 +51,7 @@ impl<'a> Widget for Layout<'a> {
 			.map(|(i, v)| {
 				let mut item = ListItem::new(v.name.clone());
 				if i == tasks.cursor {
-					item = item.style(THEME.tasks.hovered);
+					item = item.style(theme.tasks.hovered);
 				}
 				item
 			})
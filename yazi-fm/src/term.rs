@@ -1,11 +1,11 @@
 use std::{io::{self, BufWriter, Stderr, stderr}, ops::{Deref, DerefMut}, sync::atomic::{AtomicBool, AtomicU8, Ordering}};
 
 use anyhow::Result;
-use crossterm::{event::{DisableBracketedPaste, EnableBracketedPaste, KeyboardEnhancementFlags, PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags}, execute, queue, style::Print, terminal::{EnterAlternateScreen, LeaveAlternateScreen, SetTitle, disable_raw_mode, enable_raw_mode}};
+use crossterm::{event::{DisableBracketedPaste, DisableFocusChange, EnableBracketedPaste, EnableFocusChange, KeyboardEnhancementFlags, PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags}, execute, queue, style::Print, terminal::{EnterAlternateScreen, LeaveAlternateScreen, SetTitle, disable_raw_mode, enable_raw_mode}};
 use cursor::RestoreCursor;
 use ratatui::{CompletedFrame, Frame, Terminal, backend::CrosstermBackend, buffer::Buffer, layout::Rect};
-use yazi_adapter::{Emulator, tcsi};
-use yazi_config::{INPUT, MANAGER};
+use yazi_adapter::{ADAPTOR, Dimension, Emulator, TMUX, tcsi};
+use yazi_config::{INPUT, MANAGER, TASKS};
 
 static CSI_U: AtomicBool = AtomicBool::new(false);
 static BLINK: AtomicBool = AtomicBool::new(false);
@@ -30,15 +30,22 @@ impl Term {
 			BufWriter::new(stderr()),
 			Print(tcsi("\x1b[?12$p")),      // Request cursor blink status (DECSET)
 			Print(tcsi("\x1bP$q q\x1b\\")), // Request cursor shape (DECRQM)
+			Print(tcsi("\x1b[16t")),        // Request cell size in pixels
 			Print(tcsi("\x1b[?u\x1b[c")),   // Request keyboard enhancement flags (CSI u)
 			EnterAlternateScreen,
 			EnableBracketedPaste,
 			mouse::SetMouse(true),
 		)?;
 
+		// To take the images down when switching to another tmux pane or window
+		if *TMUX {
+			execute!(stderr(), EnableFocusChange)?;
+		}
+
 		let da = futures::executor::block_on(Emulator::read_until_da1());
 		CSI_U.store(da.contains("\x1b[?0u"), Ordering::Relaxed);
 		BLINK.store(da.contains("\x1b[?12;1$y"), Ordering::Relaxed);
+		Dimension::set_cell_size(&da);
 		SHAPE.store(
 			da.split_once("\x1bP1$r")
 				.and_then(|(_, s)| s.bytes().next())
@@ -67,6 +74,9 @@ impl Term {
 		if CSI_U.swap(false, Ordering::Relaxed) {
 			execute!(stderr(), PopKeyboardEnhancementFlags)?;
 		}
+		if *TMUX {
+			execute!(stderr(), DisableFocusChange)?;
+		}
 
 		execute!(
 			stderr(),
@@ -81,13 +91,24 @@ impl Term {
 	}
 
 	pub(super) fn goodbye(f: impl FnOnce() -> bool) -> ! {
+		// Images aren't part of the alternate screen, so they'd be left behind otherwise
+		if ADAPTOR.initialized() {
+			ADAPTOR.image_hide().ok();
+		}
+
 		if CSI_U.swap(false, Ordering::Relaxed) {
 			execute!(stderr(), PopKeyboardEnhancementFlags).ok();
 		}
+		if TMUX.initialized() && *TMUX {
+			execute!(stderr(), DisableFocusChange).ok();
+		}
 
 		if !MANAGER.title_format.is_empty() {
 			execute!(stderr(), SetTitle("")).ok();
 		}
+		if TASKS.progress_osc {
+			execute!(stderr(), Print(tcsi("\x1b]9;4;0;0\x1b\\"))).ok();
+		}
 
 		execute!(
 			stderr(),
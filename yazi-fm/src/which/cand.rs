@@ -16,6 +16,7 @@ impl<'a> Cand<'a> {
 
 impl Widget for Cand<'_> {
 	fn render(self, area: Rect, buf: &mut Buffer) {
+		let theme = THEME.load();
 		let keys = self.keys();
 		let mut spans = Vec::with_capacity(10);
 
@@ -23,16 +24,16 @@ impl Widget for Cand<'_> {
 		spans.push(Span::raw(" ".repeat(10usize.saturating_sub(keys.join("").len()))));
 
 		// First key
-		spans.push(Span::styled(keys[0].clone(), THEME.which.cand));
+		spans.push(Span::styled(keys[0].clone(), theme.which.cand));
 
 		// Rest keys
-		spans.extend(keys.iter().skip(1).map(|k| Span::styled(k, THEME.which.rest)));
+		spans.extend(keys.iter().skip(1).map(|k| Span::styled(k, theme.which.rest)));
 
 		// Separator
-		spans.push(Span::styled(&THEME.which.separator, THEME.which.separator_style));
+		spans.push(Span::styled(&theme.which.separator, theme.which.separator_style));
 
 		// Description
-		spans.push(Span::styled(self.cand.desc_or_run(), THEME.which.desc));
+		spans.push(Span::styled(self.cand.desc_or_run(), theme.which.desc));
 
 		Line::from(spans).render(area, buf);
 	}
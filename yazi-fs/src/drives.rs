@@ -0,0 +1,30 @@
+use std::path::PathBuf;
+
+use tokio::{fs, process::Command};
+
+// The drive letters in use, followed by the network shares that aren't mapped to any of them
+pub async fn drives() -> Vec<PathBuf> {
+	let mut drives = vec![];
+	for b in b'A'..=b'Z' {
+		let p = PathBuf::from(format!("{}:\\", b as char));
+		if fs::metadata(&p).await.is_ok() {
+			drives.push(p);
+		}
+	}
+
+	let Ok(output) = Command::new("net").arg("use").kill_on_drop(true).output().await else {
+		return drives;
+	};
+
+	// e.g. "OK           Z:        \\server\share        Microsoft Windows Network"
+	for line in String::from_utf8_lossy(&output.stdout).lines() {
+		let tokens: Vec<_> = line.split_whitespace().collect();
+		if tokens.iter().any(|t| t.len() == 2 && t.ends_with(':')) {
+			continue;
+		}
+		if let Some(share) = tokens.iter().find(|t| t.starts_with(r"\\")) {
+			drives.push(PathBuf::from(share));
+		}
+	}
+	drives
+}
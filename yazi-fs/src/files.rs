@@ -1,4 +1,4 @@
-use std::{collections::{HashMap, HashSet}, mem, ops::Deref, sync::atomic::Ordering};
+use std::{collections::{HashMap, HashSet, hash_map::DefaultHasher}, ffi::OsStr, hash::{Hash, Hasher}, mem, ops::Deref, sync::{OnceLock, atomic::Ordering}};
 
 use tokio::{fs::{self, DirEntry}, select, sync::mpsc::{self, UnboundedReceiver}};
 use yazi_config::{MANAGER, manager::SortBy};
@@ -14,6 +14,8 @@ pub struct Files {
 	pub revision: u64,
 
 	pub sizes: HashMap<UrnBuf, u64>,
+	// Hash of the urn to its position in `items`, built on the first lookup
+	index:     OnceLock<HashMap<u64, usize>>,
 
 	sorter:      FilesSorter,
 	filter:      Option<Filter>,
@@ -30,6 +32,7 @@ impl Default for Files {
 			revision: Default::default(),
 
 			sizes: Default::default(),
+			index: Default::default(),
 
 			sorter:      Default::default(),
 			filter:      Default::default(),
@@ -118,6 +121,7 @@ impl Files {
 
 impl Files {
 	pub fn update_full(&mut self, files: Vec<File>) {
+		self.index.take();
 		self.ticket = FILES_TICKET.fetch_add(1, Ordering::Relaxed);
 
 		(self.hidden, self.items) = self.split_files(files);
@@ -127,6 +131,7 @@ impl Files {
 	}
 
 	pub fn update_part(&mut self, files: Vec<File>, ticket: u64) {
+		self.index.take();
 		if !files.is_empty() {
 			if ticket != self.ticket {
 				return;
@@ -162,6 +167,7 @@ impl Files {
 	}
 
 	pub fn update_ioerr(&mut self) {
+		self.index.take();
 		self.ticket = FILES_TICKET.fetch_add(1, Ordering::Relaxed);
 		self.hidden.clear();
 		self.items.clear();
@@ -182,6 +188,7 @@ impl Files {
 				}
 				if !todo.is_empty() {
 					self.revision += $inc;
+					self.index.take();
 					$dist.extend(todo.into_values());
 				}
 			};
@@ -208,20 +215,18 @@ impl Files {
 				$dist.retain(|f| !$src.remove(f.urn()));
 				if $dist.len() != len {
 					self.revision += $inc;
+					self.index.take();
 				}
 			};
 		}
 
-		let (mut hidden, mut items) = if let Some(filter) = &self.filter {
-			urns.into_iter().partition(|u| {
-				(!self.show_hidden && u.as_urn().is_hidden())
-					|| !u.as_urn().name().is_some_and(|s| filter.matches(s))
-			})
-		} else if self.show_hidden {
-			(HashSet::new(), urns)
-		} else {
-			urns.into_iter().partition(|u| u.as_urn().is_hidden())
-		};
+		let (mut hidden, mut items): (HashSet<_>, HashSet<_>) = urns.into_iter().partition(|u| {
+			let urn = u.as_urn();
+			match urn.name() {
+				Some(s) => self.excluded(urn.is_hidden(), s),
+				None => true,
+			}
+		});
 
 		if !items.is_empty() {
 			go!(self.items, items, 1);
@@ -239,6 +244,7 @@ impl Files {
 				$dist.retain(|f| !$src.remove(f.urn()));
 				if $dist.len() != len {
 					self.revision += $inc;
+					self.index.take();
 				}
 			};
 		}
@@ -273,19 +279,15 @@ impl Files {
 						}
 					}
 				}
-				self.revision += if b { 0 } else { $inc };
+				if !b {
+					self.revision += $inc;
+					self.index.take();
+				}
 			};
 		}
 
-		let (mut hidden, mut items) = if let Some(filter) = &self.filter {
-			files
-				.into_iter()
-				.partition(|(_, f)| (f.is_hidden() && !self.show_hidden) || !filter.matches(f.name()))
-		} else if self.show_hidden {
-			(HashMap::new(), files)
-		} else {
-			files.into_iter().partition(|(_, f)| f.is_hidden())
-		};
+		let (mut hidden, mut items): (HashMap<_, _>, HashMap<_, _>) =
+			files.into_iter().partition(|(_, f)| self.excluded(f.is_hidden(), f.name()));
 
 		if !items.is_empty() {
 			go!(self.items, items, 1);
@@ -311,6 +313,7 @@ impl Files {
 		}
 		if !items.is_empty() {
 			self.revision += 1;
+			self.index.take();
 			self.items.extend(items.into_values());
 		}
 	}
@@ -321,27 +324,51 @@ impl Files {
 		}
 
 		self.version = self.revision;
+		self.index.take();
 		self.sorter.sort(&mut self.items, &self.sizes);
 		true
 	}
 
 	fn split_files(&self, files: impl IntoIterator<Item = File>) -> (Vec<File>, Vec<File>) {
-		if let Some(filter) = &self.filter {
-			files
-				.into_iter()
-				.partition(|f| (f.is_hidden() && !self.show_hidden) || !filter.matches(f.name()))
-		} else if self.show_hidden {
-			(vec![], files.into_iter().collect())
-		} else {
-			files.into_iter().partition(|f| f.is_hidden())
-		}
+		files.into_iter().partition(|f| self.excluded(f.is_hidden(), f.name()))
+	}
+
+	// Whether a file goes to `hidden`: a dotfile while they're hidden,
+	// one matching the `hide` rules, or one the filter rejects
+	#[inline]
+	fn excluded(&self, dotfile: bool, name: &OsStr) -> bool {
+		(dotfile && !self.show_hidden)
+			|| MANAGER.hide.matches(name)
+			|| self.filter.as_ref().is_some_and(|f| !f.matches(name))
 	}
 }
 
 impl Files {
 	// --- Items
+	pub fn position(&self, urn: &Urn) -> Option<usize> {
+		let index = self.index.get_or_init(|| {
+			self.items.iter().enumerate().map(|(i, f)| (Self::hash(f.urn()), i)).collect()
+		});
+
+		// On a hash collision, the other file took the slot
+		let i = *index.get(&Self::hash(urn))?;
+		if self.items.get(i).is_some_and(|f| urn == f.urn()) {
+			Some(i)
+		} else {
+			self.iter().position(|f| urn == f.urn())
+		}
+	}
+
+	#[inline]
+	fn hash(urn: &Urn) -> u64 {
+		let mut h = DefaultHasher::new();
+		urn.hash(&mut h);
+		h.finish()
+	}
+
+	// Including the hidden and filtered-out ones
 	#[inline]
-	pub fn position(&self, urn: &Urn) -> Option<usize> { self.iter().position(|f| urn == f.urn()) }
+	pub fn all(&self) -> impl Iterator<Item = &File> { self.items.iter().chain(&self.hidden) }
 
 	// --- Ticket
 	#[inline]
@@ -368,6 +395,7 @@ impl Files {
 		}
 
 		self.filter = filter;
+		self.index.take();
 		if self.filter.is_none() {
 			let take = mem::take(&mut self.hidden);
 			let (hidden, items) = self.split_files(take);
@@ -406,6 +434,7 @@ impl Files {
 		self.hidden.extend(hidden);
 		if !items.is_empty() {
 			self.revision += 1;
+			self.index.take();
 			self.items.extend(items);
 		}
 	}
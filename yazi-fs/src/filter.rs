@@ -1,23 +1,25 @@
-use std::{ffi::OsStr, fmt::Display, ops::Range};
+use std::{borrow::Cow, ffi::OsStr, fmt::Display, ops::Range};
 
 use anyhow::Result;
 use regex::bytes::{Regex, RegexBuilder};
 use yazi_shared::event::Cmd;
 
+#[derive(Clone)]
 pub struct Filter {
 	raw:   String,
 	regex: Regex,
 }
 
 impl Filter {
-	pub fn new(s: &str, case: FilterCase) -> Result<Self> {
+	pub fn new(s: &str, case: FilterCase, literal: bool) -> Result<Self> {
+		let pat = if literal { Cow::Owned(regex::escape(s)) } else { Cow::Borrowed(s) };
 		let regex = match case {
 			FilterCase::Smart => {
 				let uppercase = s.chars().any(|c| c.is_uppercase());
-				RegexBuilder::new(s).case_insensitive(!uppercase).build()?
+				RegexBuilder::new(&pat).case_insensitive(!uppercase).build()?
 			}
-			FilterCase::Sensitive => Regex::new(s)?,
-			FilterCase::Insensitive => RegexBuilder::new(s).case_insensitive(true).build()?,
+			FilterCase::Sensitive => Regex::new(&pat)?,
+			FilterCase::Insensitive => RegexBuilder::new(&pat).case_insensitive(true).build()?,
 		};
 		Ok(Self { raw: s.to_owned(), regex })
 	}
@@ -32,27 +34,48 @@ impl Filter {
 }
 
 impl PartialEq for Filter {
-	fn eq(&self, other: &Self) -> bool { self.raw == other.raw }
+	fn eq(&self, other: &Self) -> bool {
+		self.raw == other.raw && self.regex.as_str() == other.regex.as_str()
+	}
 }
 
 impl Display for Filter {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result { f.write_str(&self.raw) }
 }
 
-#[derive(Default, PartialEq, Eq)]
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
 pub enum FilterCase {
-	Smart,
 	#[default]
+	Smart,
 	Sensitive,
 	Insensitive,
 }
 
 impl From<&Cmd> for FilterCase {
 	fn from(c: &Cmd) -> Self {
-		match (c.bool("smart"), c.bool("insensitive")) {
-			(true, _) => Self::Smart,
-			(_, false) => Self::Sensitive,
+		match (c.bool("sensitive"), c.bool("insensitive")) {
+			(true, _) => Self::Sensitive,
 			(_, true) => Self::Insensitive,
+			_ => Self::Smart,
 		}
 	}
 }
+
+impl FilterCase {
+	// Shown in the input title, e.g. "smart case, regex"
+	pub fn describe(self, literal: bool) -> String {
+		let case = match self {
+			Self::Smart => "smart case",
+			Self::Sensitive => "case-sensitive",
+			Self::Insensitive => "case-insensitive",
+		};
+		format!("{case}, {}", if literal { "literal" } else { "regex" })
+	}
+
+	#[inline]
+	pub fn apply(self, cmd: Cmd) -> Cmd {
+		cmd
+			.with_bool("sensitive", self == Self::Sensitive)
+			.with_bool("insensitive", self == Self::Insensitive)
+	}
+}
@@ -1,6 +1,6 @@
 use std::mem;
 
-use yazi_config::{LAYOUT, MANAGER};
+use yazi_config::{LAYOUT, MANAGER, TASKS};
 use yazi_proxy::ManagerProxy;
 use yazi_shared::fs::{Cha, File, FilesOp, Url, Urn};
 
@@ -160,12 +160,19 @@ impl Folder {
 	#[inline]
 	pub fn hovered(&self) -> Option<&File> { self.files.get(self.cursor) }
 
+	pub fn window(&self) -> &[File] {
+		let limit = LAYOUT.load().current.height as usize;
+		&self.files[self.offset.min(self.files.len())..self.files.len().min(self.offset + limit)]
+	}
+
 	pub fn paginate(&self, page: usize) -> &[File] {
 		let len = self.files.len();
 		let limit = LAYOUT.load().current.height as usize;
 
-		let start = (page.saturating_sub(1) * limit).min(len.saturating_sub(1));
-		let end = ((page + 2) * limit).min(len);
+		let around = TASKS.precache_pages as usize;
+
+		let start = (page.saturating_sub(around) * limit).min(len.saturating_sub(1));
+		let end = ((page + 1 + around) * limit).min(len);
 		&self.files[start..end]
 	}
 }
@@ -1,15 +1,21 @@
 #![allow(clippy::if_same_then_else)]
 
+#[cfg(windows)]
+mod drives;
 mod files;
 mod filter;
 mod folder;
+mod remote;
 mod sorter;
 mod stage;
 mod step;
 
+#[cfg(windows)]
+pub use drives::*;
 pub use files::*;
 pub use filter::*;
 pub use folder::*;
+pub use remote::*;
 pub use sorter::*;
 pub use stage::*;
 pub use step::*;
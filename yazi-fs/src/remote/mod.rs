@@ -0,0 +1,11 @@
+#![allow(clippy::module_inception)]
+
+mod mtp;
+mod remote;
+mod s3;
+mod sftp;
+
+pub use mtp::*;
+pub use remote::*;
+pub use s3::*;
+pub use sftp::*;
@@ -0,0 +1,68 @@
+use std::{path::{Path, PathBuf}, process::Stdio};
+
+use anyhow::{Result, bail};
+use tokio::process::Command;
+
+// `mtp://[bus,dev]/path`, mounted with `jmtpfs`, the first device is used if not specified
+pub struct Mtp {
+	device: Option<String>,
+
+	pub(super) path: PathBuf,
+}
+
+impl Mtp {
+	pub(super) fn parse(s: &str) -> Option<Self> {
+		let s = s.strip_prefix("mtp://")?;
+		let (device, path) = s.split_once('/').unwrap_or((s, ""));
+
+		let device = match device.split_once(',') {
+			_ if device.is_empty() => None,
+			Some((b, d)) if b.parse::<u32>().is_ok() && d.parse::<u32>().is_ok() => {
+				Some(device.to_owned())
+			}
+			_ => return None,
+		};
+
+		Some(Self { device, path: PathBuf::from(format!("/{path}")) })
+	}
+
+	pub(super) fn name(&self) -> String {
+		self.device.clone().unwrap_or_else(|| "default".to_owned())
+	}
+
+	pub(super) async fn mount(&self, target: &Path) -> Result<()> {
+		let mut cmd = Command::new("jmtpfs");
+		if let Some(d) = &self.device {
+			cmd.arg(format!("-device={d}"));
+		}
+
+		let output = cmd.arg(target).stdin(Stdio::null()).kill_on_drop(true).output().await?;
+		if !output.status.success() {
+			bail!("{}", String::from_utf8_lossy(&output.stderr).trim());
+		}
+		Ok(())
+	}
+
+	// The connected devices, as pairs of their description and URL
+	pub async fn devices() -> Result<Vec<(String, String)>> {
+		let output = Command::new("jmtpfs").arg("-l").kill_on_drop(true).output().await?;
+		if !output.status.success() {
+			bail!("{}", String::from_utf8_lossy(&output.stderr).trim());
+		}
+
+		// busLocation, devNum, productId, vendorId, product, vendor
+		let stdout = String::from_utf8_lossy(&output.stdout);
+		Ok(
+			stdout
+				.lines()
+				.skip_while(|l| !l.starts_with("Available devices"))
+				.skip(1)
+				.filter_map(|l| {
+					let parts: Vec<_> = l.splitn(6, ',').map(str::trim).collect();
+					let [bus, dev, _, _, product, vendor] = parts[..] else { return None };
+					Some((format!("{vendor} {product}"), format!("mtp://{bus},{dev}/")))
+				})
+				.collect(),
+		)
+	}
+}
@@ -0,0 +1,141 @@
+use std::{env, path::{Path, PathBuf}, process::Command};
+
+use anyhow::Result;
+use parking_lot::Mutex;
+use tokio::fs;
+use yazi_shared::{Xdg, process_alive};
+
+use super::{Mtp, S3, Sftp};
+
+static MOUNTED: Mutex<Vec<PathBuf>> = Mutex::new(Vec::new());
+
+// Held across the check and the mount, so the same remote is never mounted twice
+static MOUNTING: tokio::sync::Mutex<bool> = tokio::sync::Mutex::const_new(false);
+
+// Remote filesystems are mounted locally through FUSE by an external program, so that
+// listing, previewing, and copying via the task scheduler all work on them unchanged
+pub enum Remote {
+	Sftp(Sftp),
+	Mtp(Mtp),
+	S3(S3),
+}
+
+impl Remote {
+	pub fn parse(s: &str) -> Option<Self> {
+		Sftp::parse(s)
+			.map(Self::Sftp)
+			.or_else(|| Mtp::parse(s).map(Self::Mtp))
+			.or_else(|| S3::parse(s).map(Self::S3))
+	}
+
+	// Mount it if it hasn't been yet, and return the local path the URL points to.
+	// Each instance mounts under its own directory, so quitting one of them
+	// never pulls a mount out from under another
+	pub async fn mount(&self) -> Result<PathBuf> {
+		let root = Self::dir().join(self.scheme()).join(self.name());
+
+		let mut swept = MOUNTING.lock().await;
+		if !std::mem::replace(&mut *swept, true) {
+			tokio::task::spawn_blocking(Self::sweep).await.ok();
+		}
+
+		if !MOUNTED.lock().contains(&root) {
+			fs::create_dir_all(&root).await?;
+			match self {
+				Self::Sftp(s) => s.mount(&root).await?,
+				Self::Mtp(m) => m.mount(&root).await?,
+				Self::S3(s) => s.mount(&root).await?,
+			}
+			MOUNTED.lock().push(root.clone());
+		}
+		drop(swept);
+
+		let path = self.path();
+		Ok(root.join(path.strip_prefix("/").unwrap_or(path)))
+	}
+
+	pub fn unmount_all() {
+		let mounted = std::mem::take(&mut *MOUNTED.lock());
+		if mounted.is_empty() {
+			return;
+		}
+
+		// Step out of them, otherwise they're busy
+		env::set_current_dir(Xdg::state_dir()).ok();
+		for root in mounted {
+			Self::unmount(&root);
+		}
+		Self::remove_empty(&Self::dir());
+	}
+
+	// Clean up after the instances that died without unmounting their remotes
+	fn sweep() {
+		let Ok(it) = std::fs::read_dir(Xdg::state_dir().join("mounts")) else { return };
+		for entry in it.flatten() {
+			let dir = entry.path();
+			let Some(pid) = dir.file_name().and_then(|s| s.to_str()?.parse().ok()) else { continue };
+			if pid == std::process::id() || process_alive(pid) {
+				continue;
+			}
+
+			for scheme in std::fs::read_dir(&dir).into_iter().flatten().flatten() {
+				for root in std::fs::read_dir(scheme.path()).into_iter().flatten().flatten() {
+					Self::unmount(&root.path());
+				}
+			}
+			Self::remove_empty(&dir);
+		}
+	}
+
+	fn unmount(root: &Path) {
+		let ok =
+			Command::new("fusermount").arg("-u").arg(root).status().is_ok_and(|s| s.success());
+		if !ok {
+			Command::new("umount").arg(root).status().ok();
+		}
+	}
+
+	// Only ever remove empty directories, a directory that is still mounted
+	// would otherwise have the remote files deleted
+	fn remove_empty(dir: &Path) {
+		for scheme in std::fs::read_dir(dir).into_iter().flatten().flatten() {
+			for root in std::fs::read_dir(scheme.path()).into_iter().flatten().flatten() {
+				std::fs::remove_dir(root.path()).ok();
+			}
+			std::fs::remove_dir(scheme.path()).ok();
+		}
+		std::fs::remove_dir(dir).ok();
+	}
+
+	#[inline]
+	fn dir() -> PathBuf {
+		Xdg::state_dir().join("mounts").join(std::process::id().to_string())
+	}
+
+	#[inline]
+	fn scheme(&self) -> &'static str {
+		match self {
+			Self::Sftp(_) => "sftp",
+			Self::Mtp(_) => "mtp",
+			Self::S3(_) => "s3",
+		}
+	}
+
+	#[inline]
+	fn name(&self) -> String {
+		match self {
+			Self::Sftp(s) => s.name(),
+			Self::Mtp(m) => m.name(),
+			Self::S3(s) => s.name(),
+		}
+	}
+
+	#[inline]
+	fn path(&self) -> &PathBuf {
+		match self {
+			Self::Sftp(s) => &s.path,
+			Self::Mtp(m) => &m.path,
+			Self::S3(s) => &s.path,
+		}
+	}
+}
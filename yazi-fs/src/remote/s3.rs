@@ -0,0 +1,45 @@
+use std::{path::{Path, PathBuf}, process::Stdio};
+
+use anyhow::{Result, bail};
+use tokio::process::Command;
+use yazi_config::REMOTE;
+
+// `s3://bucket/prefix`, the buckets are listed as directories at `s3://`, mounted with `rclone`
+pub struct S3 {
+	pub(super) path: PathBuf,
+}
+
+impl S3 {
+	pub(super) fn parse(s: &str) -> Option<Self> {
+		let path = s.strip_prefix("s3://")?;
+		Some(Self { path: PathBuf::from(format!("/{path}")) })
+	}
+
+	pub(super) fn name(&self) -> String { "default".to_owned() }
+
+	pub(super) async fn mount(&self, target: &Path) -> Result<()> {
+		let mut cmd = Command::new("rclone");
+		cmd.args(["mount", ":s3:", "--daemon", "--vfs-cache-mode", "writes"]).arg(target);
+
+		// Passed through the environment to keep them out of the process list
+		if !REMOTE.s3_endpoint.is_empty() {
+			cmd.env("RCLONE_S3_PROVIDER", "Other").env("RCLONE_S3_ENDPOINT", &REMOTE.s3_endpoint);
+		}
+		if !REMOTE.s3_region.is_empty() {
+			cmd.env("RCLONE_S3_REGION", &REMOTE.s3_region);
+		}
+		if REMOTE.s3_access_key.is_empty() {
+			cmd.env("RCLONE_S3_ENV_AUTH", "true");
+		} else {
+			cmd
+				.env("RCLONE_S3_ACCESS_KEY_ID", &REMOTE.s3_access_key)
+				.env("RCLONE_S3_SECRET_ACCESS_KEY", &REMOTE.s3_secret_key);
+		}
+
+		let output = cmd.stdin(Stdio::null()).kill_on_drop(true).output().await?;
+		if !output.status.success() {
+			bail!("{}", String::from_utf8_lossy(&output.stderr).trim());
+		}
+		Ok(())
+	}
+}
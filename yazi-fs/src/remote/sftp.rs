@@ -0,0 +1,63 @@
+use std::{path::{Path, PathBuf}, process::Stdio};
+
+use anyhow::{Result, bail};
+use tokio::process::Command;
+
+// `sftp://[user@]host[:port]/path`, mounted with `sshfs`
+pub struct Sftp {
+	user: Option<String>,
+	host: String,
+	port: Option<u16>,
+
+	pub(super) path: PathBuf,
+}
+
+impl Sftp {
+	pub(super) fn parse(s: &str) -> Option<Self> {
+		let s = s.strip_prefix("sftp://")?;
+		let (authority, path) = s.split_once('/').unwrap_or((s, ""));
+
+		let (user, host) = match authority.rsplit_once('@') {
+			Some((u, h)) => (Some(u.to_owned()), h),
+			None => (None, authority),
+		};
+		let (host, port) = match host.rsplit_once(':') {
+			Some((h, p)) => (h, Some(p.parse().ok()?)),
+			None => (host, None),
+		};
+
+		if host.is_empty() {
+			return None;
+		}
+		Some(Self { user, host: host.to_owned(), port, path: PathBuf::from(format!("/{path}")) })
+	}
+
+	pub(super) fn name(&self) -> String {
+		let mut s = self.user.as_ref().map(|u| format!("{u}@")).unwrap_or_default();
+		s.push_str(&self.host);
+		if let Some(p) = self.port {
+			s.push_str(&format!(":{p}"));
+		}
+		s
+	}
+
+	// There's no terminal to type a password into, so only key-based authentication works
+	pub(super) async fn mount(&self, target: &Path) -> Result<()> {
+		let source = match &self.user {
+			Some(u) => format!("{u}@{}:/", self.host),
+			None => format!("{}:/", self.host),
+		};
+
+		let mut cmd = Command::new("sshfs");
+		cmd.arg(source).arg(target).args(["-o", "reconnect", "-o", "BatchMode=yes"]);
+		if let Some(p) = self.port {
+			cmd.args(["-p", &p.to_string()]);
+		}
+
+		let output = cmd.stdin(Stdio::null()).kill_on_drop(true).output().await?;
+		if !output.status.success() {
+			bail!("{}", String::from_utf8_lossy(&output.stderr).trim());
+		}
+		Ok(())
+	}
+}
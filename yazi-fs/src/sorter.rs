@@ -1,4 +1,4 @@
-use std::{cmp::Ordering, collections::HashMap, mem};
+use std::{borrow::Cow, cmp::Ordering, collections::HashMap, mem};
 
 use yazi_config::manager::SortBy;
 use yazi_shared::{LcgRng, Transliterator, fs::{File, UrnBuf}, natsort};
@@ -14,79 +14,98 @@ pub struct FilesSorter {
 
 impl FilesSorter {
 	pub(super) fn sort(&self, items: &mut Vec<File>, sizes: &HashMap<UrnBuf, u64>) {
-		if items.is_empty() {
+		if items.is_empty() || self.by == SortBy::None {
 			return;
 		}
 
-		let by_alphabetical = |a: &File, b: &File| {
-			if self.sensitive {
-				self.cmp(a.name(), b.name(), self.promote(a, b))
+		// Keys are computed once per entry rather than on every comparison
+		let names = self.names(items);
+		let by_name = |a: usize, b: usize| {
+			let (a, b) = if self.reverse { (b, a) } else { (a, b) };
+			if self.by == SortBy::Natural {
+				natsort(&names[a], &names[b], !self.sensitive)
 			} else {
-				self.cmp(a.name().to_ascii_uppercase(), b.name().to_ascii_uppercase(), self.promote(a, b))
+				names[a].cmp(&names[b])
 			}
 		};
+		let then_name = |ord: Ordering, a: usize, b: usize| {
+			if ord == Ordering::Equal { by_name(a, b) } else { ord }
+		};
 
+		// A stable sort, which is close to linear when only a few entries changed
+		let mut indices: Vec<usize> = (0..items.len()).collect();
 		match self.by {
 			SortBy::None => {}
-			SortBy::Modified => items.sort_unstable_by(|a, b| {
-				let ord = self.cmp(a.mtime, b.mtime, self.promote(a, b));
-				if ord == Ordering::Equal { by_alphabetical(a, b) } else { ord }
-			}),
-			SortBy::Created => items.sort_unstable_by(|a, b| {
-				let ord = self.cmp(a.ctime, b.ctime, self.promote(a, b));
-				if ord == Ordering::Equal { by_alphabetical(a, b) } else { ord }
+			SortBy::Modified => indices.sort_by(|&a, &b| {
+				let (fa, fb) = (&items[a], &items[b]);
+				then_name(self.cmp(fa.mtime, fb.mtime, self.promote(fa, fb)), a, b)
 			}),
-			SortBy::Extension => items.sort_unstable_by(|a, b| {
-				let ord = if self.sensitive {
-					self.cmp(a.url.extension(), b.url.extension(), self.promote(a, b))
-				} else {
-					self.cmp(
-						a.url.extension().map(|s| s.to_ascii_lowercase()),
-						b.url.extension().map(|s| s.to_ascii_lowercase()),
-						self.promote(a, b),
-					)
-				};
-				if ord == Ordering::Equal { by_alphabetical(a, b) } else { ord }
-			}),
-			SortBy::Alphabetical => items.sort_unstable_by(by_alphabetical),
-			SortBy::Natural => self.sort_naturally(items),
-			SortBy::Size => items.sort_unstable_by(|a, b| {
-				let aa = if a.is_dir() { sizes.get(a.urn()).copied() } else { None };
-				let bb = if b.is_dir() { sizes.get(b.urn()).copied() } else { None };
-				let ord = self.cmp(aa.unwrap_or(a.len), bb.unwrap_or(b.len), self.promote(a, b));
-				if ord == Ordering::Equal { by_alphabetical(a, b) } else { ord }
+			SortBy::Created => indices.sort_by(|&a, &b| {
+				let (fa, fb) = (&items[a], &items[b]);
+				then_name(self.cmp(fa.ctime, fb.ctime, self.promote(fa, fb)), a, b)
 			}),
+			SortBy::Extension => {
+				let exts: Vec<_> = items
+					.iter()
+					.map(|f| {
+						let b = f.url.extension()?.as_encoded_bytes();
+						Some(if self.sensitive { Cow::Borrowed(b) } else { Cow::Owned(b.to_ascii_lowercase()) })
+					})
+					.collect();
+				indices.sort_by(|&a, &b| {
+					let promote = self.promote(&items[a], &items[b]);
+					then_name(self.cmp(&exts[a], &exts[b], promote), a, b)
+				})
+			}
+			SortBy::Alphabetical | SortBy::Natural => {
+				indices.sort_by(|&a, &b| then_name(self.promote(&items[a], &items[b]), a, b))
+			}
+			SortBy::Size => {
+				let lens: Vec<_> = items
+					.iter()
+					.map(|f| if f.is_dir() { sizes.get(f.urn()).copied() } else { None }.unwrap_or(f.len))
+					.collect();
+				indices.sort_by(|&a, &b| {
+					let promote = self.promote(&items[a], &items[b]);
+					then_name(self.cmp(lens[a], lens[b], promote), a, b)
+				})
+			}
 			SortBy::Random => {
 				let mut rng = LcgRng::default();
-				items.sort_unstable_by(|a, b| self.cmp(rng.next(), rng.next(), self.promote(a, b)))
+				indices.sort_unstable_by(|&a, &b| {
+					self.cmp(rng.next(), rng.next(), self.promote(&items[a], &items[b]))
+				})
 			}
 		}
-	}
-
-	fn sort_naturally(&self, items: &mut Vec<File>) {
-		let mut indices: Vec<usize> = (0..items.len()).collect();
-		indices.sort_unstable_by(|&a, &b| {
-			let (a, b) = (&items[a], &items[b]);
 
-			let promote = self.promote(a, b);
-			if promote != Ordering::Equal {
-				return promote;
-			}
-
-			let ordering = if self.translit {
-				natsort(
-					a.name().as_encoded_bytes().transliterate().as_bytes(),
-					b.name().as_encoded_bytes().transliterate().as_bytes(),
-					!self.sensitive,
-				)
-			} else {
-				natsort(a.name().as_encoded_bytes(), b.name().as_encoded_bytes(), !self.sensitive)
-			};
+		*items = indices.into_iter().map(|i| mem::take(&mut items[i])).collect();
+	}
 
-			if self.reverse { ordering.reverse() } else { ordering }
-		});
+	fn names<'a>(&self, items: &'a [File]) -> Vec<Cow<'a, [u8]>> {
+		items
+			.iter()
+			.map(|f| {
+				let b = f.name().as_encoded_bytes();
+				match self.by {
+					SortBy::Natural if self.translit => match b.transliterate() {
+						Cow::Borrowed(_) => Cow::Borrowed(b),
+						Cow::Owned(s) => Cow::Owned(s.into_bytes()),
+					},
+					// Natural sorting folds the case by itself
+					SortBy::Natural => Cow::Borrowed(b),
+					_ => self.fold(b),
+				}
+			})
+			.collect()
+	}
 
-		*items = indices.into_iter().map(|i| mem::take(&mut items[i])).collect();
+	#[inline]
+	fn fold<'a>(&self, b: &'a [u8]) -> Cow<'a, [u8]> {
+		if self.sensitive || !b.iter().any(u8::is_ascii_lowercase) {
+			Cow::Borrowed(b)
+		} else {
+			Cow::Owned(b.to_ascii_uppercase())
+		}
 	}
 
 	#[inline(always)]
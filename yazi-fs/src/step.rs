@@ -3,7 +3,7 @@ use std::{num::ParseIntError, str::FromStr};
 #[derive(Clone, Copy)]
 pub enum Step {
 	Fixed(isize),
-	Percent(i8),
+	Percent(i16),
 }
 
 impl Default for Step {
@@ -32,6 +32,16 @@ impl Step {
 
 	#[inline]
 	pub fn next(n: usize) -> Self { Self::Fixed(n as isize) }
+
+	// Repeat the step `n` times, for the count typed before its key
+	#[inline]
+	pub fn times(self, n: usize) -> Self {
+		let n = n.min(i16::MAX as usize);
+		match self {
+			Self::Fixed(s) => Self::Fixed(s.saturating_mul(n as isize)),
+			Self::Percent(p) => Self::Percent(p.saturating_mul(n as i16)),
+		}
+	}
 }
 
 impl Step {
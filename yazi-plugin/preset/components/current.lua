@@ -13,9 +13,9 @@ end
 function Current:empty()
 	local line
 	if self._folder.files.filter then
-		line = ui.Line("No filter results")
+		line = ui.Line(LOCALE.folder_no_results)
 	else
-		line = ui.Line(self._folder.stage.is_loading and "Loading..." or "No items")
+		line = ui.Line(self._folder.stage.is_loading and LOCALE.folder_loading or LOCALE.folder_empty)
 	end
 
 	return {
@@ -37,15 +37,33 @@ function Current:render()
 		entities[#entities + 1] = ui.ListItem(entity:render()):style(entity:style())
 	end
 
-	return {
+	return ya.list_merge({
 		ui.List(self._area, entities),
 		ui.Paragraph(self._area, linemodes):align(ui.Paragraph.RIGHT),
+	}, self:scrollbar())
+end
+
+function Current:scrollbar()
+	local total, h = #self._folder.files, self._area.h
+	if THEME.manager.scrollbar_symbol == "" or total <= h or h == 0 then
+		return {}
+	end
+
+	local size = math.max(1, h * h // total)
+	local y = math.floor(self._folder.offset * (h - size) / (total - h) + 0.5)
+	return {
+		ui.Bar(ui.Rect { x = self._area.x, y = self._area.y + y, w = self._area.w, h = size }, ui.Bar.RIGHT)
+			:symbol(THEME.manager.scrollbar_symbol)
+			:style(THEME.manager.scrollbar_style),
 	}
 end
 
 -- Mouse events
 function Current:click(event, up)
-	if up or event.is_middle then
+	if up and Current._dragging then
+		Current._dragging = false
+		return ya.manager_emit("escape", { visual = true })
+	elseif up or event.is_middle then
 		return
 	end
 
@@ -55,12 +73,42 @@ function Current:click(event, up)
 		return
 	end
 
-	ya.manager_emit("arrow", { y + f.offset - f.hovered.idx })
+	local idx = y + f.offset
+	ya.manager_emit("arrow", { idx - f.hovered.idx })
 	if event.is_right then
+		return ya.manager_emit("open", {})
+	end
+
+	-- Open on double-click
+	local now, last = ya.time(), Current._last_click
+	if last and last.idx == idx and now - last.time < 0.4 then
+		Current._last_click = nil
 		ya.manager_emit("open", {})
+	else
+		Current._last_click = { idx = idx, time = now }
 	end
 end
 
 function Current:scroll(event, step) ya.manager_emit("arrow", { step }) end
 
 function Current:touch(event, step) end
+
+-- Select a range by dragging, with the visual mode
+function Current:drag(event)
+	local f = self._folder
+	if not event.is_left or not f.hovered or #f.window == 0 then
+		return
+	end
+
+	local y = math.max(1, math.min(event.y - self._area.y + 1, #f.window))
+	local step = y + f.offset - f.hovered.idx
+	if step == 0 then
+		return
+	end
+
+	if not Current._dragging then
+		Current._dragging = true
+		ya.manager_emit("visual_mode", {})
+	end
+	ya.manager_emit("arrow", { step })
+end
@@ -0,0 +1,109 @@
+Dual = {
+	_id = "dual",
+}
+
+function Dual:new(area)
+	local me = setmetatable({ _area = area }, { __index = self })
+	me:layout()
+	me:build()
+	return me
+end
+
+-- Tabs are paired up as 1 and 2, 3 and 4, etc., and the pair of the active one is shown
+function Dual.pair()
+	local first = cx.tabs.idx - (cx.tabs.idx - 1) % 2
+	return first, math.min(first + 1, #cx.tabs)
+end
+
+function Dual:layout()
+	self._chunks = ui.Layout()
+		:direction(ui.Layout.HORIZONTAL)
+		:constraints({
+			ui.Constraint.Ratio(1, 2),
+			ui.Constraint.Ratio(1, 2),
+		})
+		:split(self._area)
+end
+
+function Dual:build()
+	self._idx = { self.pair() }
+	self._base = {
+		ui.Bar(self._chunks[1], ui.Bar.RIGHT):symbol(THEME.manager.border_symbol):style(THEME.manager.border_style),
+	}
+	self._children = {}
+
+	for i, idx in ipairs(self._idx) do
+		local chunks = ui.Layout()
+			:direction(ui.Layout.VERTICAL)
+			:constraints({
+				ui.Constraint.Length(1),
+				ui.Constraint.Fill(1),
+			})
+			:split(self._chunks[i]:padding(ui.Padding.x(1)))
+
+		local tab = cx.tabs[idx]
+		self._base[#self._base + 1] = self:title(chunks[1], tab, idx == cx.tabs.idx)
+		self._children[i] = Current:new(chunks[2], tab)
+
+		-- Only the active pane is the "current" one, whose area the paging goes by
+		if idx ~= cx.tabs.idx then
+			self._children[i]._id = "dual_other"
+		end
+	end
+end
+
+function Dual:title(area, tab, active)
+	local s = ya.truncate(ya.readable_path(tostring(tab.current.cwd)), { max = area.w, rtl = true })
+	return ui.Paragraph(area, { ui.Line(s) }):style(active and THEME.manager.cwd or ui.Style())
+end
+
+function Dual:render()
+	local children = self._base or {}
+	for _, child in ipairs(self._children) do
+		children = ya.list_merge(children, ya.render_with(child))
+	end
+	return children
+end
+
+-- Mouse events
+function Dual:child_at(event)
+	local c = ya.child_at(ui.Position { x = event.x, y = event.y }, self._children)
+	for i, child in ipairs(self._children) do
+		if child == c then
+			return c, self._idx[i]
+		end
+	end
+end
+
+-- Focus the pane under the cursor first
+function Dual:focus(idx)
+	if idx ~= cx.tabs.idx then
+		ya.manager_emit("tab_switch", { idx - 1 })
+	end
+end
+
+function Dual:click(event, up)
+	local c, idx = self:child_at(event)
+	if c and not up then
+		self:focus(idx)
+	end
+	return c and c:click(event, up)
+end
+
+function Dual:scroll(event, step)
+	local c, idx = self:child_at(event)
+	if c then
+		self:focus(idx)
+	end
+	return c and c:scroll(event, step)
+end
+
+function Dual:touch(event, step)
+	local c = self:child_at(event)
+	return c and c:touch(event, step)
+end
+
+function Dual:drag(event)
+	local c = self:child_at(event)
+	return c and c:drag(event)
+end
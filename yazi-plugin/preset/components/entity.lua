@@ -86,6 +86,11 @@ end
 
 function Entity:style()
 	local s = self._file:style()
+	local yanked = self._file:is_yanked()
+	if yanked ~= 0 then
+		s = (s or ui.Style()):patch(yanked == 1 and THEME.manager.entry_copied or THEME.manager.entry_cut)
+	end
+
 	if not self._file:is_hovered() then
 		return s
 	elseif self._file:in_preview() then
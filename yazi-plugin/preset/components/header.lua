@@ -26,8 +26,68 @@ function Header:cwd()
 		return ui.Span("")
 	end
 
-	local s = ya.readable_path(tostring(self._tab.current.cwd)) .. self:flags()
-	return ui.Span(ya.truncate(s, { max = max, rtl = true })):style(THEME.manager.cwd)
+	local flags = self:flags()
+	local crumbs = self:crumbs(max - ui.Line(flags):width())
+	if not crumbs then
+		local s = ya.readable_path(tostring(self._tab.current.cwd)) .. flags
+		return ui.Span(ya.truncate(s, { max = max, rtl = true })):style(THEME.manager.cwd)
+	end
+
+	local spans = {}
+	for _, c in ipairs(crumbs) do
+		spans[#spans + 1] = ui.Span(c.text)
+	end
+	spans[#spans + 1] = ui.Span(flags)
+	return ui.Line(spans):style(THEME.manager.cwd)
+end
+
+-- Split the CWD into segments pointing to each ancestor, shortened to fit in `max` columns:
+-- the ancestors are abbreviated to their first character first, and then dropped from the left
+function Header:crumbs(max)
+	if max <= 0 then
+		return nil
+	end
+
+	local parts = {}
+	for part in (ya.readable_path(tostring(self._tab.current.cwd)):gsub("/$", "") .. "/"):gmatch("(.-)/") do
+		parts[#parts + 1] = part
+	end
+
+	local crumbs, url = {}, self._tab.current.cwd
+	for i = #parts, 1, -1 do
+		crumbs[i] = { text = i == #parts and parts[i] or parts[i] .. "/", url = url }
+		url = url and url:parent()
+	end
+	if crumbs[#crumbs].text == "" then
+		crumbs[#crumbs].text = "/"
+	end
+
+	local function width()
+		local w = 0
+		for _, c in ipairs(crumbs) do
+			w = w + ui.Line(c.text):width()
+		end
+		return w
+	end
+
+	for i = 1, #crumbs - 1 do
+		if width() <= max then
+			return crumbs
+		end
+		local name = crumbs[i].text:sub(1, -2)
+		crumbs[i].text = (name:match("^%.?" .. utf8.charpattern) or name) .. "/"
+	end
+
+	while #crumbs > 2 and width() > max do
+		table.remove(crumbs, 1)
+		crumbs[1].text = "…/"
+	end
+
+	if width() > max then
+		local last = crumbs[#crumbs]
+		crumbs = { { text = ya.truncate(last.text, { max = max, rtl = true }), url = last.url } }
+	end
+	return crumbs
 end
 
 function Header:flags()
@@ -79,7 +139,8 @@ function Header:tabs()
 	for i = 1, tabs do
 		local text = i
 		if THEME.manager.tab_width > 2 then
-			text = ya.truncate(text .. " " .. cx.tabs[i]:name(), { max = THEME.manager.tab_width })
+			local name = cx.tabs[i].title or cx.tabs[i]:name()
+			text = ya.truncate(text .. " " .. name, { max = THEME.manager.tab_width })
 		end
 		if i == cx.tabs.idx then
 			spans[#spans + 1] = ui.Span(" " .. text .. " "):style(THEME.manager.tab_active)
@@ -102,12 +163,28 @@ function Header:render()
 end
 
 -- Mouse events
-function Header:click(event, up) end
+function Header:click(event, up)
+	if up or event.is_middle then
+		return
+	end
+
+	-- Jump to the ancestor under the cursor
+	self._right_width = self:children_render(self.RIGHT):width()
+	local x = self._area.x
+	for _, c in ipairs(self:crumbs(self._area.w - self._right_width - ui.Line(self:flags()):width()) or {}) do
+		x = x + ui.Line(c.text):width()
+		if event.x < x then
+			return c.url and ya.manager_emit("cd", { c.url })
+		end
+	end
+end
 
 function Header:scroll(event, step) end
 
 function Header:touch(event, step) end
 
+function Header:drag(event) end
+
 -- Children
 function Header:children_add(fn, order, side)
 	self._inc = self._inc + 1
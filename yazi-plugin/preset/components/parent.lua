@@ -41,6 +41,14 @@ function Parent:click(event, up)
 	end
 end
 
-function Parent:scroll(event, step) end
+function Parent:scroll(event, step)
+	local f = self._folder
+	local target = f and f.hovered and f.files[f.hovered.idx + step]
+	if target and target.cha.is_dir then
+		ya.manager_emit("cd", { target.url })
+	end
+end
 
 function Parent:touch(event, step) end
+
+function Parent:drag(event) end
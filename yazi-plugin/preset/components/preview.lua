@@ -30,3 +30,5 @@ end
 function Preview:scroll(event, step) ya.manager_emit("seek", { step }) end
 
 function Preview:touch(event, step) end
+
+function Preview:drag(event) end
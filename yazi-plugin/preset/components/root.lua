@@ -1,6 +1,14 @@
 Root = {
+	LEFT = 0,
+	RIGHT = 1,
+	TOP = 2,
+	BOTTOM = 3,
+	POPUP = 4,
+
 	_id = "root",
 	_drag_start = ui.Rect.default,
+	_inc = 1000,
+	_slots = {},
 }
 
 function Root:new(area)
@@ -14,17 +22,62 @@ function Root:layout()
 	self._chunks = ui.Layout()
 		:direction(ui.Layout.VERTICAL)
 		:constraints({
-			ui.Constraint.Length(1),
+			ui.Constraint.Length(cx.tabs.zen and 0 or 1),
 			ui.Constraint.Fill(1),
 			ui.Constraint.Length(1),
 		})
 		:split(self._area)
+
+	-- Slots are cut off the edges of the main area in the order they were added
+	for _, slot in ipairs(self._slots) do
+		slot.area, self._chunks[2] = self:slot_split(slot, self._chunks[2])
+	end
+end
+
+function Root:slot_split(slot, main)
+	if slot.side == self.POPUP then
+		local w, h = math.min(slot.size[1], self._area.w), math.min(slot.size[2], self._area.h)
+		local x, y = self._area.x + (self._area.w - w) // 2, self._area.y + (self._area.h - h) // 2
+		return ui.Rect { x = x, y = y, w = w, h = h }, main
+	end
+
+	local vertical = slot.side == self.TOP or slot.side == self.BOTTOM
+	local first = slot.side == self.LEFT or slot.side == self.TOP
+	local chunks = ui.Layout()
+		:direction(vertical and ui.Layout.VERTICAL or ui.Layout.HORIZONTAL)
+		:constraints(first and { ui.Constraint.Length(slot.size), ui.Constraint.Fill(1) } or {
+			ui.Constraint.Fill(1),
+			ui.Constraint.Length(slot.size),
+		})
+		:split(main)
+
+	if first then
+		return chunks[1], chunks[2]
+	else
+		return chunks[2], chunks[1]
+	end
 end
 
 function Root:build()
+	if cx.active.preview.zoomed then
+		self._children = { Preview:new(self._area, cx.active) }
+		return
+	end
+
+	-- Zen keeps only the file list, full-width, and the status bar below it
+	if cx.tabs.zen then
+		self._children = {
+			Current:new(self._chunks[2], cx.active),
+			Marker:new(self._chunks[2]:padding(ui.Padding.left(1)), cx.active.current),
+			Status:new(self._chunks[3], cx.active),
+		}
+		return
+	end
+
+	local first, second = Dual.pair()
 	self._children = {
 		Header:new(self._chunks[1], cx.active),
-		Tab:new(self._chunks[2], cx.active),
+		(MANAGER.dual_pane and first ~= second) and Dual:new(self._chunks[2]) or Tab:new(self._chunks[2], cx.active),
 		Status:new(self._chunks[3], cx.active),
 	}
 end
@@ -34,6 +87,53 @@ function Root:render()
 	for _, child in ipairs(self._children) do
 		children = ya.list_merge(children, ya.render_with(child))
 	end
+
+	if not cx.active.preview.zoomed then
+		children = ya.list_merge(children, self:slots_render(false))
+		children = ya.list_merge(children, self:slots_render(true))
+	end
+	return children
+end
+
+-- Slots, for plugins to draw panes or popups of their own with the `ui` elements, e.g.
+--   Root:slot_add(function(area) return { ui.List(area, items) } end, 30, Root.RIGHT)
+-- `size` is the width or height taken off that side, or `{ w, h }` for a centered popup
+function Root:slot_add(fn, size, side)
+	self._inc = self._inc + 1
+	self._slots[#self._slots + 1] = { fn, id = self._inc, size = size, side = side }
+	ya.render()
+	return self._inc
+end
+
+function Root:slot_remove(id)
+	for i, slot in ipairs(self._slots) do
+		if slot.id == id then
+			table.remove(self._slots, i)
+			ya.render()
+			break
+		end
+	end
+end
+
+-- The area a slot got in the last render, e.g. for `ya.image_show()` to fill it with an image
+function Root:slot_area(id)
+	for _, slot in ipairs(self._slots) do
+		if slot.id == id then
+			return slot.area
+		end
+	end
+end
+
+function Root:slots_render(popup)
+	local children = {}
+	for _, slot in ipairs(self._slots) do
+		if slot.area and (slot.side == self.POPUP) == popup then
+			if popup then
+				children[#children + 1] = ui.Clear(slot.area)
+			end
+			children = ya.list_merge(children, slot[1](slot.area))
+		end
+	end
 	return children
 end
 
@@ -55,4 +155,7 @@ end
 
 function Root:move(event) end
 
-function Root:drag(event) end
+function Root:drag(event)
+	local c = ya.child_at(ui.Position { x = event.x, y = event.y }, self._children)
+	return c and c:drag(event)
+end
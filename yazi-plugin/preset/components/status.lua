@@ -10,6 +10,8 @@ Status = {
 		{ "name", id = 3, order = 3000 },
 	},
 	_right = {
+		{ "filter", id = 8, order = 250 },
+		{ "pending", id = 7, order = 500 },
 		{ "permissions", id = 4, order = 1000 },
 		{ "percentage", id = 5, order = 2000 },
 		{ "position", id = 6, order = 3000 },
@@ -66,6 +68,35 @@ function Status:name()
 	return ui.Line(" " .. h.name)
 end
 
+-- Not shown by default, add it with `Status:children_add("mtime", 4000, Status.LEFT)`
+function Status:mtime()
+	local h = self._tab.current.hovered
+	local time = h and (h.cha.modified or 0) // 1 or 0
+	if time == 0 then
+		return ui.Line {}
+	end
+
+	return ui.Line(" " .. os.date("%Y-%m-%d %H:%M", time))
+end
+
+function Status:filter()
+	local filter = self._tab.current.files.filter
+	if not filter then
+		return ui.Line {}
+	end
+
+	return ui.Line(string.format(" /%s/ ", tostring(filter))):style(THEME.manager.find_keyword)
+end
+
+function Status:pending()
+	local keys = cx.which.pending
+	if not keys then
+		return ui.Line {}
+	end
+
+	return ui.Line(" " .. keys .. " ")
+end
+
 function Status:permissions()
 	local h = self._tab.current.hovered
 	if not h then
@@ -104,9 +135,9 @@ function Status:percentage()
 	end
 
 	if percent == 0 then
-		percent = " Top "
+		percent = " " .. LOCALE.status_top .. " "
 	elseif percent == 100 then
-		percent = " Bot "
+		percent = " " .. LOCALE.status_bottom .. " "
 	else
 		percent = string.format(" %2d%% ", percent)
 	end
@@ -122,10 +153,15 @@ function Status:position()
 	local cursor = self._tab.current.cursor
 	local length = #self._tab.current.files
 
+	-- Still streaming in, more entries are on the way
+	if self._tab.current.stage.is_loading then
+		length = length .. "+"
+	end
+
 	local style = self:style()
 	return ui.Line {
 		ui.Span(THEME.status.separator_open):fg(style.bg):bg(THEME.status.separator_style.fg),
-		ui.Span(string.format(" %2d/%-2d ", cursor + 1, length)):style(style),
+		ui.Span(string.format(" %2d/%-2s ", cursor + 1, length)):style(style),
 		ui.Span(THEME.status.separator_close):fg(style.bg),
 	}
 end
@@ -147,6 +183,8 @@ function Status:scroll(event, step) end
 
 function Status:touch(event, step) end
 
+function Status:drag(event) end
+
 -- Children
 function Status:children_add(fn, order, side)
 	self._inc = self._inc + 1
@@ -52,3 +52,8 @@ function Tab:touch(event, step)
 	local c = ya.child_at(ui.Position { x = event.x, y = event.y }, self._children)
 	return c and c:touch(event, step)
 end
+
+function Tab:drag(event)
+	local c = ya.child_at(ui.Position { x = event.x, y = event.y }, self._children)
+	return c and c:drag(event)
+end
@@ -7,8 +7,11 @@ function M:peek()
 	end
 
 	ya.sleep(math.max(0, PREVIEW.image_delay / 1000 + start - os.clock()))
-	ya.image_show(url, self.area)
-	ya.preview_widgets(self, {})
+	if ya.image_show(url, self.area) then
+		ya.preview_widgets(self, {})
+	else
+		require("file").peek(self) -- Undecodable, show what it is instead
+	end
 end
 
 function M:seek() end
@@ -0,0 +1,51 @@
+local M = {}
+
+function M:peek()
+	local start, cache = os.clock(), ya.file_cache(self)
+	if not cache or self:preload() ~= 1 then
+		return require("file").peek(self)
+	end
+
+	ya.sleep(math.max(0, PREVIEW.image_delay / 1000 + start - os.clock()))
+	ya.image_show(cache, self.area)
+	ya.preview_widgets(self, {})
+end
+
+function M:seek() end
+
+-- Camera RAW files carry a JPEG rendition, which is far cheaper to extract than developing the RAW
+function M:preload()
+	local cache = ya.file_cache(self)
+	if not cache or fs.cha(cache) then
+		return 1
+	end
+
+	local jpeg
+	for _, tag in ipairs { "-JpgFromRaw", "-PreviewImage" } do
+		local output, code = Command("exiftool")
+			:args({ "-b", tag, tostring(self.file.url) })
+			:stdout(Command.PIPED)
+			:output()
+		if not output then
+			ya.err("spawn `exiftool` command returns " .. tostring(code))
+			return 0
+		elseif output.status.success and #output.stdout > 0 then
+			jpeg = output.stdout
+			break
+		end
+	end
+	if not jpeg then
+		return 2
+	end
+
+	local tmp = Url(tostring(cache) .. ".jpg")
+	if not fs.write(tmp, jpeg) then
+		return 0
+	end
+
+	local ok = ya.image_precache(tmp, cache)
+	fs.remove("file", tmp)
+	return ok and 1 or 2
+end
+
+return M
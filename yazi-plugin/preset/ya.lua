@@ -28,16 +28,6 @@ function ya.dict_merge(a, b)
 	return a
 end
 
-function ya.readable_size(size)
-	local units = { "B", "K", "M", "G", "T", "P", "E", "Z", "Y", "R", "Q" }
-	local i = 1
-	while size > 1024.0 and i < #units do
-		size = size / 1024.0
-		i = i + 1
-	end
-	return string.format("%.1f%s", size, units[i])
-end
-
 function ya.readable_path(path)
 	local home = os.getenv("HOME") or os.getenv("USERPROFILE")
 	if not home then
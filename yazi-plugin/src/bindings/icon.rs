@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use mlua::{AnyUserData, Lua, UserDataFields};
 
 use super::Cast;
@@ -7,7 +9,7 @@ pub struct Icon;
 
 impl Icon {
 	pub fn register(lua: &Lua) -> mlua::Result<()> {
-		lua.register_userdata_type::<&yazi_shared::theme::Icon>(|reg| {
+		lua.register_userdata_type::<Arc<yazi_shared::theme::Icon>>(|reg| {
 			reg.add_field_method_get("text", |lua, me| lua.create_string(&me.text));
 			reg.add_field_method_get("style", |_, me| Ok(Style::from(me.style)));
 		})?;
@@ -16,11 +18,8 @@ impl Icon {
 	}
 }
 
-impl Cast<&'static yazi_shared::theme::Icon> for Icon {
-	fn cast<'lua>(
-		lua: &'lua Lua,
-		data: &'static yazi_shared::theme::Icon,
-	) -> mlua::Result<AnyUserData<'lua>> {
+impl Cast<Arc<yazi_shared::theme::Icon>> for Icon {
+	fn cast(lua: &Lua, data: Arc<yazi_shared::theme::Icon>) -> mlua::Result<AnyUserData> {
 		lua.create_any_userdata(data)
 	}
 }
@@ -1,111 +1,170 @@
-use std::ffi::OsString;
+use std::{ffi::{OsStr, OsString}, io::{BufWriter, stderr}, process::Stdio};
 
+use crossterm::execute;
 use parking_lot::Mutex;
-use yazi_shared::RoCell;
+use tokio::{io::AsyncWriteExt, process::Command};
+use yazi_config::clipboard::ClipboardBackend;
+use yazi_shared::{RoCell, bin_exists, env_exists, in_ssh_connection, in_wsl};
 
 pub static CLIPBOARD: RoCell<Clipboard> = RoCell::new();
 
-#[derive(Default)]
 pub struct Clipboard {
+	backend: ClipboardBackend,
 	content: Mutex<OsString>,
 }
 
+impl Default for Clipboard {
+	fn default() -> Self {
+		let backend = match yazi_config::CLIPBOARD.backend {
+			ClipboardBackend::Auto => Self::detect(),
+			b => b,
+		};
+
+		Self { backend, content: Default::default() }
+	}
+}
+
 impl Clipboard {
-	#[cfg(unix)]
 	pub async fn get(&self) -> OsString {
-		use std::os::unix::prelude::OsStringExt;
+		#[cfg(windows)]
+		if self.backend == ClipboardBackend::Windows {
+			use clipboard_win::{formats, get_clipboard};
 
-		use tokio::process::Command;
-		use yazi_shared::in_ssh_connection;
-
-		if in_ssh_connection() {
-			return self.content.lock().clone();
+			let result = tokio::task::spawn_blocking(|| get_clipboard::<String, _>(formats::Unicode));
+			if let Ok(Ok(s)) = result.await {
+				return s.into();
+			}
 		}
 
-		let all = [
-			("pbpaste", &[][..]),
-			("wl-paste", &[]),
-			("xclip", &["-o", "-selection", "clipboard"]),
-			("xsel", &["-ob"]),
-		];
-
-		for (bin, args) in all {
-			let Ok(output) = Command::new(bin).args(args).kill_on_drop(true).output().await else {
-				continue;
-			};
-			if output.status.success() {
-				return OsString::from_vec(output.stdout);
+		let Some((bin, args)) = self.paste_cmd() else {
+			return self.content.lock().clone();
+		};
+
+		match Command::new(bin).args(args).kill_on_drop(true).output().await {
+			Ok(output) if output.status.success() => {
+				let mut b = output.stdout;
+				if self.backend == ClipboardBackend::Wsl && b.ends_with(b"\r\n") {
+					b.truncate(b.len() - 2);
+				}
+				Self::os_string(b)
 			}
+			_ => self.content.lock().clone(),
 		}
-		self.content.lock().clone()
 	}
 
-	#[cfg(windows)]
-	pub async fn get(&self) -> OsString {
-		use clipboard_win::{formats, get_clipboard};
+	pub async fn set(&self, s: impl AsRef<OsStr>) {
+		s.as_ref().clone_into(&mut self.content.lock());
+
+		#[cfg(windows)]
+		if self.backend == ClipboardBackend::Windows {
+			use clipboard_win::{formats, set_clipboard};
 
-		let result = tokio::task::spawn_blocking(|| get_clipboard::<String, _>(formats::Unicode));
-		if let Ok(Ok(s)) = result.await {
-			return s.into();
+			let s = s.as_ref().to_owned();
+			let result =
+				tokio::task::spawn_blocking(move || set_clipboard(formats::Unicode, s.to_string_lossy()));
+			if matches!(result.await, Ok(Ok(()))) {
+				return;
+			}
 		}
 
-		self.content.lock().clone()
+		if let Some((bin, args)) = self.copy_cmd() {
+			if Self::pipe(bin, args, &self.encode(s.as_ref())).await {
+				return;
+			}
+		}
+
+		// OSC 52 goes last, since not all terminals support it
+		execute!(BufWriter::new(stderr()), osc52::SetClipboard::new(s.as_ref())).ok();
 	}
 
-	#[cfg(unix)]
-	pub async fn set(&self, s: impl AsRef<std::ffi::OsStr>) {
-		use std::{io::{BufWriter, stderr}, process::Stdio};
+	fn detect() -> ClipboardBackend {
+		if cfg!(windows) {
+			return ClipboardBackend::Windows;
+		} else if in_ssh_connection() {
+			return ClipboardBackend::Osc52;
+		} else if cfg!(target_os = "macos") {
+			return ClipboardBackend::Pasteboard;
+		}
 
-		use crossterm::execute;
-		use tokio::{io::AsyncWriteExt, process::Command};
+		if env_exists("WAYLAND_DISPLAY") && bin_exists("wl-copy") {
+			ClipboardBackend::Wayland
+		} else if env_exists("DISPLAY") && bin_exists("xclip") {
+			ClipboardBackend::Xclip
+		} else if env_exists("DISPLAY") && bin_exists("xsel") {
+			ClipboardBackend::Xsel
+		} else if in_wsl() {
+			ClipboardBackend::Wsl
+		} else {
+			ClipboardBackend::Osc52
+		}
+	}
 
-		s.as_ref().clone_into(&mut self.content.lock());
-		execute!(BufWriter::new(stderr()), osc52::SetClipboard::new(s.as_ref())).ok();
+	fn copy_cmd(&self) -> Option<(&'static str, &'static [&'static str])> {
+		Some(match self.backend {
+			ClipboardBackend::Pasteboard => ("pbcopy", &[]),
+			ClipboardBackend::Wayland => ("wl-copy", &[]),
+			ClipboardBackend::Xclip => ("xclip", &["-selection", "clipboard"]),
+			ClipboardBackend::Xsel => ("xsel", &["-ib"]),
+			ClipboardBackend::Wsl => ("clip.exe", &[]),
+			_ => return None,
+		})
+	}
 
-		let all = [
-			("pbcopy", &[][..]),
-			("wl-copy", &[]),
-			("xclip", &["-selection", "clipboard"]),
-			("xsel", &["-ib"]),
-		];
-
-		for (bin, args) in all {
-			let cmd = Command::new(bin)
-				.args(args)
-				.stdin(Stdio::piped())
-				.stdout(Stdio::null())
-				.stderr(Stdio::null())
-				.kill_on_drop(true)
-				.spawn();
-
-			let Ok(mut child) = cmd else { continue };
-
-			let mut stdin = child.stdin.take().unwrap();
-			if stdin.write_all(s.as_ref().as_encoded_bytes()).await.is_err() {
-				continue;
-			}
-			drop(stdin);
+	fn paste_cmd(&self) -> Option<(&'static str, &'static [&'static str])> {
+		Some(match self.backend {
+			ClipboardBackend::Pasteboard => ("pbpaste", &[]),
+			ClipboardBackend::Wayland => ("wl-paste", &["--no-newline"]),
+			ClipboardBackend::Xclip => ("xclip", &["-o", "-selection", "clipboard"]),
+			ClipboardBackend::Xsel => ("xsel", &["-ob"]),
+			ClipboardBackend::Wsl => ("powershell.exe", &[
+				"-NoProfile",
+				"-Command",
+				"[Console]::OutputEncoding = [Text.Encoding]::UTF8; Get-Clipboard -Raw",
+			]),
+			_ => return None,
+		})
+	}
 
-			if child.wait().await.map(|s| s.success()).unwrap_or_default() {
-				break;
-			}
+	// `clip.exe` reads the OEM code page unless given UTF-16LE with a BOM
+	fn encode(&self, s: &OsStr) -> Vec<u8> {
+		if self.backend != ClipboardBackend::Wsl {
+			return s.as_encoded_bytes().to_vec();
 		}
+
+		let s = s.to_string_lossy();
+		[0xfeff].into_iter().chain(s.encode_utf16()).flat_map(u16::to_le_bytes).collect()
 	}
 
-	#[cfg(windows)]
-	pub async fn set(&self, s: impl AsRef<std::ffi::OsStr>) {
-		use clipboard_win::{formats, set_clipboard};
+	async fn pipe(bin: &str, args: &[&str], b: &[u8]) -> bool {
+		let cmd = Command::new(bin)
+			.args(args)
+			.stdin(Stdio::piped())
+			.stdout(Stdio::null())
+			.stderr(Stdio::null())
+			.kill_on_drop(true)
+			.spawn();
+
+		let Ok(mut child) = cmd else { return false };
 
-		let s = s.as_ref().to_owned();
-		*self.content.lock() = s.clone();
+		let mut stdin = child.stdin.take().unwrap();
+		if stdin.write_all(b).await.is_err() {
+			return false;
+		}
+		drop(stdin);
 
-		tokio::task::spawn_blocking(move || set_clipboard(formats::Unicode, s.to_string_lossy()))
-			.await
-			.ok();
+		child.wait().await.map(|s| s.success()).unwrap_or_default()
 	}
+
+	#[cfg(unix)]
+	fn os_string(b: Vec<u8>) -> OsString {
+		use std::os::unix::ffi::OsStringExt;
+		OsString::from_vec(b)
+	}
+
+	#[cfg(windows)]
+	fn os_string(b: Vec<u8>) -> OsString { String::from_utf8_lossy(&b).into_owned().into() }
 }
 
-#[cfg(unix)]
 mod osc52 {
 	use std::ffi::OsStr;
 
@@ -126,5 +185,8 @@ mod osc52 {
 		fn write_ansi(&self, f: &mut impl std::fmt::Write) -> std::fmt::Result {
 			write!(f, "\x1b]52;c;{}\x1b\\", self.content)
 		}
+
+		#[cfg(windows)]
+		fn execute_winapi(&self) -> std::io::Result<()> { Ok(()) }
 	}
 }
@@ -1,6 +1,6 @@
 use mlua::{Lua, LuaSerdeExt, SerializeOptions};
 use yazi_boot::BOOT;
-use yazi_config::{MANAGER, PREVIEW, THEME};
+use yazi_config::{LOCALE, MANAGER, PREVIEW, THEME};
 
 const OPTIONS: SerializeOptions =
 	SerializeOptions::new().serialize_none_to_null(false).serialize_unit_to_null(false);
@@ -23,7 +23,12 @@ impl<'a> Config<'a> {
 	}
 
 	pub fn install_theme(self) -> mlua::Result<Self> {
-		self.lua.globals().raw_set("THEME", self.lua.to_value_with(&*THEME, OPTIONS)?)?;
+		self.lua.globals().raw_set("THEME", self.lua.to_value_with(&**THEME.load(), OPTIONS)?)?;
+		Ok(self)
+	}
+
+	pub fn install_locale(self) -> mlua::Result<Self> {
+		self.lua.globals().raw_set("LOCALE", self.lua.to_value_with(&*LOCALE, OPTIONS)?)?;
 		Ok(self)
 	}
 
@@ -2,7 +2,7 @@ use std::mem;
 
 use ansi_to_tui::IntoText;
 use mlua::{AnyUserData, ExternalError, ExternalResult, FromLua, IntoLua, Lua, Table, UserData, UserDataMethods, Value};
-use unicode_width::UnicodeWidthChar;
+use yazi_shared::{char_width, str_width};
 
 use super::Span;
 
@@ -79,6 +79,12 @@ impl Line {
 
 		ui.raw_set("Line", line)
 	}
+
+	// Unlike `ratatui::text::Line::width()`, this respects the `ambiguous` option
+	#[inline]
+	pub(super) fn width(line: &ratatui::text::Line) -> usize {
+		line.spans.iter().map(|s| str_width(&s.content)).sum()
+	}
 }
 
 impl UserData for Line {
@@ -86,7 +92,7 @@ impl UserData for Line {
 		crate::impl_style_method!(methods, 0.style);
 		crate::impl_style_shorthands!(methods, 0.style);
 
-		methods.add_method("width", |_, me, ()| Ok(me.0.width()));
+		methods.add_method("width", |_, me, ()| Ok(Self::width(&me.0)));
 		methods.add_function("align", |_, (ud, align): (AnyUserData, u8)| {
 			ud.borrow_mut::<Self>()?.0.alignment = Some(match align {
 				CENTER => ratatui::layout::Alignment::Center,
@@ -96,7 +102,7 @@ impl UserData for Line {
 			Ok(ud)
 		});
 		methods.add_method("visible", |_, me, ()| {
-			Ok(me.0.iter().flat_map(|s| s.content.chars()).any(|c| c.width().unwrap_or(0) > 0))
+			Ok(me.0.iter().flat_map(|s| s.content.chars()).any(|c| char_width(c) > 0))
 		});
 	}
 }
@@ -1,5 +1,5 @@
 use mlua::{FromLua, Lua, Table, UserData, UserDataMethods};
-use unicode_width::UnicodeWidthChar;
+use yazi_shared::char_width;
 
 #[derive(Clone, FromLua)]
 pub struct Span(pub(super) ratatui::text::Span<'static>);
@@ -20,8 +20,6 @@ impl UserData for Span {
 		crate::impl_style_method!(methods, 0.style);
 		crate::impl_style_shorthands!(methods, 0.style);
 
-		methods.add_method("visible", |_, me, ()| {
-			Ok(me.0.content.chars().any(|c| c.width().unwrap_or(0) > 0))
-		});
+		methods.add_method("visible", |_, me, ()| Ok(me.0.content.chars().any(|c| char_width(c) > 0)));
 	}
 }
@@ -0,0 +1,40 @@
+use std::{path::PathBuf, process::Stdio};
+
+use anyhow::Result;
+use tokio::{io::{AsyncBufReadExt, BufReader}, process::Command, sync::mpsc::{self, UnboundedReceiver}};
+use yazi_shared::fs::{File, Url};
+
+pub struct EsOpt {
+	pub cwd:     Url,
+	pub hidden:  bool,
+	pub subject: String,
+	pub args:    Vec<String>,
+}
+
+// The command-line interface of Everything, which requires it to be running
+pub fn es(opt: EsOpt) -> Result<UnboundedReceiver<File>> {
+	let mut child = Command::new("es.exe")
+		.arg("-path")
+		.arg(&opt.cwd)
+		.args(if opt.hidden { None } else { Some("/a-h") })
+		.args(opt.args)
+		.arg("-regex")
+		.arg(opt.subject)
+		.kill_on_drop(true)
+		.stdout(Stdio::piped())
+		.stderr(Stdio::null())
+		.spawn()?;
+
+	let mut it = BufReader::new(child.stdout.take().unwrap()).lines();
+	let (tx, rx) = mpsc::unbounded_channel();
+
+	tokio::spawn(async move {
+		while let Ok(Some(line)) = it.next_line().await {
+			if let Ok(file) = File::from(Url::from(PathBuf::from(line))).await {
+				tx.send(file).ok();
+			}
+		}
+		child.wait().await.ok();
+	});
+	Ok(rx)
+}
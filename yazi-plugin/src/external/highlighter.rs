@@ -21,7 +21,7 @@ impl Highlighter {
 	pub async fn init() -> (&'static Theme, &'static SyntaxSet) {
 		let fut = async {
 			tokio::task::spawn_blocking(|| {
-				let theme = std::fs::File::open(&THEME.manager.syntect_theme)
+				let theme = std::fs::File::open(&THEME.load().manager.syntect_theme)
 					.map_err(LoadingError::Io)
 					.and_then(|f| ThemeSet::load_from_reader(&mut std::io::BufReader::new(f)))
 					.or_else(|_| ThemeSet::load_from_reader(&mut Cursor::new(yazi_prebuild::ansi_theme())));
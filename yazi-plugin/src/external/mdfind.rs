@@ -0,0 +1,47 @@
+use std::{path::PathBuf, process::Stdio};
+
+use anyhow::Result;
+use tokio::{io::{AsyncBufReadExt, BufReader}, process::Command, sync::mpsc::{self, UnboundedReceiver}};
+use yazi_shared::fs::{File, Url};
+
+pub struct MdfindOpt {
+	pub cwd:     Url,
+	pub hidden:  bool,
+	pub subject: String,
+	pub args:    Vec<String>,
+}
+
+// Spotlight, which only matches the names by substrings instead of regexes
+pub fn mdfind(opt: MdfindOpt) -> Result<UnboundedReceiver<File>> {
+	let mut child = Command::new("mdfind")
+		.arg("-onlyin")
+		.arg(&opt.cwd)
+		.args(opt.args)
+		.arg("-name")
+		.arg(opt.subject)
+		.kill_on_drop(true)
+		.stdout(Stdio::piped())
+		.stderr(Stdio::null())
+		.spawn()?;
+
+	let mut it = BufReader::new(child.stdout.take().unwrap()).lines();
+	let (tx, rx) = mpsc::unbounded_channel();
+
+	tokio::spawn(async move {
+		while let Ok(Some(line)) = it.next_line().await {
+			let path = PathBuf::from(line);
+			let hidden = path
+				.strip_prefix(&opt.cwd)
+				.is_ok_and(|p| p.iter().any(|s| s.as_encoded_bytes().starts_with(b".")));
+
+			if hidden && !opt.hidden {
+				continue;
+			}
+			if let Ok(file) = File::from(Url::from(path)).await {
+				tx.send(file).ok();
+			}
+		}
+		child.wait().await.ok();
+	});
+	Ok(rx)
+}
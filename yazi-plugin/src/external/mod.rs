@@ -1,7 +1,11 @@
+mod es;
 mod fd;
 mod highlighter;
+mod mdfind;
 mod rg;
 
+pub use es::*;
 pub use fd::*;
 pub use highlighter::*;
+pub use mdfind::*;
 pub use rg::*;
@@ -1,4 +1,4 @@
-use std::process::Stdio;
+use std::{path::Path, process::Stdio};
 
 use anyhow::Result;
 use tokio::{io::{AsyncBufReadExt, BufReader}, process::Command, sync::mpsc::{self, UnboundedReceiver}};
@@ -36,3 +36,19 @@ pub fn rg(opt: RgOpt) -> Result<UnboundedReceiver<File>> {
 	});
 	Ok(rx)
 }
+
+// 1-based line number of the first match in a file
+pub async fn rg_line(subject: &str, args: &[String], path: &Path) -> Option<usize> {
+	let output = Command::new("rg")
+		.args(["--color=never", "--max-count=1", "--line-number", "--no-filename", "--smart-case"])
+		.args(args)
+		.args(["--", subject])
+		.arg(path)
+		.kill_on_drop(true)
+		.stderr(Stdio::null())
+		.output()
+		.await
+		.ok()?;
+
+	String::from_utf8_lossy(&output.stdout).split_once(':')?.0.parse().ok()
+}
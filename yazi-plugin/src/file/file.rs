@@ -34,10 +34,11 @@ impl File {
 			use yazi_shared::theme::IconCache;
 
 			let me = me.as_ref();
-			match me.icon.get() {
+			let cached = me.icon.borrow().clone();
+			match cached {
 				IconCache::Missing => {
-					let matched = THEME.icons.matches(me);
-					me.icon.set(matched.map_or(IconCache::Undefined, IconCache::Icon));
+					let matched = THEME.load().icons.matches(me).cloned();
+					*me.icon.borrow_mut() = matched.clone().map_or(IconCache::Undefined, IconCache::Icon);
 					matched.map(|i| Icon::cast(lua, i)).transpose()
 				}
 				IconCache::Undefined => Ok(None),
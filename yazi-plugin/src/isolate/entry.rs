@@ -3,14 +3,18 @@ use tokio::runtime::Handle;
 use yazi_dds::Sendable;
 use yazi_shared::event::Data;
 
-use super::slim_lua;
+use super::{Task, slim_lua};
 use crate::loader::LOADER;
 
-pub async fn entry(name: String, args: Vec<Data>) -> mlua::Result<()> {
+pub async fn entry(name: String, args: Vec<Data>, task: Option<Task>) -> mlua::Result<()> {
 	LOADER.ensure(&name).await.into_lua_err()?;
 
 	tokio::task::spawn_blocking(move || {
 		let lua = slim_lua(&name)?;
+		if let Some(task) = task {
+			lua.set_app_data(task);
+		}
+
 		let plugin: Table = if let Some(b) = LOADER.read().get(&name) {
 			lua.load(b.as_ref()).set_name(name).call(())?
 		} else {
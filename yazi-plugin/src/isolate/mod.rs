@@ -6,6 +6,7 @@ mod isolate;
 mod peek;
 mod preload;
 mod seek;
+mod task;
 
 pub use entry::*;
 pub use fetch::*;
@@ -13,3 +14,4 @@ pub use isolate::*;
 pub use peek::*;
 pub use preload::*;
 pub use seek::*;
+pub use task::*;
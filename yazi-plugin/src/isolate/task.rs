@@ -0,0 +1,6 @@
+// The scheduler task an async plugin runs as
+pub struct Task {
+	pub id:       usize,
+	pub progress: Box<dyn Fn(u64, u64) + Send>,
+	pub canceled: Box<dyn Fn() -> bool + Send>,
+}
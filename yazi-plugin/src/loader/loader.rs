@@ -38,6 +38,7 @@ impl Loader {
 			"mime" => preset!("plugins/mime"),
 			"noop" => preset!("plugins/noop"),
 			"pdf" => preset!("plugins/pdf"),
+			"raw" => preset!("plugins/raw"),
 			"session" => preset!("plugins/session"),
 			"video" => preset!("plugins/video"),
 			"zoxide" => preset!("plugins/zoxide"),
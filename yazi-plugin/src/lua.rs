@@ -16,7 +16,7 @@ pub(super) fn init_lua() -> Result<()> {
 }
 
 fn stage_1(lua: &'static Lua) -> Result<()> {
-	crate::Config::new(lua).install_boot()?.install_manager()?.install_theme()?;
+	crate::Config::new(lua).install_boot()?.install_manager()?.install_theme()?.install_locale()?;
 	crate::utils::install(lua)?;
 
 	// Base
@@ -33,6 +33,7 @@ fn stage_1(lua: &'static Lua) -> Result<()> {
 
 	// Components
 	lua.load(preset!("components/current")).set_name("current.lua").exec()?;
+	lua.load(preset!("components/dual")).set_name("dual.lua").exec()?;
 	lua.load(preset!("components/entity")).set_name("entity.lua").exec()?;
 	lua.load(preset!("components/header")).set_name("header.lua").exec()?;
 	lua.load(preset!("components/linemode")).set_name("linemode.lua").exec()?;
@@ -3,8 +3,9 @@ use std::{str::FromStr, time::Duration};
 use mlua::{ExternalError, ExternalResult, IntoLuaMulti, Lua, Table, Value};
 use tokio::sync::mpsc;
 use tokio_stream::wrappers::UnboundedReceiverStream;
-use yazi_config::{keymap::{Chord, Key}, popup::InputCfg};
-use yazi_proxy::{AppProxy, InputProxy};
+use ratatui::widgets::{Paragraph, Wrap};
+use yazi_config::{keymap::{Chord, Key}, popup::{ConfirmCfg, InputCfg, SelectCfg}};
+use yazi_proxy::{Answer, AppProxy, InputProxy, Prompt, PromptProxy};
 use yazi_shared::{Debounce, Layer, emit, event::Cmd};
 
 use super::Utils;
@@ -86,18 +87,43 @@ impl Utils {
 			})?,
 		)?;
 
-		// TODO: redesign the confirm API
-		// ya.raw_set(
-		// 	"confirm",
-		// 	lua.create_async_function(|_, t: Table| async move {
-		// 		let result = ConfirmProxy::show(ConfirmCfg {
-		// 			title:    t.raw_get("title")?,
-		// 			content:  t.raw_get("content")?,
-		// 			position: Position::try_from(t.raw_get::<_, Table>("position")?)?.into(),
-		// 		});
-		// 		Ok(result.await)
-		// 	})?,
-		// )?;
+		ya.raw_set(
+			"prompt",
+			lua.create_async_function(|lua, t: Table| async move {
+				let title = t.raw_get("title")?;
+				let position = Position::try_from(t.raw_get::<_, Table>("position")?)?.into();
+
+				let prompt = match t.raw_get::<_, mlua::String>("type")?.to_str()? {
+					"input" => Prompt::Input(InputCfg {
+						title,
+						value: t.raw_get("value").unwrap_or_default(),
+						position,
+						..Default::default()
+					}),
+					"select" => Prompt::Select(SelectCfg {
+						title,
+						items: t.raw_get("items")?,
+						position,
+						current: t.raw_get::<_, Option<usize>>("current")?.and_then(|n| n.checked_sub(1)),
+					}),
+					"confirm" => Prompt::Confirm(Box::new(ConfirmCfg {
+						title,
+						position,
+						content: Paragraph::new(t.raw_get::<_, Option<String>>("content")?.unwrap_or_default())
+							.wrap(Wrap { trim: false }),
+						..Default::default()
+					})),
+					_ => Err("invalid prompt `type`".into_lua_err())?,
+				};
+
+				match PromptProxy::show(prompt).await {
+					Some(Answer::Input(s)) => s.into_lua_multi(lua),
+					Some(Answer::Select(i)) => (i + 1).into_lua_multi(lua),
+					Some(Answer::Confirm(b)) => b.into_lua_multi(lua),
+					None => Value::Nil.into_lua_multi(lua),
+				}
+			})?,
+		)?;
 
 		ya.raw_set(
 			"notify",
@@ -9,6 +9,7 @@ mod log;
 mod preview;
 mod sync;
 mod target;
+mod task;
 mod text;
 mod time;
 mod user;
@@ -0,0 +1,32 @@
+use mlua::{Lua, Table};
+
+use super::Utils;
+use crate::isolate::Task;
+
+impl Utils {
+	pub(super) fn task(lua: &Lua, ya: &Table) -> mlua::Result<()> {
+		ya.raw_set(
+			"task_id",
+			lua.create_function(|lua, ()| Ok(lua.app_data_ref::<Task>().map(|t| t.id)))?,
+		)?;
+
+		ya.raw_set(
+			"task_progress",
+			lua.create_function(|lua, (done, total): (u64, u64)| {
+				if let Some(t) = lua.app_data_ref::<Task>() {
+					(t.progress)(done, total);
+				}
+				Ok(())
+			})?,
+		)?;
+
+		ya.raw_set(
+			"task_canceled",
+			lua.create_function(|lua, ()| {
+				Ok(lua.app_data_ref::<Task>().is_some_and(|t| (t.canceled)()))
+			})?,
+		)?;
+
+		Ok(())
+	}
+}
@@ -2,7 +2,7 @@ use std::ops::ControlFlow;
 
 use md5::{Digest, Md5};
 use mlua::{Lua, Table};
-use unicode_width::UnicodeWidthChar;
+use yazi_shared::{char_width, readable_size};
 
 use super::Utils;
 use crate::CLIPBOARD;
@@ -41,6 +41,11 @@ impl Utils {
 			})?,
 		)?;
 
+		ya.raw_set(
+			"readable_size",
+			lua.create_function(|_, size: mlua::Number| Ok(readable_size(size as u64)))?,
+		)?;
+
 		ya.raw_set(
 			"clipboard",
 			lua.create_async_function(|lua, text: Option<String>| async move {
@@ -59,7 +64,7 @@ impl Utils {
 	fn truncate(mut chars: impl Iterator<Item = char>, max: usize) -> Vec<char> {
 		let mut width = 0;
 		let flow = chars.try_fold(Vec::with_capacity(max), |mut v, c| {
-			width += c.width().unwrap_or(0);
+			width += char_width(c);
 			if width < max {
 				v.push(c);
 				ControlFlow::Continue(v)
@@ -34,6 +34,7 @@ pub fn install_isolate(lua: &mlua::Lua) -> mlua::Result<()> {
 	Utils::preview(lua, &ya)?;
 	Utils::sync_isolate(lua, &ya)?;
 	Utils::target(lua, &ya)?;
+	Utils::task(lua, &ya)?;
 	Utils::text(lua, &ya)?;
 	Utils::time(lua, &ya)?;
 	Utils::user(lua, &ya)?;
@@ -1,7 +1,7 @@
 use std::time::Duration;
 
 use tokio::sync::oneshot;
-use yazi_shared::{Layer, emit, event::Cmd};
+use yazi_shared::{Layer, emit, event::Cmd, fs::Url};
 
 use crate::options::{NotifyLevel, NotifyOpt};
 
@@ -25,6 +25,18 @@ impl AppProxy {
 		emit!(Call(Cmd::new("notify").with_any("option", opt), Layer::App));
 	}
 
+	// Ring the terminal bell, unless `dir` is already the CWD
+	#[inline]
+	pub fn bell(dir: Option<&Url>) {
+		emit!(Call(
+			match dir {
+				Some(u) => Cmd::args("bell", &[u]),
+				None => Cmd::new("bell"),
+			},
+			Layer::App
+		));
+	}
+
 	#[inline]
 	pub fn notify_warn(title: &str, content: impl ToString) {
 		emit!(Call(
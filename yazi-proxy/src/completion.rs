@@ -12,4 +12,12 @@ impl CompletionProxy {
 	pub fn trigger(word: &str, ticket: usize) {
 		emit!(Call(Cmd::args("trigger", &[word]).with("ticket", ticket), Layer::Completion));
 	}
+
+	#[inline]
+	pub fn trigger_with(word: &str, cands: Vec<String>, ticket: usize) {
+		emit!(Call(
+			Cmd::args("trigger", &[word]).with_any("cands", cands).with("ticket", ticket),
+			Layer::Completion
+		));
+	}
 }
@@ -4,6 +4,7 @@ mod confirm;
 mod input;
 mod manager;
 pub mod options;
+mod prompt;
 mod select;
 mod semaphore;
 mod tab;
@@ -14,6 +15,7 @@ pub use completion::*;
 pub use confirm::*;
 pub use input::*;
 pub use manager::*;
+pub use prompt::*;
 pub use select::*;
 pub use semaphore::*;
 pub use tab::*;
@@ -1,3 +1,4 @@
+use yazi_config::KEYMAP;
 use yazi_shared::{Layer, emit, event::Cmd, fs::Url};
 
 use crate::options::OpenDoOpt;
@@ -18,6 +19,13 @@ impl ManagerProxy {
 		));
 	}
 
+	#[inline]
+	pub fn hook(event: &str) {
+		if let Some(run) = KEYMAP.load().hooks.get(event) {
+			emit!(Seq(run.iter().map(Cmd::shallow_clone).collect(), Layer::Manager));
+		}
+	}
+
 	#[inline]
 	pub fn refresh() {
 		emit!(Call(Cmd::new("refresh"), Layer::Manager));
@@ -29,9 +37,12 @@ impl ManagerProxy {
 	}
 
 	#[inline]
-	pub fn remove_do(targets: Vec<Url>, permanently: bool) {
+	pub fn remove_do(targets: Vec<Url>, permanently: bool, shred: bool) {
 		emit!(Call(
-			Cmd::new("remove_do").with_bool("permanently", permanently).with_any("targets", targets),
+			Cmd::new("remove_do")
+				.with_bool("permanently", permanently)
+				.with_bool("shred", shred)
+				.with_any("targets", targets),
 			Layer::Manager
 		));
 	}
@@ -41,6 +52,14 @@ impl ManagerProxy {
 		emit!(Call(Cmd::new("update_task").with_any("url", url.clone()), Layer::Manager));
 	}
 
+	#[inline]
+	pub fn preview_line(line: usize, only_if: &Url) {
+		emit!(Call(
+			Cmd::args("preview_line", &[line]).with_any("only-if", only_if.clone()),
+			Layer::Manager
+		));
+	}
+
 	#[inline]
 	pub fn update_paged() {
 		emit!(Call(Cmd::new("update_paged"), Layer::Manager));
@@ -52,20 +52,22 @@ pub enum NotifyLevel {
 
 impl NotifyLevel {
 	#[inline]
-	pub fn icon(self) -> &'static str {
+	pub fn icon(self) -> String {
+		let notify = &THEME.load().notify;
 		match self {
-			Self::Info => &THEME.notify.icon_info,
-			Self::Warn => &THEME.notify.icon_warn,
-			Self::Error => &THEME.notify.icon_error,
+			Self::Info => notify.icon_info.clone(),
+			Self::Warn => notify.icon_warn.clone(),
+			Self::Error => notify.icon_error.clone(),
 		}
 	}
 
 	#[inline]
-	pub fn style(self) -> &'static Style {
+	pub fn style(self) -> Style {
+		let notify = &THEME.load().notify;
 		match self {
-			Self::Info => &THEME.notify.title_info,
-			Self::Warn => &THEME.notify.title_warn,
-			Self::Error => &THEME.notify.title_error,
+			Self::Info => notify.title_info,
+			Self::Warn => notify.title_warn,
+			Self::Error => notify.title_error,
 		}
 	}
 }
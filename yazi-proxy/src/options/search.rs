@@ -30,6 +30,8 @@ pub enum SearchOptVia {
 	None,
 	Rg,
 	Fd,
+	Mdfind,
+	Es,
 }
 
 impl From<String> for SearchOptVia {
@@ -37,6 +39,8 @@ impl From<String> for SearchOptVia {
 		match value.as_str() {
 			"rg" => Self::Rg,
 			"fd" => Self::Fd,
+			"mdfind" => Self::Mdfind,
+			"es" => Self::Es,
 			_ => Self::None,
 		}
 	}
@@ -47,6 +51,8 @@ impl Display for SearchOptVia {
 		f.write_str(match self {
 			Self::Rg => "rg",
 			Self::Fd => "fd",
+			Self::Mdfind => "mdfind",
+			Self::Es => "es",
 			Self::None => "none",
 		})
 	}
@@ -0,0 +1,52 @@
+use yazi_config::popup::{ConfirmCfg, InputCfg, SelectCfg};
+use yazi_shared::InputError;
+
+use crate::{ConfirmProxy, InputProxy, SelectProxy};
+
+pub enum Prompt {
+	Input(InputCfg),
+	Select(SelectCfg),
+	Confirm(Box<ConfirmCfg>),
+}
+
+impl From<InputCfg> for Prompt {
+	fn from(cfg: InputCfg) -> Self { Self::Input(cfg) }
+}
+
+impl From<SelectCfg> for Prompt {
+	fn from(cfg: SelectCfg) -> Self { Self::Select(cfg) }
+}
+
+impl From<ConfirmCfg> for Prompt {
+	fn from(cfg: ConfirmCfg) -> Self { Self::Confirm(Box::new(cfg)) }
+}
+
+#[derive(Debug)]
+pub enum Answer {
+	Input(String),
+	Select(usize),
+	Confirm(bool),
+}
+
+pub struct PromptProxy;
+
+impl PromptProxy {
+	// Shows any of the popups and waits for the user, `None` if they canceled it
+	pub async fn show(prompt: impl Into<Prompt>) -> Option<Answer> {
+		match prompt.into() {
+			Prompt::Input(cfg) => {
+				let mut rx = InputProxy::show(cfg);
+				while let Some(result) = rx.recv().await {
+					match result {
+						Ok(s) => return Some(Answer::Input(s)),
+						Err(InputError::Canceled(_)) => return None,
+						Err(_) => {}
+					}
+				}
+				None
+			}
+			Prompt::Select(cfg) => SelectProxy::show(cfg).await.ok().map(Answer::Select),
+			Prompt::Confirm(cfg) => ConfirmProxy::show_rx(*cfg).await.ok().map(Answer::Confirm),
+		}
+	}
+}
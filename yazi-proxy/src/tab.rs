@@ -15,6 +15,16 @@ impl TabProxy {
 		emit!(Call(Cmd::args("reveal", &[target]), Layer::Manager));
 	}
 
+	#[inline]
+	pub fn sort(by: &str, reverse: bool) {
+		emit!(Call(Cmd::args("sort", &[by]).with_bool("reverse", reverse), Layer::Manager));
+	}
+
+	#[inline]
+	pub fn linemode(mode: &str) {
+		emit!(Call(Cmd::args("linemode", &[mode]), Layer::Manager));
+	}
+
 	#[inline]
 	pub fn search_do(opt: SearchOpt) {
 		emit!(Call(
@@ -0,0 +1,75 @@
+use std::{fs::Metadata, str::FromStr};
+
+use anyhow::bail;
+use tokio::{fs, io};
+use yazi_shared::{event::Cmd, fs::{Url, unique_name, unique_name_paren}};
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Conflict {
+	// Append a `_1`-style suffix to the destination
+	#[default]
+	Unique,
+	Overwrite,
+	Skip,
+	// Append a ` (1)`-style suffix to the destination
+	Rename,
+	// Overwrite only if the source is newer than the destination
+	Newer,
+}
+
+impl FromStr for Conflict {
+	type Err = anyhow::Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Ok(match s {
+			"unique" => Self::Unique,
+			"overwrite" => Self::Overwrite,
+			"skip" => Self::Skip,
+			"rename" => Self::Rename,
+			"newer" => Self::Newer,
+			_ => bail!("invalid conflict policy: {s}"),
+		})
+	}
+}
+
+// `--force` is short for `--conflict=overwrite`
+impl TryFrom<&Cmd> for Conflict {
+	type Error = anyhow::Error;
+
+	fn try_from(c: &Cmd) -> Result<Self, Self::Error> {
+		if c.bool("force") {
+			return Ok(Self::Overwrite);
+		}
+		c.str("conflict").map_or(Ok(Self::default()), Self::from_str)
+	}
+}
+
+impl Conflict {
+	// Resolve the top-level destination of a task.
+	pub async fn resolve(self, to: Url) -> io::Result<Url> {
+		match self {
+			Self::Unique => unique_name(to).await,
+			Self::Rename => unique_name_paren(to).await,
+			Self::Overwrite | Self::Skip | Self::Newer => Ok(to),
+		}
+	}
+
+	// Whether the file can be written to the destination, directories are merged.
+	pub async fn admit(self, meta: &Metadata, to: &Url) -> bool {
+		let Ok(dest) = fs::symlink_metadata(to).await else {
+			return true;
+		};
+
+		match self {
+			Self::Unique | Self::Rename | Self::Overwrite => true,
+			Self::Skip => false,
+			Self::Newer => match (meta.modified(), dest.modified()) {
+				(Ok(a), Ok(b)) => a > b,
+				_ => false,
+			},
+		}
+	}
+
+	#[inline]
+	pub fn merging(self) -> bool { matches!(self, Self::Skip | Self::Newer) }
+}
@@ -1,17 +1,18 @@
-use std::{borrow::Cow, collections::VecDeque, fs::Metadata, path::{Path, PathBuf}};
+use std::{borrow::Cow, collections::VecDeque, fs::Metadata, path::{Path, PathBuf}, sync::Arc, time::Duration};
 
-use anyhow::{Result, anyhow};
-use tokio::{fs, io::{self, ErrorKind::{AlreadyExists, NotFound}}, sync::mpsc};
+use anyhow::{Result, anyhow, bail};
+use tokio::{fs, io::{self, AsyncWriteExt, ErrorKind::{AlreadyExists, NotFound}}, sync::mpsc, time::sleep};
 use tracing::warn;
 use yazi_config::TASKS;
-use yazi_shared::fs::{Url, calculate_size, copy_with_progress, maybe_exists, ok_or_not_found, path_relative_to};
+use yazi_shared::{LcgRng, RateLimiter, fs::{Url, calculate_size, canonicalize, copy_with_progress, maybe_exists, ok_or_not_found, path_relative_to}};
 
-use super::{FileOp, FileOpDelete, FileOpHardlink, FileOpLink, FileOpPaste, FileOpTrash};
+use super::{FileOp, FileOpDelete, FileOpHardlink, FileOpLink, FileOpPaste, FileOpRestore, FileOpTrash};
 use crate::{LOW, NORMAL, TaskOp, TaskProg};
 
 pub struct File {
-	macro_: async_priority_channel::Sender<TaskOp, u8>,
-	prog:   mpsc::UnboundedSender<TaskProg>,
+	macro_:  async_priority_channel::Sender<TaskOp, u8>,
+	prog:    mpsc::UnboundedSender<TaskProg>,
+	limiter: Option<Arc<RateLimiter>>,
 }
 
 impl File {
@@ -19,24 +20,52 @@ impl File {
 		macro_: async_priority_channel::Sender<TaskOp, u8>,
 		prog: mpsc::UnboundedSender<TaskProg>,
 	) -> Self {
-		Self { macro_, prog }
+		let limiter = (TASKS.copy_limit > 0).then(|| Arc::new(RateLimiter::new(TASKS.copy_limit)));
+		Self { macro_, prog, limiter }
 	}
 
 	pub async fn work(&self, op: FileOp) -> Result<()> {
+		// Bytes reported so far, so a retried attempt doesn't count them twice
+		let mut reported = 0;
+		let mut attempt = 0;
+		loop {
+			match self.work_once(op.clone(), &mut reported).await {
+				Err(e) if attempt < TASKS.transient_retry && Self::is_transient(&e) => {
+					attempt += 1;
+					let max = TASKS.transient_retry;
+					self.log(op.id(), format!("Retry {attempt}/{max} after a transient error: {e}"))?;
+					sleep(Duration::from_millis(250u64.saturating_mul(1 << attempt.min(10)))).await;
+				}
+				result => return result.map_err(|e| e.context(format!("{:?}", op.source()))),
+			}
+		}
+	}
+
+	async fn work_once(&self, op: FileOp, reported: &mut u64) -> Result<()> {
 		match op {
 			FileOp::Paste(mut task) => {
 				ok_or_not_found(fs::remove_file(&task.to).await)?;
-				let mut it = copy_with_progress(&task.from, &task.to, task.meta.as_ref().unwrap());
-
+				let mut it = copy_with_progress(
+					&task.from,
+					&task.to,
+					task.meta.as_ref().unwrap(),
+					task.limiter.clone().or_else(|| self.limiter.clone()),
+				);
+
+				let mut copied = 0;
 				while let Some(res) = it.recv().await {
 					match res {
 						Ok(0) => {
 							if task.cut {
+								Self::verify(&task).await?;
 								fs::remove_file(&task.from).await.ok();
 							}
 							break;
 						}
-						Ok(n) => self.prog.send(TaskProg::Adv(task.id, 0, n))?,
+						Ok(n) => {
+							copied += n;
+							self.advance(task.id, copied, reported)?;
+						}
 						Err(e) if e.kind() == NotFound => {
 							warn!("Paste task partially done: {task:?}");
 							break;
@@ -74,7 +103,7 @@ impl File {
 				};
 
 				let src = if task.relative {
-					path_relative_to(&src, &fs::canonicalize(task.to.parent().unwrap()).await?)
+					path_relative_to(&src, &canonicalize(task.to.parent().unwrap()).await?)
 				} else {
 					src
 				};
@@ -102,7 +131,7 @@ impl File {
 				let meta = task.meta.as_ref().unwrap();
 				let src = if !task.follow {
 					Cow::Borrowed(task.from.as_path())
-				} else if let Ok(p) = fs::canonicalize(&task.from).await {
+				} else if let Ok(p) = canonicalize(&task.from).await {
 					Cow::Owned(p)
 				} else {
 					Cow::Borrowed(task.from.as_path())
@@ -119,13 +148,16 @@ impl File {
 				self.prog.send(TaskProg::Adv(task.id, 1, meta.len()))?;
 			}
 			FileOp::Delete(task) => {
+				if task.shred {
+					self.shred(&task, reported).await?;
+				}
 				if let Err(e) = fs::remove_file(&task.target).await {
 					if e.kind() != NotFound && maybe_exists(&task.target).await {
 						self.fail(task.id, format!("Delete task failed: {:?}, {e}", task))?;
 						Err(e)?
 					}
 				}
-				self.prog.send(TaskProg::Adv(task.id, 1, task.length))?
+				self.prog.send(TaskProg::Adv(task.id, 1, if task.shred { 0 } else { task.length }))?
 			}
 			FileOp::Trash(task) => {
 				tokio::task::spawn_blocking(move || {
@@ -150,8 +182,12 @@ impl File {
 	}
 
 	pub async fn paste(&self, mut task: FileOpPaste) -> Result<()> {
-		if task.cut && ok_or_not_found(fs::rename(&task.from, &task.to).await).is_ok() {
-			return self.succ(task.id);
+		if task.cut && !(task.conflict.merging() && maybe_exists(&task.to).await) {
+			match ok_or_not_found(fs::rename(&task.from, &task.to).await) {
+				Ok(()) => return self.succ(task.id),
+				// e.g. Cross-device link (os error 18), fall back to copy-then-delete
+				Err(e) => self.log(task.id, format!("Cannot rename, copying instead: {e}"))?,
+			}
 		}
 
 		if task.meta.is_none() {
@@ -161,8 +197,12 @@ impl File {
 		let meta = task.meta.as_ref().unwrap();
 		if !meta.is_dir() {
 			let id = task.id;
-			self.prog.send(TaskProg::New(id, meta.len()))?;
+			if !task.conflict.admit(meta, &task.to).await {
+				self.log(id, format!("Skipped due to conflict: {:?}", task.to))?;
+				return self.succ(id);
+			}
 
+			self.prog.send(TaskProg::New(id, meta.len()))?;
 			if meta.is_file() {
 				self.queue(FileOp::Paste(task), LOW).await?;
 			} else if meta.is_symlink() {
@@ -206,8 +246,12 @@ impl File {
 				}
 
 				let to = dest.join(from.file_name().unwrap());
-				self.prog.send(TaskProg::New(task.id, meta.len()))?;
+				if !task.conflict.admit(&meta, &to).await {
+					self.log(task.id, format!("Skipped due to conflict: {to:?}"))?;
+					continue;
+				}
 
+				self.prog.send(TaskProg::New(task.id, meta.len()))?;
 				if meta.is_file() {
 					self.queue(FileOp::Paste(task.spawn(from, to, meta)), LOW).await?;
 				} else if meta.is_symlink() {
@@ -315,6 +359,37 @@ impl File {
 		self.succ(task.id)
 	}
 
+	// Overwrite the file with pseudo-random data in place, symlinks are left intact
+	// to avoid destroying their targets.
+	async fn shred(&self, task: &FileOpDelete, reported: &mut u64) -> Result<()> {
+		match fs::symlink_metadata(&task.target).await {
+			Ok(m) if m.is_file() => {}
+			Ok(_) => return Ok(self.prog.send(TaskProg::Adv(task.id, 0, task.length))?),
+			Err(e) if e.kind() == NotFound => return Ok(()),
+			Err(e) => Err(e)?,
+		}
+
+		let mut rng = LcgRng::default();
+		let mut buf = vec![0u8; 64 * 1024];
+		let mut file = fs::OpenOptions::new().write(true).open(&task.target).await?;
+
+		let (mut left, mut written) = (task.length, 0);
+		while left > 0 {
+			for (chunk, n) in buf.chunks_exact_mut(8).zip(&mut rng) {
+				chunk.copy_from_slice(&n.to_le_bytes());
+			}
+
+			let n = left.min(buf.len() as u64);
+			file.write_all(&buf[..n as usize]).await?;
+			written += n;
+			self.advance(task.id, written, reported)?;
+			left -= n;
+		}
+
+		file.sync_all().await?;
+		Ok(())
+	}
+
 	pub async fn trash(&self, mut task: FileOpTrash) -> Result<()> {
 		let id = task.id;
 		task.length = calculate_size(&task.target).await;
@@ -324,6 +399,100 @@ impl File {
 		self.succ(id)
 	}
 
+	async fn verify(task: &FileOpPaste) -> Result<()> {
+		let expected = task.meta.as_ref().map_or(0, |m| m.len());
+		let actual = fs::symlink_metadata(&task.to).await?.len();
+		if actual != expected {
+			bail!("Size mismatch after copying, keeping the source: {actual} != {expected}");
+		}
+		Ok(())
+	}
+
+	pub async fn restore(&self, task: FileOpRestore) -> Result<()> {
+		self.prog.send(TaskProg::New(task.id, 0))?;
+		if maybe_exists(&task.target).await {
+			bail!("Cannot restore {:?}, the path already exists", task.target);
+		}
+
+		let target = task.target.clone();
+		tokio::task::spawn_blocking(move || Self::restore_from_trash(&target)).await??;
+
+		self.prog.send(TaskProg::Adv(task.id, 1, 0))?;
+		self.succ(task.id)
+	}
+
+	#[cfg(any(windows, all(unix, not(target_os = "macos"), not(target_os = "android"))))]
+	fn restore_from_trash(target: &Path) -> Result<()> {
+		let item = trash::os_limited::list()?
+			.into_iter()
+			.filter(|item| item.original_path() == target)
+			.max_by_key(|item| item.time_deleted);
+
+		match item {
+			Some(item) => Ok(trash::os_limited::restore_all([item])?),
+			None => bail!("Cannot find {target:?} in the trash"),
+		}
+	}
+
+	#[cfg(any(target_os = "macos", target_os = "android"))]
+	fn restore_from_trash(target: &Path) -> Result<()> {
+		bail!("Restoring {target:?} from the trash is not supported on this platform")
+	}
+
+	// Newest first, with only the latest one of those deleted from the same path
+	#[cfg(any(windows, all(unix, not(target_os = "macos"), not(target_os = "android"))))]
+	pub(crate) fn trashed() -> Result<Vec<Url>> {
+		let mut items = trash::os_limited::list()?;
+		items.sort_unstable_by_key(|i| std::cmp::Reverse(i.time_deleted));
+
+		let mut seen = std::collections::HashSet::new();
+		Ok(
+			items
+				.into_iter()
+				.map(|item| item.original_path())
+				.filter(|p| seen.insert(p.clone()))
+				.map(Url::from)
+				.collect(),
+		)
+	}
+
+	#[cfg(any(target_os = "macos", target_os = "android"))]
+	pub(crate) fn trashed() -> Result<Vec<Url>> {
+		bail!("Listing the trash is not supported on this platform")
+	}
+
+	// Report the bytes `done` by this attempt, minus those already reported by earlier ones
+	#[inline]
+	fn advance(&self, id: usize, done: u64, reported: &mut u64) -> Result<()> {
+		if done > *reported {
+			self.prog.send(TaskProg::Adv(id, 0, done - *reported))?;
+			*reported = done;
+		}
+		Ok(())
+	}
+
+	fn is_transient(e: &anyhow::Error) -> bool {
+		let Some(e) = e.downcast_ref::<io::Error>() else {
+			return false;
+		};
+		if matches!(
+			e.kind(),
+			io::ErrorKind::Interrupted | io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut
+		) {
+			return true;
+		}
+
+		#[cfg(unix)]
+		{
+			matches!(e.raw_os_error(), Some(libc::EBUSY | libc::EIO))
+		}
+		#[cfg(windows)]
+		{
+			// ERROR_SHARING_VIOLATION, ERROR_LOCK_VIOLATION
+			matches!(e.raw_os_error(), Some(32 | 33))
+		}
+	}
+
 	#[inline]
 	async fn metadata(path: &Path, follow: bool) -> io::Result<Metadata> {
 		if !follow {
@@ -1,8 +1,10 @@
-use std::fs::Metadata;
+use std::{fs::Metadata, sync::Arc};
 
-use yazi_shared::fs::Url;
+use yazi_shared::{RateLimiter, fs::Url};
 
-#[derive(Debug)]
+use crate::Conflict;
+
+#[derive(Clone, Debug)]
 pub enum FileOp {
 	Paste(FileOpPaste),
 	Link(FileOpLink),
@@ -21,18 +23,30 @@ impl FileOp {
 			Self::Trash(op) => op.id,
 		}
 	}
+
+	pub fn source(&self) -> &Url {
+		match self {
+			Self::Paste(op) => &op.from,
+			Self::Link(op) => &op.from,
+			Self::Hardlink(op) => &op.from,
+			Self::Delete(op) => &op.target,
+			Self::Trash(op) => &op.target,
+		}
+	}
 }
 
 // --- Paste
 #[derive(Clone, Debug)]
 pub struct FileOpPaste {
-	pub id:     usize,
-	pub from:   Url,
-	pub to:     Url,
-	pub meta:   Option<Metadata>,
-	pub cut:    bool,
-	pub follow: bool,
-	pub retry:  u8,
+	pub id:       usize,
+	pub from:     Url,
+	pub to:       Url,
+	pub meta:     Option<Metadata>,
+	pub cut:      bool,
+	pub follow:   bool,
+	pub conflict: Conflict,
+	pub limiter:  Option<Arc<RateLimiter>>,
+	pub retry:    u8,
 }
 
 impl FileOpPaste {
@@ -44,6 +58,8 @@ impl FileOpPaste {
 			meta: Some(meta),
 			cut: self.cut,
 			follow: self.follow,
+			conflict: self.conflict,
+			limiter: self.limiter.clone(),
 			retry: self.retry,
 		}
 	}
@@ -97,6 +113,7 @@ pub struct FileOpDelete {
 	pub id:     usize,
 	pub target: Url,
 	pub length: u64,
+	pub shred:  bool,
 }
 
 // --- Trash
@@ -106,3 +123,10 @@ pub struct FileOpTrash {
 	pub target: Url,
 	pub length: u64,
 }
+
+// --- Restore
+#[derive(Clone, Debug)]
+pub struct FileOpRestore {
+	pub id:     usize,
+	pub target: Url,
+}
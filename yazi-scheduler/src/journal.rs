@@ -0,0 +1,107 @@
+use std::collections::VecDeque;
+
+use parking_lot::Mutex;
+use yazi_shared::fs::Url;
+
+pub static JOURNAL: Mutex<Journal> = Mutex::new(Journal::new());
+
+// The maximum number of batches kept in the journal
+const LIMIT: usize = 50;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum JournalOp {
+	Move { from: Url, to: Url },
+	Trash { target: Url },
+}
+
+#[derive(Debug, Default)]
+pub struct Journal {
+	incr:    usize,
+	batches: VecDeque<(usize, Vec<JournalOp>)>,
+}
+
+impl Journal {
+	const fn new() -> Self { Self { incr: 0, batches: VecDeque::new() } }
+
+	pub fn begin(&mut self) -> usize {
+		if self.batches.len() >= LIMIT {
+			self.batches.pop_front();
+		}
+
+		self.incr += 1;
+		self.batches.push_back((self.incr, vec![]));
+		self.incr
+	}
+
+	pub fn push(&mut self, batch: usize, op: JournalOp) {
+		if let Some((_, ops)) = self.batches.iter_mut().rfind(|(id, _)| *id == batch) {
+			ops.push(op);
+		}
+	}
+
+	pub fn record(&mut self, ops: Vec<JournalOp>) {
+		if !ops.is_empty() {
+			self.begin();
+			self.batches.back_mut().unwrap().1 = ops;
+		}
+	}
+
+	pub fn pop(&mut self) -> Option<Vec<JournalOp>> {
+		while let Some((_, ops)) = self.batches.pop_back() {
+			if !ops.is_empty() {
+				return Some(ops);
+			}
+		}
+		None
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn mv(from: &str, to: &str) -> JournalOp { JournalOp::Move { from: from.into(), to: to.into() } }
+
+	#[test]
+	fn test_undo_order() {
+		let mut j = Journal::new();
+		let a = j.begin();
+		let b = j.begin();
+		j.push(a, mv("/a", "/x/a"));
+		j.push(b, JournalOp::Trash { target: "/b".into() });
+		j.push(a, mv("/c", "/x/c"));
+
+		// The most recent batch first, and tasks finishing out of order still land in their own
+		assert_eq!(j.pop(), Some(vec![JournalOp::Trash { target: "/b".into() }]));
+		assert_eq!(j.pop(), Some(vec![mv("/a", "/x/a"), mv("/c", "/x/c")]));
+		assert_eq!(j.pop(), None);
+	}
+
+	#[test]
+	fn test_skip_empty() {
+		let mut j = Journal::new();
+		j.record(vec![mv("/a", "/b")]);
+		j.begin();
+		j.record(vec![]);
+
+		// A batch whose tasks all failed has nothing to undo
+		assert_eq!(j.pop(), Some(vec![mv("/a", "/b")]));
+		assert_eq!(j.pop(), None);
+	}
+
+	#[test]
+	fn test_limit() {
+		let mut j = Journal::new();
+		let first = j.begin();
+		for i in 0..LIMIT {
+			j.record(vec![mv(&format!("/{i}"), "/x")]);
+		}
+
+		// The oldest batch is gone, so late pushes to it are dropped
+		j.push(first, mv("/late", "/x"));
+		for i in (0..LIMIT).rev() {
+			assert_eq!(j.pop(), Some(vec![mv(&format!("/{i}"), "/x")]));
+		}
+		assert_eq!(j.pop(), None);
+	}
+}
@@ -1,16 +1,23 @@
 #![allow(clippy::option_map_unit_fn, clippy::unit_arg)]
 
+mod conflict;
 mod file;
+mod journal;
+mod notify;
 mod ongoing;
 mod op;
+mod pending;
 mod plugin;
 mod prework;
 mod process;
 mod scheduler;
 mod task;
 
+pub use conflict::*;
+pub use journal::*;
 pub use ongoing::*;
 pub use op::*;
+pub use pending::*;
 pub use scheduler::*;
 pub use task::*;
 
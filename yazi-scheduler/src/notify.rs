@@ -0,0 +1,89 @@
+use std::time::Duration;
+
+use yazi_config::{LOCALE, TASKS};
+use yazi_proxy::{AppProxy, options::{NotifyLevel, NotifyOpt}};
+
+use crate::{PENDING, Task, TaskStage};
+
+impl Task {
+	pub(super) fn notify(&self) {
+		if !self.notable() {
+			return;
+		}
+
+		self.alert(&LOCALE.task_finished, &self.name);
+		AppProxy::notify(NotifyOpt {
+			title:   LOCALE.task_finished.clone(),
+			content: self.name.clone(),
+			level:   NotifyLevel::Info,
+			timeout: Duration::from_secs(5),
+		});
+	}
+
+	// Once everything in a task has either succeeded or failed, sum up the failures,
+	// listing the failed items so they can be retried
+	pub(super) fn summarize(&self) {
+		if self.fail == 0 || self.stage != TaskStage::Dispatched || self.succ + self.fail < self.total {
+			return;
+		}
+
+		let mut content = LOCALE
+			.task_failures_content
+			.replace("{fail}", &self.fail.to_string())
+			.replace("{total}", &self.total.to_string());
+		content.push('\n');
+		for line in self.logs.lines().filter(|l| !l.starts_with("Retry ")).take(10) {
+			content.push_str(line);
+			content.push('\n');
+		}
+
+		if self.notable() {
+			self.alert(&LOCALE.task_failures, &format!("{}\n\n{content}", self.name));
+		}
+		AppProxy::notify(NotifyOpt {
+			title: LOCALE.task_failures.clone(),
+			content,
+			level: NotifyLevel::Error,
+			timeout: Duration::from_secs(10),
+		});
+	}
+
+	// Long enough, and of a kind the user wants to hear about
+	fn notable(&self) -> bool {
+		self.started.elapsed() >= Duration::from_secs(TASKS.notify_after)
+			&& TASKS.notify_kinds.iter().any(|k| k == self.kind.as_str())
+	}
+
+	// Get the user's attention outside of the toast, on the desktop or with the bell
+	fn alert(&self, title: &str, content: &str) {
+		if TASKS.notify_desktop {
+			Self::notify_desktop(title, content);
+		}
+		if TASKS.notify_bell {
+			AppProxy::bell(PENDING.lock().changed_dir(self.id).as_ref());
+		}
+	}
+
+	#[cfg(unix)]
+	fn notify_desktop(title: &str, content: &str) {
+		use std::process::Stdio;
+
+		#[cfg(not(target_os = "macos"))]
+		let mut cmd = {
+			let mut cmd = tokio::process::Command::new("notify-send");
+			cmd.args(["--app-name=yazi", title, content]);
+			cmd
+		};
+		#[cfg(target_os = "macos")]
+		let mut cmd = {
+			let mut cmd = tokio::process::Command::new("osascript");
+			cmd.args(["-e", &format!("display notification {content:?} with title {title:?}")]);
+			cmd
+		};
+
+		cmd.stdin(Stdio::null()).stdout(Stdio::null()).stderr(Stdio::null()).spawn().ok();
+	}
+
+	#[cfg(windows)]
+	fn notify_desktop(_: &str, _: &str) {}
+}
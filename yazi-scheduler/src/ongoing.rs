@@ -2,9 +2,11 @@ use std::collections::HashMap;
 
 use futures::future::BoxFuture;
 use yazi_config::TASKS;
+use yazi_dds::Pubsub;
+use yazi_proxy::ManagerProxy;
 
 use super::{Task, TaskStage};
-use crate::TaskKind;
+use crate::{PENDING, TaskKind};
 
 #[derive(Default)]
 pub struct Ongoing {
@@ -66,6 +68,11 @@ impl Ongoing {
 					if task.succ < task.total {
 						return None;
 					}
+					task.notify();
+					if task.kind == TaskKind::User {
+						Pubsub::pub_from_task(task.id, &task.name);
+						ManagerProxy::hook("task");
+					}
 					if let Some(hook) = self.hooks.remove(&id) {
 						return Some(hook(false));
 					}
@@ -73,6 +80,7 @@ impl Ongoing {
 				TaskStage::Hooked => {}
 			}
 
+			PENDING.lock().remove(id);
 			self.all.remove(&id);
 		}
 		None
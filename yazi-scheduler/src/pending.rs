@@ -0,0 +1,133 @@
+use std::{collections::BTreeMap, fmt::{self, Display}, mem, path::PathBuf, time::Duration};
+
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use tokio::{fs, time::sleep};
+use yazi_shared::{Xdg, fs::Url, process_alive};
+
+pub static PENDING: Mutex<Pending> = Mutex::new(Pending::new());
+
+// File operations that haven't finished yet, persisted so they can be resumed
+// after yazi is closed or crashes in the middle of them
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum PendingOp {
+	Cut { from: Url, to: Url },
+	Copy { from: Url, to: Url, follow: bool },
+	Delete { target: Url, shred: bool },
+	Trash { target: Url },
+}
+
+impl Display for PendingOp {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Cut { from, to } => write!(f, "Cut {from} to {to}"),
+			Self::Copy { from, to, .. } => write!(f, "Copy {from} to {to}"),
+			Self::Delete { target, shred: false } => write!(f, "Delete {target}"),
+			Self::Delete { target, shred: true } => write!(f, "Shred {target}"),
+			Self::Trash { target } => write!(f, "Trash {target}"),
+		}
+	}
+}
+
+#[derive(Debug, Default)]
+pub struct Pending {
+	ops:   BTreeMap<usize, PendingOp>,
+	dirty: bool,
+}
+
+impl Pending {
+	const fn new() -> Self { Self { ops: BTreeMap::new(), dirty: false } }
+
+	pub fn insert(&mut self, id: usize, op: PendingOp) {
+		self.ops.insert(id, op);
+		self.schedule();
+	}
+
+	pub fn remove(&mut self, id: usize) {
+		if self.ops.remove(&id).is_some() {
+			self.schedule();
+		}
+	}
+
+	// Destination of a pending cut or copy
+	pub fn target(&self, id: usize) -> Option<Url> {
+		match self.ops.get(&id)? {
+			PendingOp::Cut { to, .. } | PendingOp::Copy { to, .. } => Some(to.clone()),
+			PendingOp::Delete { .. } | PendingOp::Trash { .. } => None,
+		}
+	}
+
+	// The directory an operation changes
+	pub fn changed_dir(&self, id: usize) -> Option<Url> {
+		match self.ops.get(&id)? {
+			PendingOp::Cut { to, .. } | PendingOp::Copy { to, .. } => to.parent_url(),
+			PendingOp::Delete { target, .. } | PendingOp::Trash { target } => target.parent_url(),
+		}
+	}
+
+	// Take the operations left over by instances that are no longer running,
+	// each instance has its own file so a running one is never interfered with
+	pub fn take() -> Vec<PendingOp> {
+		let Ok(it) = std::fs::read_dir(Self::dir()) else { return vec![] };
+
+		let mut ops = vec![];
+		for entry in it.flatten() {
+			let path = entry.path();
+			if path.extension().and_then(|e| e.to_str()) != Some("json") {
+				continue;
+			}
+
+			let Some(pid) = path.file_stem().and_then(|s| s.to_str()?.parse().ok()) else { continue };
+			if pid == std::process::id() || process_alive(pid) {
+				continue;
+			}
+
+			// Claim it first, so that two instances starting together don't both resume it
+			let claimed = path.with_extension(format!("claimed-{}", std::process::id()));
+			if std::fs::rename(&path, &claimed).is_err() {
+				continue;
+			}
+
+			if let Ok(s) = std::fs::read_to_string(&claimed) {
+				ops.extend(serde_json::from_str::<Vec<PendingOp>>(&s).unwrap_or_default());
+			}
+			std::fs::remove_file(claimed).ok();
+		}
+		ops
+	}
+
+	#[inline]
+	fn dir() -> PathBuf { Xdg::state_dir().join("tasks") }
+
+	#[inline]
+	fn path() -> PathBuf { Self::dir().join(format!("{}.json", std::process::id())) }
+
+	// Coalesce the writes, since a single paste can add thousands of tasks
+	fn schedule(&mut self) {
+		if mem::replace(&mut self.dirty, true) {
+			return;
+		}
+
+		tokio::spawn(async {
+			sleep(Duration::from_millis(500)).await;
+			let json = {
+				let mut pending = PENDING.lock();
+				pending.dirty = false;
+				if pending.ops.is_empty() {
+					None
+				} else {
+					serde_json::to_string(&pending.ops.values().collect::<Vec<_>>()).ok()
+				}
+			};
+
+			match json {
+				Some(s) => {
+					fs::create_dir_all(Self::dir()).await.ok();
+					fs::write(Self::path(), s).await.ok()
+				}
+				None => fs::remove_file(Self::path()).await.ok(),
+			};
+		});
+	}
+}
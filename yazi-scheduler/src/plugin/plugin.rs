@@ -1,27 +1,34 @@
+use std::sync::Arc;
+
 use anyhow::{Result, anyhow};
+use parking_lot::Mutex;
 use tokio::sync::mpsc;
 use yazi_plugin::isolate;
 
 use super::{PluginOp, PluginOpEntry};
-use crate::{HIGH, TaskOp, TaskProg};
+use crate::{HIGH, Ongoing, TaskOp, TaskProg};
 
 pub struct Plugin {
-	macro_: async_priority_channel::Sender<TaskOp, u8>,
-	prog:   mpsc::UnboundedSender<TaskProg>,
+	macro_:  async_priority_channel::Sender<TaskOp, u8>,
+	prog:    mpsc::UnboundedSender<TaskProg>,
+	ongoing: Arc<Mutex<Ongoing>>,
 }
 
 impl Plugin {
 	pub fn new(
 		macro_: async_priority_channel::Sender<TaskOp, u8>,
 		prog: mpsc::UnboundedSender<TaskProg>,
+		ongoing: Arc<Mutex<Ongoing>>,
 	) -> Self {
-		Self { macro_, prog }
+		Self { macro_, prog, ongoing }
 	}
 
 	pub async fn work(&self, op: PluginOp) -> Result<()> {
 		match op {
 			PluginOp::Entry(task) => {
-				isolate::entry(task.name, task.args).await?;
+				let (id, handle) = (task.id, self.handle(task.id));
+				isolate::entry(task.name, task.args, Some(handle)).await?;
+				self.prog.send(TaskProg::Adv(id, 1, 0))?;
 			}
 		}
 		Ok(())
@@ -30,7 +37,8 @@ impl Plugin {
 	pub async fn micro(&self, task: PluginOpEntry) -> Result<()> {
 		self.prog.send(TaskProg::New(task.id, 0))?;
 
-		if let Err(e) = isolate::entry(task.name, task.args).await {
+		let handle = self.handle(task.id);
+		if let Err(e) = isolate::entry(task.name, task.args, Some(handle)).await {
 			self.fail(task.id, format!("Micro plugin failed:\n{e}"))?;
 			return Err(e.into());
 		}
@@ -49,6 +57,17 @@ impl Plugin {
 }
 
 impl Plugin {
+	fn handle(&self, id: usize) -> isolate::Task {
+		let (prog, ongoing) = (self.prog.clone(), self.ongoing.clone());
+		isolate::Task {
+			id,
+			progress: Box::new(move |done, total| {
+				prog.send(TaskProg::Prog(id, done, total.max(done))).ok();
+			}),
+			canceled: Box::new(move || !ongoing.lock().exists(id)),
+		}
+	}
+
 	#[inline]
 	fn succ(&self, id: usize) -> Result<()> { Ok(self.prog.send(TaskProg::Succ(id))?) }
 
@@ -4,13 +4,14 @@ use anyhow::Result;
 use futures::{FutureExt, future::BoxFuture};
 use parking_lot::Mutex;
 use tokio::{fs, select, sync::{mpsc::{self, UnboundedReceiver}, oneshot}, task::JoinHandle};
-use yazi_config::{TASKS, open::Opener, plugin::{Fetcher, Preloader}};
+use tracing::warn;
+use yazi_config::{LOCALE, TASKS, open::Opener, plugin::{Fetcher, Preloader}};
 use yazi_dds::Pump;
-use yazi_proxy::ManagerProxy;
-use yazi_shared::{Throttle, event::Data, fs::{Url, remove_dir_clean, unique_name}};
+use yazi_proxy::{ManagerProxy, TabProxy};
+use yazi_shared::{RateLimiter, Throttle, event::Data, fs::{Url, remove_dir_clean, unique_name}};
 
 use super::{Ongoing, TaskProg, TaskStage};
-use crate::{HIGH, LOW, NORMAL, TaskKind, TaskOp, file::{File, FileOpDelete, FileOpHardlink, FileOpLink, FileOpPaste, FileOpTrash}, plugin::{Plugin, PluginOpEntry}, prework::{Prework, PreworkOpFetch, PreworkOpLoad, PreworkOpSize}, process::{Process, ProcessOpBg, ProcessOpBlock, ProcessOpOrphan}};
+use crate::{Conflict, HIGH, JOURNAL, JournalOp, LOW, NORMAL, PENDING, PendingOp, TaskKind, TaskOp, file::{File, FileOpDelete, FileOpHardlink, FileOpLink, FileOpPaste, FileOpRestore, FileOpTrash}, plugin::{Plugin, PluginOpEntry}, prework::{Prework, PreworkOpFetch, PreworkOpLoad, PreworkOpSize}, process::{Process, ProcessOpBg, ProcessOpBlock, ProcessOpOrphan, ShellOpt, shell}};
 
 pub struct Scheduler {
 	pub file:    Arc<File>,
@@ -29,17 +30,18 @@ impl Scheduler {
 		let (micro_tx, micro_rx) = async_priority_channel::unbounded();
 		let (macro_tx, macro_rx) = async_priority_channel::unbounded();
 		let (prog_tx, prog_rx) = mpsc::unbounded_channel();
+		let ongoing: Arc<Mutex<Ongoing>> = Default::default();
 
 		let mut scheduler = Self {
 			file:    Arc::new(File::new(macro_tx.clone(), prog_tx.clone())),
-			plugin:  Arc::new(Plugin::new(macro_tx.clone(), prog_tx.clone())),
+			plugin:  Arc::new(Plugin::new(macro_tx.clone(), prog_tx.clone(), ongoing.clone())),
 			prework: Arc::new(Prework::new(macro_tx.clone(), prog_tx.clone())),
 			process: Arc::new(Process::new(prog_tx.clone())),
 
 			micro:   micro_tx,
 			prog:    prog_tx,
 			handles: Vec::with_capacity(TASKS.micro_workers as usize + TASKS.macro_workers as usize + 1),
-			ongoing: Default::default(),
+			ongoing,
 		};
 
 		for _ in 0..TASKS.micro_workers {
@@ -60,6 +62,7 @@ impl Scheduler {
 			return false;
 		}
 
+		PENDING.lock().remove(id);
 		ongoing.all.remove(&id).is_some()
 	}
 
@@ -69,12 +72,20 @@ impl Scheduler {
 		}
 	}
 
-	pub fn file_cut(&self, from: Url, mut to: Url, force: bool) {
+	pub fn file_cut(
+		&self,
+		from: Url,
+		mut to: Url,
+		conflict: Conflict,
+		limit: Option<u64>,
+		journal: Option<usize>,
+		reveal: bool,
+	) {
 		let mut ongoing = self.ongoing.lock();
-		let id = ongoing.add(TaskKind::User, format!("Cut {:?} to {:?}", from, to));
+		let id = ongoing.add(TaskKind::User, Self::pair_name(&LOCALE.task_cut, &from, &to));
 
 		if to.starts_with(&from) && to != from {
-			self.new_and_fail(id, "Cannot cut directory into itself").ok();
+			self.new_and_fail(id, &LOCALE.fail_cut_into_itself).ok();
 			return;
 		}
 
@@ -85,7 +96,15 @@ impl Scheduler {
 			Box::new(move |canceled: bool| {
 				async move {
 					if !canceled {
+						let to = Self::resolved_target(id, to);
+						if let Some(batch) = journal {
+							JOURNAL.lock().push(batch, JournalOp::Move { from: from.clone(), to: to.clone() });
+						}
 						remove_dir_clean(&from).await;
+						Self::post_hooks("cut", &[&from, &to]);
+						if reveal {
+							TabProxy::reveal(&to);
+						}
 						Pump::push_move(from, to);
 					}
 					ongoing.lock().try_remove(id, TaskStage::Hooked);
@@ -96,33 +115,82 @@ impl Scheduler {
 
 		let file = self.file.clone();
 		self.send_micro(id, LOW, async move {
-			if !force {
-				to = unique_name(to).await?;
-			}
-			file.paste(FileOpPaste { id, from, to, meta: None, cut: true, follow: false, retry: 0 }).await
+			to = conflict.resolve(to).await?;
+			PENDING.lock().insert(id, PendingOp::Cut { from: from.clone(), to: to.clone() });
+			file
+				.paste(FileOpPaste {
+					id,
+					from,
+					to,
+					meta: None,
+					cut: true,
+					follow: false,
+					conflict,
+					limiter: limit.map(|n| Arc::new(RateLimiter::new(n))),
+					retry: 0,
+				})
+				.await
 		});
 	}
 
-	pub fn file_copy(&self, from: Url, mut to: Url, force: bool, follow: bool) {
-		let name = format!("Copy {:?} to {:?}", from, to);
-		let id = self.ongoing.lock().add(TaskKind::User, name);
+	pub fn file_copy(
+		&self,
+		from: Url,
+		mut to: Url,
+		conflict: Conflict,
+		follow: bool,
+		limit: Option<u64>,
+		reveal: bool,
+	) {
+		let mut ongoing = self.ongoing.lock();
+		let id = ongoing.add(TaskKind::User, Self::pair_name(&LOCALE.task_copy, &from, &to));
 
 		if to.starts_with(&from) && to != from {
-			self.new_and_fail(id, "Cannot copy directory into itself").ok();
+			self.new_and_fail(id, &LOCALE.fail_copy_into_itself).ok();
 			return;
 		}
 
+		ongoing.hooks.insert(id, {
+			let ongoing = self.ongoing.clone();
+			let (from, to) = (from.clone(), to.clone());
+
+			Box::new(move |canceled: bool| {
+				async move {
+					if !canceled {
+						let to = Self::resolved_target(id, to);
+						Self::post_hooks("copy", &[&from, &to]);
+						if reveal {
+							TabProxy::reveal(&to);
+						}
+					}
+					ongoing.lock().try_remove(id, TaskStage::Hooked);
+				}
+				.boxed()
+			})
+		});
+
 		let file = self.file.clone();
 		self.send_micro(id, LOW, async move {
-			if !force {
-				to = unique_name(to).await?;
-			}
-			file.paste(FileOpPaste { id, from, to, meta: None, cut: false, follow, retry: 0 }).await
+			to = conflict.resolve(to).await?;
+			PENDING.lock().insert(id, PendingOp::Copy { from: from.clone(), to: to.clone(), follow });
+			file
+				.paste(FileOpPaste {
+					id,
+					from,
+					to,
+					meta: None,
+					cut: false,
+					follow,
+					conflict,
+					limiter: limit.map(|n| Arc::new(RateLimiter::new(n))),
+					retry: 0,
+				})
+				.await
 		});
 	}
 
 	pub fn file_link(&self, from: Url, mut to: Url, relative: bool, force: bool) {
-		let name = format!("Link {from:?} to {to:?}");
+		let name = Self::pair_name(&LOCALE.task_link, &from, &to);
 		let id = self.ongoing.lock().add(TaskKind::User, name);
 
 		let file = self.file.clone();
@@ -137,11 +205,11 @@ impl Scheduler {
 	}
 
 	pub fn file_hardlink(&self, from: Url, mut to: Url, force: bool, follow: bool) {
-		let name = format!("Hardlink {:?} to {:?}", from, to);
+		let name = Self::pair_name(&LOCALE.task_hardlink, &from, &to);
 		let id = self.ongoing.lock().add(TaskKind::User, name);
 
 		if to.starts_with(&from) && to != from {
-			self.new_and_fail(id, "Cannot hardlink directory into itself").ok();
+			self.new_and_fail(id, &LOCALE.fail_hardlink_into_itself).ok();
 			return;
 		}
 
@@ -154,9 +222,12 @@ impl Scheduler {
 		});
 	}
 
-	pub fn file_delete(&self, target: Url) {
+	pub fn file_delete(&self, target: Url, shred: bool) {
 		let mut ongoing = self.ongoing.lock();
-		let id = ongoing.add(TaskKind::User, format!("Delete {:?}", target));
+		let tpl = if shred { &LOCALE.task_shred } else { &LOCALE.task_delete };
+		let name = tpl.replace("{target}", &format!("{target:?}"));
+		let id = ongoing.add(TaskKind::User, name);
+		PENDING.lock().insert(id, PendingOp::Delete { target: target.clone(), shred });
 
 		ongoing.hooks.insert(id, {
 			let target = target.clone();
@@ -167,6 +238,7 @@ impl Scheduler {
 					if !canceled {
 						fs::remove_dir_all(&target).await.ok();
 						ManagerProxy::update_task(&target);
+						Self::post_hooks(if shred { "shred" } else { "delete" }, &[&target]);
 						Pump::push_delete(target);
 					}
 					ongoing.lock().try_remove(id, TaskStage::Hooked);
@@ -179,13 +251,15 @@ impl Scheduler {
 		self.send_micro(
 			id,
 			LOW,
-			async move { file.delete(FileOpDelete { id, target, length: 0 }).await },
+			async move { file.delete(FileOpDelete { id, target, length: 0, shred }).await },
 		);
 	}
 
-	pub fn file_trash(&self, target: Url) {
+	pub fn file_trash(&self, target: Url, journal: Option<usize>) {
 		let mut ongoing = self.ongoing.lock();
-		let id = ongoing.add(TaskKind::User, format!("Trash {:?}", target));
+		let name = LOCALE.task_trash.replace("{target}", &format!("{target:?}"));
+		let id = ongoing.add(TaskKind::User, name);
+		PENDING.lock().insert(id, PendingOp::Trash { target: target.clone() });
 
 		ongoing.hooks.insert(id, {
 			let target = target.clone();
@@ -194,7 +268,11 @@ impl Scheduler {
 			Box::new(move |canceled: bool| {
 				async move {
 					if !canceled {
+						if let Some(batch) = journal {
+							JOURNAL.lock().push(batch, JournalOp::Trash { target: target.clone() });
+						}
 						ManagerProxy::update_task(&target);
+						Self::post_hooks("trash", &[&target]);
 						Pump::push_trash(target);
 					}
 					ongoing.lock().try_remove(id, TaskStage::Hooked);
@@ -209,6 +287,51 @@ impl Scheduler {
 		})
 	}
 
+	pub fn file_restore(&self, target: Url) {
+		let mut ongoing = self.ongoing.lock();
+		let name = LOCALE.task_restore.replace("{target}", &format!("{target:?}"));
+		let id = ongoing.add(TaskKind::User, name);
+
+		ongoing.hooks.insert(id, {
+			let target = target.clone();
+			let ongoing = self.ongoing.clone();
+
+			Box::new(move |canceled: bool| {
+				async move {
+					if !canceled {
+						ManagerProxy::update_task(&target);
+					}
+					ongoing.lock().try_remove(id, TaskStage::Hooked);
+				}
+				.boxed()
+			})
+		});
+
+		let file = self.file.clone();
+		self.send_micro(id, LOW, async move { file.restore(FileOpRestore { id, target }).await });
+	}
+
+	pub async fn file_trashed(&self) -> Result<Vec<Url>> {
+		tokio::task::spawn_blocking(File::trashed).await?
+	}
+
+	pub fn resume(&self, ops: Vec<PendingOp>) {
+		// Destinations were already resolved when the operations were first scheduled,
+		// so write into them again instead of creating new ones
+		for op in ops {
+			match op {
+				PendingOp::Cut { from, to } => {
+					self.file_cut(from, to, Conflict::Overwrite, None, None, false)
+				}
+				PendingOp::Copy { from, to, follow } => {
+					self.file_copy(from, to, Conflict::Overwrite, follow, None, false)
+				}
+				PendingOp::Delete { target, shred } => self.file_delete(target, shred),
+				PendingOp::Trash { target } => self.file_trash(target, None),
+			}
+		}
+	}
+
 	pub fn plugin_micro(&self, name: String, args: Vec<Data>) {
 		let id = self.ongoing.lock().add(TaskKind::User, format!("Run micro plugin `{name}`"));
 
@@ -362,7 +485,7 @@ impl Scheduler {
 						};
 
 						if let Err(e) = result {
-							prog.send(TaskProg::Fail(id, format!("Failed to work on this task: {e:?}"))).ok();
+							prog.send(TaskProg::Fail(id, format!("Failed to work on this task: {e:#}"))).ok();
 						}
 					}
 				}
@@ -388,6 +511,7 @@ impl Scheduler {
 						if let Some(task) = ongoing.get_mut(id) {
 							task.succ += succ;
 							task.processed += processed;
+							task.summarize();
 						}
 						if succ > 0 {
 							if let Some(fut) = ongoing.try_remove(id, TaskStage::Pending) {
@@ -395,9 +519,18 @@ impl Scheduler {
 							}
 						}
 					}
+					TaskProg::Prog(id, processed, found) => {
+						if let Some(task) = ongoing.lock().get_mut(id) {
+							task.processed = processed;
+							task.found = found;
+						}
+					}
 					TaskProg::Succ(id) => {
-						if let Some(fut) = ongoing.lock().try_remove(id, TaskStage::Dispatched) {
+						let mut ongoing = ongoing.lock();
+						if let Some(fut) = ongoing.try_remove(id, TaskStage::Dispatched) {
 							micro.try_send(fut, LOW).ok();
+						} else if let Some(task) = ongoing.get_mut(id) {
+							task.summarize();
 						}
 					}
 					TaskProg::Fail(id, reason) => {
@@ -409,6 +542,7 @@ impl Scheduler {
 							if let Some(logger) = &task.logger {
 								logger.send(reason).ok();
 							}
+							task.summarize();
 						}
 					}
 					TaskProg::Log(id, line) => {
@@ -443,6 +577,28 @@ impl Scheduler {
 		);
 	}
 
+	fn post_hooks(action: &str, urls: &[&Url]) {
+		for hook in TASKS.hooks.iter().filter(|h| h.matches(action)) {
+			let mut args = Vec::with_capacity(urls.len() + 1);
+			args.push(OsString::new());
+			args.extend(urls.iter().map(|u| u.as_os_str().to_owned()));
+
+			let opt = ShellOpt { cmd: (&hook.run).into(), args, orphan: true, ..Default::default() };
+			if let Err(e) = shell(opt) {
+				warn!("Failed to run the post-task hook `{}`: {e}", hook.run);
+			}
+		}
+	}
+
+	// Where the task actually put its file, after any conflict was resolved
+	#[inline]
+	fn resolved_target(id: usize, to: Url) -> Url { PENDING.lock().target(id).unwrap_or(to) }
+
+	#[inline]
+	fn pair_name(tpl: &str, from: &Url, to: &Url) -> String {
+		tpl.replace("{from}", &format!("{from:?}")).replace("{to}", &format!("{to:?}"))
+	}
+
 	fn new_and_fail(&self, id: usize, reason: &str) -> Result<()> {
 		self.prog.send(TaskProg::New(id, 0))?;
 		self.prog.send(TaskProg::Fail(id, reason.to_owned()))?;
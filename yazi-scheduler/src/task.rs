@@ -1,11 +1,14 @@
+use std::time::Instant;
+
 use tokio::sync::mpsc;
 
-#[derive(Debug, Default)]
+#[derive(Debug)]
 pub struct Task {
-	pub id:    usize,
-	pub kind:  TaskKind,
-	pub name:  String,
-	pub stage: TaskStage,
+	pub id:      usize,
+	pub kind:    TaskKind,
+	pub name:    String,
+	pub stage:   TaskStage,
+	pub started: Instant,
 
 	pub total: u32,
 	pub succ:  u32,
@@ -20,7 +23,23 @@ pub struct Task {
 
 impl Task {
 	pub fn new(id: usize, kind: TaskKind, name: String) -> Self {
-		Self { id, kind, name, ..Default::default() }
+		Self {
+			id,
+			kind,
+			name,
+			stage: Default::default(),
+			started: Instant::now(),
+
+			total: 0,
+			succ: 0,
+			fail: 0,
+
+			found: 0,
+			processed: 0,
+
+			logs: Default::default(),
+			logger: None,
+		}
 	}
 }
 
@@ -31,6 +50,16 @@ pub enum TaskKind {
 	Preload,
 }
 
+impl TaskKind {
+	#[inline]
+	pub fn as_str(self) -> &'static str {
+		match self {
+			Self::User => "user",
+			Self::Preload => "preload",
+		}
+	}
+}
+
 #[derive(Debug)]
 pub struct TaskSummary {
 	pub name: String,
@@ -64,6 +93,8 @@ pub enum TaskProg {
 	New(usize, u64),
 	// id, processed, size
 	Adv(usize, u32, u64),
+	// id, processed, found
+	Prog(usize, u64, u64),
 	// id
 	Succ(usize),
 	// id
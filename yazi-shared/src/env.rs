@@ -1,6 +1,11 @@
 #[inline]
 pub fn env_exists(name: &str) -> bool { std::env::var_os(name).is_some_and(|s| !s.is_empty()) }
 
+pub fn bin_exists(bin: &str) -> bool {
+	std::env::var_os("PATH")
+		.is_some_and(|p| std::env::split_paths(&p).any(|d| d.join(bin).is_file()))
+}
+
 #[inline]
 pub fn in_wsl() -> bool {
 	#[cfg(target_os = "linux")]
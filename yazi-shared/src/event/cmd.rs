@@ -128,9 +128,14 @@ impl Display for Cmd {
 impl FromStr for Cmd {
 	type Err = anyhow::Error;
 
+	fn from_str(s: &str) -> Result<Self, Self::Err> { Self::try_from(shell_words::split(s)?) }
+}
+
+impl TryFrom<Vec<String>> for Cmd {
+	type Error = anyhow::Error;
+
 	#[allow(clippy::explicit_counter_loop)]
-	fn from_str(s: &str) -> Result<Self, Self::Err> {
-		let mut args = shell_words::split(s)?;
+	fn try_from(mut args: Vec<String>) -> Result<Self, Self::Error> {
 		if args.is_empty() || args[0].is_empty() {
 			bail!("command name cannot be empty");
 		}
@@ -61,6 +61,7 @@ impl Data {
 		match self {
 			Data::String(s) => Some(Url::from(s)),
 			Data::Url(u) => Some(u),
+			Data::Any(a) => a.downcast::<Url>().ok().map(|u| *u),
 			_ => None,
 		}
 	}
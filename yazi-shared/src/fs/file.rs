@@ -1,4 +1,4 @@
-use std::{cell::Cell, ffi::OsStr, fs::{FileType, Metadata}, ops::Deref};
+use std::{cell::RefCell, ffi::OsStr, fs::{FileType, Metadata}, ops::Deref};
 
 use anyhow::Result;
 use tokio::fs;
@@ -11,7 +11,7 @@ pub struct File {
 	pub url:     Url,
 	pub cha:     Cha,
 	pub link_to: Option<Url>,
-	pub icon:    Cell<IconCache>,
+	pub icon:    RefCell<IconCache>,
 }
 
 impl Deref for File {
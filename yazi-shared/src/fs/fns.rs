@@ -1,8 +1,10 @@
-use std::{borrow::Cow, collections::{HashMap, HashSet, VecDeque}, ffi::{OsStr, OsString}, fs::Metadata, path::{Path, PathBuf}};
+use std::{borrow::Cow, collections::{HashMap, HashSet, VecDeque}, ffi::{OsStr, OsString}, fs::Metadata, path::{Path, PathBuf}, sync::Arc};
 
 use anyhow::{Result, bail};
 use tokio::{fs, io, select, sync::{mpsc, oneshot}, time};
 
+use crate::{RateLimiter, fs::strip_verbatim};
+
 #[inline]
 pub async fn must_exists(p: impl AsRef<Path>) -> bool { fs::symlink_metadata(p).await.is_ok() }
 
@@ -76,9 +78,16 @@ async fn _paths_to_same_file(a: &Path, b: &Path) -> std::io::Result<bool> {
 	Ok(final_name(a).await? == final_name(b).await?)
 }
 
+// Same as `fs::canonicalize`, but without the `\\?\` prefix on Windows,
+// so it still compares equal to the paths the user navigated to
+#[inline]
+pub async fn canonicalize(p: impl AsRef<Path>) -> io::Result<PathBuf> {
+	fs::canonicalize(p).await.map(strip_verbatim)
+}
+
 pub async fn realname(p: &Path) -> Option<OsString> {
 	let name = p.file_name()?;
-	if p == fs::canonicalize(p).await.ok()? {
+	if p == canonicalize(p).await.ok()? {
 		return None;
 	}
 
@@ -176,6 +185,7 @@ pub fn copy_with_progress(
 	from: &Path,
 	to: &Path,
 	meta: &Metadata,
+	limiter: Option<Arc<RateLimiter>>,
 ) -> mpsc::Receiver<Result<u64, io::Error>> {
 	let (tx, rx) = mpsc::channel(1);
 	let (tick_tx, mut tick_rx) = oneshot::channel();
@@ -198,7 +208,11 @@ pub fn copy_with_progress(
 		}
 
 		async move {
-			_ = match fs::copy(&from, &to).await {
+			let result = match limiter {
+				Some(l) => copy_limited(&from, &to, &l).await,
+				None => copy(&from, &to).await,
+			};
+			_ = match result {
 				Ok(len) => {
 					_ = tokio::task::spawn_blocking(move || {
 						std::fs::File::options().write(true).open(to).and_then(|f| f.set_times(ft)).ok();
@@ -252,6 +266,114 @@ pub fn copy_with_progress(
 	rx
 }
 
+// Copy a file, trying a reflink first so that copies within the same
+// filesystem are instant and share their extents. Falls back to `fs::copy`,
+// which uses `copy_file_range(2)` on Linux and `fcopyfile(3)` on macOS.
+pub async fn copy(from: &Path, to: &Path) -> io::Result<u64> {
+	#[cfg(any(target_os = "linux", target_os = "macos"))]
+	{
+		let (from_, to_) = (from.to_owned(), to.to_owned());
+		if let Ok(Ok(len)) = tokio::task::spawn_blocking(move || reflink_probed(&from_, &to_)).await {
+			return Ok(len);
+		}
+	}
+
+	fs::copy(from, to).await
+}
+
+// Copy a file in chunks, with the throughput capped by the `limiter`.
+pub async fn copy_limited(from: &Path, to: &Path, limiter: &RateLimiter) -> io::Result<u64> {
+	use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+	let mut src = fs::File::open(from).await?;
+	let mut dest = fs::File::create(to).await?;
+
+	let mut buf = vec![0; 128 * 1024];
+	let mut total = 0;
+	loop {
+		let n = src.read(&mut buf).await?;
+		if n == 0 {
+			break;
+		}
+
+		limiter.acquire(n as u64).await;
+		dest.write_all(&buf[..n]).await?;
+		total += n as u64;
+	}
+
+	dest.flush().await?;
+	dest.set_permissions(src.metadata().await?.permissions()).await?;
+	Ok(total)
+}
+
+// Remember the pairs of filesystems that can't share extents, so only the first
+// copy between them pays for creating and removing the destination
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+fn reflink_probed(from: &Path, to: &Path) -> io::Result<u64> {
+	use std::{os::unix::fs::MetadataExt, sync::Mutex};
+
+	static UNSUPPORTED: Mutex<Vec<(u64, u64)>> = Mutex::new(Vec::new());
+
+	let dir = to.parent().filter(|p| !p.as_os_str().is_empty()).unwrap_or(Path::new("."));
+	let devs = (std::fs::metadata(from)?.dev(), std::fs::metadata(dir)?.dev());
+	if UNSUPPORTED.lock().unwrap().contains(&devs) {
+		return Err(io::ErrorKind::Unsupported.into());
+	}
+
+	let result = reflink(from, to);
+	let code = result.as_ref().err().and_then(|e| e.raw_os_error()).unwrap_or(0);
+	if [libc::EOPNOTSUPP, libc::ENOTSUP, libc::ENOTTY, libc::EXDEV, libc::EINVAL].contains(&code) {
+		UNSUPPORTED.lock().unwrap().push(devs);
+	}
+	result
+}
+
+#[cfg(target_os = "linux")]
+fn reflink(from: &Path, to: &Path) -> io::Result<u64> {
+	use std::os::{fd::AsRawFd, unix::fs::{OpenOptionsExt, PermissionsExt}};
+
+	// _IOW(0x94, 9, int)
+	const FICLONE: libc::c_ulong = 0x40049409;
+
+	let src = std::fs::File::open(from)?;
+	let meta = src.metadata()?;
+	let dest = std::fs::File::options()
+		.write(true)
+		.create_new(true)
+		.mode(meta.permissions().mode())
+		.open(to)?;
+
+	if unsafe { libc::ioctl(dest.as_raw_fd(), FICLONE as _, src.as_raw_fd()) } != 0 {
+		let e = io::Error::last_os_error();
+		drop(dest);
+		std::fs::remove_file(to).ok();
+		return Err(e);
+	}
+
+	dest.set_permissions(meta.permissions())?;
+	Ok(meta.len())
+}
+
+#[cfg(target_os = "macos")]
+fn reflink(from: &Path, to: &Path) -> io::Result<u64> {
+	use std::{ffi::CString, os::unix::ffi::OsStrExt};
+
+	extern "C" {
+		fn clonefile(src: *const libc::c_char, dst: *const libc::c_char, flags: u32) -> libc::c_int;
+	}
+
+	// Don't follow symlinks, they are handled by the link task
+	const CLONE_NOFOLLOW: u32 = 0x0001;
+
+	let src = CString::new(from.as_os_str().as_bytes())?;
+	let dst = CString::new(to.as_os_str().as_bytes())?;
+	if unsafe { clonefile(src.as_ptr(), dst.as_ptr(), CLONE_NOFOLLOW) } != 0 {
+		return Err(io::Error::last_os_error());
+	}
+
+	Ok(std::fs::symlink_metadata(to)?.len())
+}
+
 pub async fn remove_dir_clean(dir: &Path) {
 	let Ok(mut it) = fs::read_dir(dir).await else { return };
 
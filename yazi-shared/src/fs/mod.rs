@@ -4,8 +4,12 @@ mod fns;
 mod loc;
 mod op;
 mod path;
+#[cfg(target_os = "macos")]
+mod tags;
 mod url;
 mod urn;
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+mod xattr;
 
 pub use cha::*;
 pub use file::*;
@@ -13,5 +17,9 @@ pub use fns::*;
 pub use loc::*;
 pub use op::*;
 pub use path::*;
+#[cfg(target_os = "macos")]
+pub use tags::*;
 pub use url::*;
 pub use urn::*;
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+pub use xattr::*;
@@ -1,4 +1,4 @@
-use std::{borrow::Cow, env, ffi::OsString, io, path::{Component, Path, PathBuf}};
+use std::{borrow::Cow, env, ffi::{OsStr, OsString}, io, path::{Component, Path, PathBuf}};
 
 use tokio::fs;
 
@@ -10,6 +10,7 @@ pub fn current_cwd() -> Option<PathBuf> {
 		.map(PathBuf::from)
 		.filter(|p| p.is_absolute())
 		.or_else(|| env::current_dir().ok())
+		.map(strip_verbatim)
 }
 
 #[inline]
@@ -32,7 +33,37 @@ fn _clean_path(path: &Path) -> PathBuf {
 		}
 	}
 
-	if out.is_empty() { PathBuf::from(".") } else { out.iter().collect() }
+	if out.is_empty() { PathBuf::from(".") } else { strip_verbatim(out.iter().collect()) }
+}
+
+// Strip the `\\?\` prefix of Windows paths when they work without it
+pub fn strip_verbatim(p: PathBuf) -> PathBuf {
+	#[cfg(windows)]
+	{
+		use std::path::Prefix;
+
+		let mut it = p.components();
+		let Some(Component::Prefix(prefix)) = it.next() else { return p };
+
+		let mut out = match prefix.kind() {
+			Prefix::VerbatimDisk(d) => PathBuf::from(format!("{}:", d as char)),
+			Prefix::VerbatimUNC(server, share) => {
+				let mut s = OsString::from(r"\\");
+				s.push(server);
+				s.push(r"\");
+				s.push(share);
+				PathBuf::from(s)
+			}
+			_ => return p,
+		};
+
+		out.extend(it);
+		if out.as_os_str().len() < 260 {
+			return out;
+		}
+	}
+
+	p
 }
 
 #[inline]
@@ -74,7 +105,14 @@ fn _expand_path(p: &Path) -> PathBuf {
 	}
 }
 
-pub async fn unique_name(mut u: Url) -> io::Result<Url> {
+#[inline]
+pub async fn unique_name(u: Url) -> io::Result<Url> { unique_name_by(u, "_", "").await }
+
+// Same as `unique_name`, but with a ` (1)`-style suffix
+#[inline]
+pub async fn unique_name_paren(u: Url) -> io::Result<Url> { unique_name_by(u, " (", ")").await }
+
+async fn unique_name_by(mut u: Url, left: &str, right: &str) -> io::Result<Url> {
 	let Some(stem) = u.file_stem().map(|s| s.to_owned()) else {
 		return Err(io::Error::new(io::ErrorKind::InvalidInput, "empty file stem"));
 	};
@@ -98,10 +136,11 @@ pub async fn unique_name(mut u: Url) -> io::Result<Url> {
 			Err(e) => return Err(e),
 		}
 
-		let mut name = OsString::with_capacity(stem.len() + ext.len() + 5);
+		let mut name = OsString::with_capacity(stem.len() + ext.len() + 7);
 		name.push(&stem);
-		name.push("_");
+		name.push(left);
 		name.push(i.to_string());
+		name.push(right);
 		name.push(&ext);
 
 		p.set_file_name(name);
@@ -157,12 +196,78 @@ pub fn path_relative_to<'a>(path: &'a Path, root: &Path) -> Cow<'a, Path> {
 	Cow::from(buf)
 }
 
+// Apply an edit made on the lossy form of `orig` back to it, so the bytes that
+// aren't valid UTF-8 survive as long as the edit leaves them alone.
+#[cfg(unix)]
+pub fn unlossy(orig: &OsStr, edited: &str) -> OsString {
+	use std::os::unix::ffi::OsStringExt;
+
+	// Each char of the lossy form, paired with the original bytes it came from
+	let mut pairs: Vec<(char, &[u8])> = vec![];
+	let mut rest = orig.as_encoded_bytes();
+	while !rest.is_empty() {
+		let (valid, n) = match std::str::from_utf8(rest) {
+			Ok(s) => (s, 0),
+			Err(e) => {
+				let (valid, after) = rest.split_at(e.valid_up_to());
+				(std::str::from_utf8(valid).unwrap(), e.error_len().unwrap_or(after.len()))
+			}
+		};
+
+		for (i, c) in valid.char_indices() {
+			pairs.push((c, &rest[i..i + c.len_utf8()]));
+		}
+
+		rest = &rest[valid.len()..];
+		if n > 0 {
+			pairs.push(('\u{FFFD}', &rest[..n]));
+			rest = &rest[n..];
+		}
+	}
+
+	let chars: Vec<_> = edited.chars().collect();
+	let prefix = pairs.iter().zip(&chars).take_while(|((a, _), b)| a == *b).count();
+	let suffix = pairs[prefix..]
+		.iter()
+		.rev()
+		.zip(chars[prefix..].iter().rev())
+		.take_while(|((a, _), b)| a == *b)
+		.count();
+
+	let mut b: Vec<u8> = pairs[..prefix].iter().flat_map(|&(_, b)| b).copied().collect();
+	b.extend(chars[prefix..chars.len() - suffix].iter().collect::<String>().as_bytes());
+	b.extend(pairs[pairs.len() - suffix..].iter().flat_map(|&(_, b)| b));
+	OsString::from_vec(b)
+}
+
+#[cfg(windows)]
+pub fn unlossy(_: &OsStr, edited: &str) -> OsString { edited.into() }
+
 #[cfg(test)]
 mod tests {
 	use std::{borrow::Cow, path::Path};
 
 	use super::path_relative_to;
 
+	#[cfg(unix)]
+	#[test]
+	fn test_unlossy() {
+		use std::{ffi::OsStr, os::unix::ffi::OsStrExt};
+
+		use super::unlossy;
+
+		fn assert(orig: &[u8], edited: &str, res: &[u8]) {
+			assert_eq!(unlossy(OsStr::from_bytes(orig), edited).as_bytes(), res);
+		}
+
+		assert(b"abc", "abd", b"abd");
+		assert(b"a\xffb.txt", "a\u{FFFD}b.txt", b"a\xffb.txt");
+		assert(b"a\xffb.txt", "a\u{FFFD}b.md", b"a\xffb.md");
+		assert(b"\xfe\xff.txt", "x.txt", b"x.txt");
+		assert(b"\xe4\xbd.txt", "\u{FFFD}.txt", b"\xe4\xbd.txt");
+		assert(b"\xff", "", b"");
+	}
+
 	#[cfg(unix)]
 	#[test]
 	fn test_path_relative_to() {
@@ -0,0 +1,184 @@
+use std::{io, path::Path};
+
+use super::{xattr_get, xattr_set};
+
+const KEY: &str = "com.apple.metadata:_kMDItemUserTags";
+
+// Finder suffixes the color tags with their index, e.g. "Red\n6"
+const COLORS: [&str; 8] = ["", "Gray", "Green", "Purple", "Blue", "Yellow", "Red", "Orange"];
+
+// Finder tags, stored as a binary property list of strings
+pub fn finder_tags(path: &Path) -> Vec<String> {
+	raw(path).into_iter().map(|s| name(&s).to_owned()).collect()
+}
+
+pub fn finder_tag_add(path: &Path, tag: &str) -> io::Result<()> {
+	let mut tags = raw(path);
+	if tags.iter().any(|s| name(s).eq_ignore_ascii_case(tag)) {
+		return Ok(());
+	}
+
+	match COLORS.iter().position(|&c| !c.is_empty() && c.eq_ignore_ascii_case(tag)) {
+		Some(i) => tags.push(format!("{}\n{i}", COLORS[i])),
+		None => tags.push(tag.to_owned()),
+	}
+	xattr_set(path, KEY, &bplist::encode(&tags))
+}
+
+pub fn finder_tag_remove(path: &Path, tag: &str) -> io::Result<()> {
+	let mut tags = raw(path);
+	tags.retain(|s| !name(s).eq_ignore_ascii_case(tag));
+	xattr_set(path, KEY, &bplist::encode(&tags))
+}
+
+#[inline]
+fn raw(path: &Path) -> Vec<String> {
+	xattr_get(path, KEY).ok().and_then(|b| bplist::decode(&b)).unwrap_or_default()
+}
+
+#[inline]
+fn name(s: &str) -> &str { s.split('\n').next().unwrap_or_default() }
+
+// Only what's needed for an array of strings
+mod bplist {
+	pub(super) fn decode(b: &[u8]) -> Option<Vec<String>> {
+		if b.len() < 40 || !b.starts_with(b"bplist00") {
+			return None;
+		}
+
+		let t = &b[b.len() - 32..];
+		let (int_size, ref_size) = (t[6] as usize, t[7] as usize);
+		let (top, table) = (int(&t[16..24])?, int(&t[24..32])?);
+
+		// Every offset comes from the file itself, so none of them is trusted
+		let object = |i: usize| {
+			let start = i.checked_mul(int_size)?.checked_add(table)?;
+			b.get(int(b.get(start..start.checked_add(int_size)?)?)?..)
+		};
+
+		let (len, refs) = header(object(top)?, 0xa)?;
+		(0..len)
+			.map(|k| {
+				let start = k.checked_mul(ref_size)?;
+				string(object(int(refs.get(start..start.checked_add(ref_size)?)?)?)?)
+			})
+			.collect()
+	}
+
+	pub(super) fn encode(tags: &[String]) -> Vec<u8> {
+		let mut b = b"bplist00".to_vec();
+
+		// The array first, followed by the strings it refers to
+		let mut offsets = vec![b.len()];
+		push_header(&mut b, 0xa, tags.len());
+		for i in 1..=tags.len() {
+			b.extend((i as u16).to_be_bytes());
+		}
+
+		for s in tags {
+			offsets.push(b.len());
+			if s.is_ascii() {
+				push_header(&mut b, 0x5, s.len());
+				b.extend(s.as_bytes());
+			} else {
+				let units: Vec<_> = s.encode_utf16().collect();
+				push_header(&mut b, 0x6, units.len());
+				units.into_iter().for_each(|u| b.extend(u.to_be_bytes()));
+			}
+		}
+
+		let table = b.len();
+		offsets.iter().for_each(|&o| b.extend((o as u64).to_be_bytes()));
+
+		// Trailer: 8-byte offsets, 2-byte object references
+		b.extend([0, 0, 0, 0, 0, 0, 8, 2]);
+		b.extend((offsets.len() as u64).to_be_bytes());
+		b.extend(0u64.to_be_bytes());
+		b.extend((table as u64).to_be_bytes());
+		b
+	}
+
+	fn header(o: &[u8], kind: u8) -> Option<(usize, &[u8])> {
+		let (&marker, rest) = o.split_first()?;
+		if marker >> 4 != kind {
+			return None;
+		} else if marker & 0xf != 0xf {
+			return Some(((marker & 0xf) as usize, rest));
+		}
+
+		let (&marker, rest) = rest.split_first()?;
+		if marker >> 4 != 0x1 {
+			return None;
+		}
+
+		let n = 1 << (marker & 0xf);
+		Some((int(rest.get(..n)?)?, rest.get(n..)?))
+	}
+
+	fn push_header(b: &mut Vec<u8>, kind: u8, len: usize) {
+		if len < 0xf {
+			b.push((kind << 4) | len as u8);
+		} else {
+			b.extend([(kind << 4) | 0xf, 0x13]);
+			b.extend((len as u64).to_be_bytes());
+		}
+	}
+
+	fn string(o: &[u8]) -> Option<String> {
+		if let Some((len, rest)) = header(o, 0x5) {
+			return Some(String::from_utf8_lossy(rest.get(..len)?).into_owned());
+		}
+
+		let (len, rest) = header(o, 0x6)?;
+		let units: Vec<_> =
+			rest.get(..len.checked_mul(2)?)?.chunks_exact(2).map(|c| u16::from_be_bytes([c[0], c[1]])).collect();
+		String::from_utf16(&units).ok()
+	}
+
+	#[inline]
+	fn int(b: &[u8]) -> Option<usize> {
+		(b.len() <= 8).then(|| b.iter().fold(0, |n, &x| (n << 8) | x as usize))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::bplist::{decode, encode};
+
+	#[test]
+	fn test_round_trip() {
+		fn assert(tags: &[&str]) {
+			let tags: Vec<_> = tags.iter().map(|&s| s.to_owned()).collect();
+			assert_eq!(decode(&encode(&tags)), Some(tags));
+		}
+
+		assert(&[]);
+		assert(&["Red\n6"]);
+		assert(&["Work", "Green\n2", "日本語", "a tag longer than fifteen bytes"]);
+		assert(&["x"; 20]);
+	}
+
+	#[test]
+	fn test_malformed() {
+		let b = encode(&["Red\n6".to_owned(), "Work".to_owned()]);
+		assert_eq!(decode(&b[..b.len() - 1]), None);
+		assert_eq!(decode(&b[8..]), None);
+		assert_eq!(decode(b"bplist00"), None);
+
+		// Offset table pointing past the end, and huge sizes that would overflow
+		let mut bad = b.clone();
+		let n = bad.len();
+		bad[n - 8..].copy_from_slice(&u64::MAX.to_be_bytes());
+		assert_eq!(decode(&bad), None);
+
+		let mut bad = b.clone();
+		bad[n - 26] = 0xff;
+		bad[n - 25] = 0xff;
+		assert_eq!(decode(&bad), None);
+
+		// An array claiming more references than it has
+		let mut bad = b;
+		bad[8] = 0xae;
+		assert_eq!(decode(&bad), None);
+	}
+}
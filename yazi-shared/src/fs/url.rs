@@ -1,12 +1,16 @@
 use std::{ffi::OsStr, fmt::{Debug, Display, Formatter}, ops::Deref, path::{Path, PathBuf}};
 
-use percent_encoding::{AsciiSet, CONTROLS, percent_decode_str, percent_encode};
+use percent_encoding::{AsciiSet, CONTROLS, NON_ALPHANUMERIC, percent_decode_str, percent_encode};
 use serde::{Deserialize, Serialize};
 
 use super::{Loc, UrnBuf};
 
 const ENCODE_SET: &AsciiSet = &CONTROLS.add(b'#');
 
+// Unreserved characters and the separators allowed in the path of a `file:` URI
+const URI_SET: &AsciiSet =
+	&NON_ALPHANUMERIC.remove(b'-').remove(b'.').remove(b'_').remove(b'~').remove(b'/').remove(b':');
+
 #[derive(Clone, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct Url {
 	loc:    Loc,
@@ -249,6 +253,15 @@ impl Url {
 	#[inline]
 	pub fn into_path(self) -> PathBuf { self.loc.into_path() }
 
+	pub fn to_file_uri(&self) -> String {
+		#[cfg(unix)]
+		let path = self.loc.as_os_str().as_encoded_bytes().to_vec();
+		#[cfg(windows)]
+		let path = format!("/{}", self.loc.to_string_lossy().replace('\\', "/")).into_bytes();
+
+		format!("file://{}", percent_encode(&path, URI_SET))
+	}
+
 	// --- Scheme
 	#[inline]
 	pub fn scheme(&self) -> UrlScheme { self.scheme }
@@ -0,0 +1,120 @@
+use std::{ffi::CString, io, os::unix::ffi::OsStrExt, path::Path, ptr};
+
+use libc::{c_char, c_int, c_void, size_t, ssize_t};
+
+// Extended attributes of the path itself, symlinks are not followed
+pub fn xattr_list(path: &Path) -> io::Result<Vec<String>> {
+	let p = cstring(path.as_os_str().as_bytes())?;
+	let buf = read(|buf, size| unsafe { sys::list(p.as_ptr(), buf.cast(), size) })?;
+
+	Ok(
+		buf
+			.split(|&b| b == 0)
+			.filter(|s| !s.is_empty())
+			.map(|s| String::from_utf8_lossy(s).into_owned())
+			.collect(),
+	)
+}
+
+pub fn xattr_get(path: &Path, name: &str) -> io::Result<Vec<u8>> {
+	let (p, n) = (cstring(path.as_os_str().as_bytes())?, cstring(name.as_bytes())?);
+	read(|buf, size| unsafe { sys::get(p.as_ptr(), n.as_ptr(), buf.cast(), size) })
+}
+
+pub fn xattr_set(path: &Path, name: &str, value: &[u8]) -> io::Result<()> {
+	let (p, n) = (cstring(path.as_os_str().as_bytes())?, cstring(name.as_bytes())?);
+	let ret = unsafe { sys::set(p.as_ptr(), n.as_ptr(), value.as_ptr().cast(), value.len()) };
+	if ret < 0 { Err(io::Error::last_os_error()) } else { Ok(()) }
+}
+
+pub fn xattr_remove(path: &Path, name: &str) -> io::Result<()> {
+	let (p, n) = (cstring(path.as_os_str().as_bytes())?, cstring(name.as_bytes())?);
+	let ret = unsafe { sys::remove(p.as_ptr(), n.as_ptr()) };
+	if ret < 0 { Err(io::Error::last_os_error()) } else { Ok(()) }
+}
+
+#[inline]
+fn cstring(b: &[u8]) -> io::Result<CString> {
+	CString::new(b).map_err(|_| io::Error::from(io::ErrorKind::InvalidInput))
+}
+
+// Query the size first, then read into a buffer of that size
+fn read(f: impl Fn(*mut u8, size_t) -> ssize_t) -> io::Result<Vec<u8>> {
+	let len = f(ptr::null_mut(), 0);
+	if len < 0 {
+		return Err(io::Error::last_os_error());
+	}
+
+	let mut buf = vec![0u8; len as usize];
+	let len = f(buf.as_mut_ptr(), buf.len());
+	if len < 0 {
+		return Err(io::Error::last_os_error());
+	}
+
+	buf.truncate(len as usize);
+	Ok(buf)
+}
+
+#[cfg(target_os = "linux")]
+mod sys {
+	use super::*;
+
+	pub(super) unsafe fn list(p: *const c_char, buf: *mut c_char, size: size_t) -> ssize_t {
+		libc::llistxattr(p, buf, size)
+	}
+
+	pub(super) unsafe fn get(
+		p: *const c_char,
+		n: *const c_char,
+		buf: *mut c_void,
+		size: size_t,
+	) -> ssize_t {
+		libc::lgetxattr(p, n, buf, size)
+	}
+
+	pub(super) unsafe fn set(
+		p: *const c_char,
+		n: *const c_char,
+		value: *const c_void,
+		size: size_t,
+	) -> c_int {
+		libc::lsetxattr(p, n, value, size, 0)
+	}
+
+	pub(super) unsafe fn remove(p: *const c_char, n: *const c_char) -> c_int {
+		libc::lremovexattr(p, n)
+	}
+}
+
+#[cfg(target_os = "macos")]
+mod sys {
+	use libc::XATTR_NOFOLLOW;
+
+	use super::*;
+
+	pub(super) unsafe fn list(p: *const c_char, buf: *mut c_char, size: size_t) -> ssize_t {
+		libc::listxattr(p, buf, size, XATTR_NOFOLLOW)
+	}
+
+	pub(super) unsafe fn get(
+		p: *const c_char,
+		n: *const c_char,
+		buf: *mut c_void,
+		size: size_t,
+	) -> ssize_t {
+		libc::getxattr(p, n, buf, size, 0, XATTR_NOFOLLOW)
+	}
+
+	pub(super) unsafe fn set(
+		p: *const c_char,
+		n: *const c_char,
+		value: *const c_void,
+		size: size_t,
+	) -> c_int {
+		libc::setxattr(p, n, value, size, 0, XATTR_NOFOLLOW)
+	}
+
+	pub(super) unsafe fn remove(p: *const c_char, n: *const c_char) -> c_int {
+		libc::removexattr(p, n, XATTR_NOFOLLOW)
+	}
+}
@@ -8,6 +8,7 @@ mod errors;
 pub mod event;
 pub mod fs;
 mod layer;
+mod limiter;
 mod natsort;
 mod number;
 mod os;
@@ -19,6 +20,7 @@ pub mod theme;
 mod throttle;
 mod time;
 mod translit;
+mod width;
 mod xdg;
 
 pub use chars::*;
@@ -27,9 +29,9 @@ pub use debounce::*;
 pub use env::*;
 pub use errors::*;
 pub use layer::*;
+pub use limiter::*;
 pub use natsort::*;
 pub use number::*;
-#[cfg(unix)]
 pub use os::*;
 pub use rand::*;
 pub use ro_cell::*;
@@ -37,6 +39,7 @@ pub use terminal::*;
 pub use throttle::*;
 pub use time::*;
 pub use translit::*;
+pub use width::*;
 pub use xdg::*;
 
 pub fn init() {
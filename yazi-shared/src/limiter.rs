@@ -0,0 +1,26 @@
+use std::time::{Duration, Instant};
+
+use parking_lot::Mutex;
+use tokio::time::sleep_until;
+
+// A rate limiter shared by concurrent transfers, each caller reserves a time
+// slot for the bytes it's about to transfer, and waits until the slot starts.
+#[derive(Debug)]
+pub struct RateLimiter {
+	rate: u64,
+	next: Mutex<Instant>,
+}
+
+impl RateLimiter {
+	pub fn new(rate: u64) -> Self { Self { rate: rate.max(1), next: Mutex::new(Instant::now()) } }
+
+	pub async fn acquire(&self, n: u64) {
+		let start = {
+			let mut next = self.next.lock();
+			let start = Instant::now().max(*next);
+			*next = start + Duration::from_secs_f64(n as f64 / self.rate as f64);
+			start
+		};
+		sleep_until(start.into()).await;
+	}
+}
@@ -26,3 +26,15 @@ impl PartialEq for OrderedFloat {
 }
 
 impl Eq for OrderedFloat {}
+
+// A size in bytes with its binary unit, e.g. "1.5M"
+pub fn readable_size(size: u64) -> String {
+	const UNITS: [char; 11] = ['B', 'K', 'M', 'G', 'T', 'P', 'E', 'Z', 'Y', 'R', 'Q'];
+
+	let (mut size, mut i) = (size as f64, 0);
+	while size > 1024.0 && i < UNITS.len() - 1 {
+		size /= 1024.0;
+		i += 1;
+	}
+	format!("{size:.1}{}", UNITS[i])
+}
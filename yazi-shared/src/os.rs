@@ -20,3 +20,27 @@ pub fn hostname() -> Result<String, std::io::Error> {
 		.map_err(|_| Error::new(ErrorKind::Other, "invalid hostname"))
 		.map(|s| s.to_owned())
 }
+
+// Whether a process with this PID is still running
+#[cfg(unix)]
+pub fn process_alive(pid: u32) -> bool {
+	(unsafe { libc::kill(pid as libc::pid_t, 0) } == 0)
+		|| std::io::Error::last_os_error().raw_os_error() == Some(libc::EPERM)
+}
+
+#[cfg(windows)]
+pub fn process_alive(pid: u32) -> bool {
+	use windows_sys::Win32::{Foundation::{CloseHandle, STILL_ACTIVE}, System::Threading::{GetExitCodeProcess, OpenProcess, PROCESS_QUERY_LIMITED_INFORMATION}};
+
+	unsafe {
+		let handle = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, 0, pid);
+		if handle.is_null() {
+			return false;
+		}
+
+		let mut code = 0;
+		let ok = GetExitCodeProcess(handle, &mut code) != 0;
+		CloseHandle(handle);
+		ok && code == STILL_ACTIVE as u32
+	}
+}
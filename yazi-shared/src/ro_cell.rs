@@ -40,7 +40,7 @@ impl<T> RoCell<T> {
 	}
 
 	#[inline]
-	fn initialized(&self) -> bool { unsafe { (*self.0.get()).is_some() } }
+	pub fn initialized(&self) -> bool { unsafe { (*self.0.get()).is_some() } }
 }
 
 impl<T> Default for RoCell<T> {
@@ -1,4 +1,4 @@
-use std::io::Write;
+use std::{io::Write, sync::atomic::{AtomicU8, Ordering}};
 
 use crossterm::queue;
 
@@ -8,3 +8,18 @@ pub fn terminal_clear(w: &mut impl Write) -> std::io::Result<()> {
 	writeln!(w)?;
 	w.flush()
 }
+
+// 0 for unknown, 1 for a dark background, 2 for a light one
+static LIGHT_BACKGROUND: AtomicU8 = AtomicU8::new(0);
+
+#[inline]
+pub fn set_light_background(light: bool) { LIGHT_BACKGROUND.store(1 + light as u8, Ordering::Relaxed); }
+
+// Whether the terminal reported a light background when asked at startup
+#[inline]
+pub fn light_background() -> Option<bool> {
+	match LIGHT_BACKGROUND.load(Ordering::Relaxed) {
+		0 => None,
+		n => Some(n == 2),
+	}
+}
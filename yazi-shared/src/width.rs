@@ -0,0 +1,39 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+// Whether the East Asian Ambiguous characters, like "①" or "…", take up two cells,
+// it depends on the terminal and its font so it's up to the user
+static AMBIGUOUS_WIDE: AtomicBool = AtomicBool::new(false);
+
+#[inline]
+pub fn set_ambiguous_wide(wide: bool) { AMBIGUOUS_WIDE.store(wide, Ordering::Relaxed); }
+
+#[inline]
+pub fn char_width(c: char) -> usize {
+	if AMBIGUOUS_WIDE.load(Ordering::Relaxed) { c.width_cjk() } else { c.width() }.unwrap_or(0)
+}
+
+#[inline]
+pub fn str_width(s: &str) -> usize {
+	if AMBIGUOUS_WIDE.load(Ordering::Relaxed) { s.width_cjk() } else { s.width() }
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_width() {
+		assert_eq!(str_width("readme.md"), 9);
+		assert_eq!(str_width("中文文件名.txt"), 14);
+		assert_eq!(str_width("日本語のファイル"), 16);
+		assert_eq!(char_width('\u{301}'), 0);
+
+		assert_eq!(str_width("①…"), 2);
+		set_ambiguous_wide(true);
+		assert_eq!(str_width("①…"), 4);
+		assert_eq!(str_width("中文文件名.txt"), 14);
+		set_ambiguous_wide(false);
+	}
+}